@@ -2,6 +2,7 @@ use winreg::enums::*;
 use winreg::RegKey;
 use serde::{Deserialize, Serialize};
 use std::process::Command;
+use crate::datetime::{self, Timestamp};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppInfo {
@@ -9,7 +10,7 @@ pub struct AppInfo {
     pub version: String,
     pub publisher: String,
     pub uninstall_string: String,
-    pub install_date: String,
+    pub install_date: Option<Timestamp>,
 }
 
 #[tauri::command]
@@ -18,6 +19,8 @@ pub fn get_installed_apps() -> Result<Vec<AppInfo>, String> {
     let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
     
+    let date_order = datetime::system_date_order();
+
     let paths = vec![
         "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
         "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
@@ -25,10 +28,10 @@ pub fn get_installed_apps() -> Result<Vec<AppInfo>, String> {
 
     for path in paths {
         if let Ok(key) = hklm.open_subkey(path) {
-            collect_apps_from_key(&key, &mut apps);
+            collect_apps_from_key(&key, &mut apps, date_order);
         }
         if let Ok(key) = hkcu.open_subkey(path) {
-            collect_apps_from_key(&key, &mut apps);
+            collect_apps_from_key(&key, &mut apps, date_order);
         }
     }
 
@@ -37,7 +40,7 @@ pub fn get_installed_apps() -> Result<Vec<AppInfo>, String> {
     Ok(apps)
 }
 
-fn collect_apps_from_key(key: &RegKey, apps: &mut Vec<AppInfo>) {
+fn collect_apps_from_key(key: &RegKey, apps: &mut Vec<AppInfo>, date_order: datetime::DateOrder) {
     for name in key.enum_keys().map(|x| x.unwrap_or_default()) {
         if let Ok(subkey) = key.open_subkey(&name) {
             let display_name: String = subkey.get_value("DisplayName").unwrap_or_default();
@@ -48,7 +51,9 @@ fn collect_apps_from_key(key: &RegKey, apps: &mut Vec<AppInfo>) {
 
             let display_version: String = subkey.get_value("DisplayVersion").unwrap_or_default();
             let publisher: String = subkey.get_value("Publisher").unwrap_or_default();
-            let install_date: String = subkey.get_value("InstallDate").unwrap_or_default();
+            // InstallDate is documented as YYYYMMDD, but some installers write a locale date instead
+            let install_date_raw: String = subkey.get_value("InstallDate").unwrap_or_default();
+            let install_date = datetime::parse_any(&install_date_raw, date_order);
 
             apps.push(AppInfo {
                 name: display_name,
@@ -0,0 +1,313 @@
+use serde::Serialize;
+use std::hint::black_box;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+use super::{BenchmarkProgress, Cancelled, XorShift};
+
+// Native CPU benchmark: every workload runs for a fixed time, first on one thread and then on
+// all of them. Inputs are generated from fixed seeds, so the amount of work per unit never
+// changes and the score is simply units per second against a reference rate.
+//
+// 1000 points = one thread of the reference machine (the development box the `reference` rates
+// below were measured on). Multi-thread scores scale with the thread count.
+
+const POINTS: f64 = 1000.0;
+// A reference-speed thread is worth about this many points on the benchmark database scale
+// (12-thread i5-12400F: ~19500 database points)
+const DATABASE_POINTS_PER_THREAD: f64 = 1600.0;
+
+pub struct Workload {
+    pub id: &'static str,
+    pub name: &'static str,
+    // Work units per second on one reference thread
+    reference: f64,
+    // Builds the per-thread state; the returned closure runs one unit of work
+    make: fn() -> Box<dyn FnMut() -> u64>,
+}
+
+impl Workload {
+    // Fresh per-thread state; every call of the closure returns the same value on a healthy CPU
+    pub fn unit(&self) -> Box<dyn FnMut() -> u64> {
+        (self.make)()
+    }
+}
+
+pub static WORKLOADS: &[Workload] = &[
+    Workload { id: "integer", name: "Integer (prime sieve)", reference: 900.0, make: sieve_unit },
+    Workload { id: "float", name: "Floating point / SIMD (matrix multiply)", reference: 21000.0, make: matmul_unit },
+    Workload { id: "compression", name: "Compression (LZ77)", reference: 3900.0, make: compress_unit },
+    Workload { id: "hashing", name: "Hashing (SHA-256)", reference: 2200.0, make: sha256_unit },
+    Workload { id: "sorting", name: "Sorting (64-bit keys)", reference: 650.0, make: sort_unit },
+];
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TestResult {
+    pub id: String,
+    pub name: String,
+    pub single_thread: u32,
+    pub multi_thread: u32,
+    // Raw work units per second, for comparing runs at a finer grain than the points
+    pub single_rate: f64,
+    pub multi_rate: f64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CpuBenchmarkResult {
+    pub threads: usize,
+    // Per workload and mode
+    pub duration_ms: u64,
+    // Geometric means over all workloads
+    pub single_thread: u32,
+    pub multi_thread: u32,
+    // Multi-thread result converted to the scale of scoring::benchmarks::CPU_BENCHMARKS
+    pub database_points: u32,
+    pub tests: Vec<TestResult>,
+}
+
+fn sieve_unit() -> Box<dyn FnMut() -> u64> {
+    const LIMIT: usize = 1 << 18;
+    let mut composite = vec![false; LIMIT];
+    Box::new(move || {
+        composite.fill(false);
+        let mut count = 0u64;
+        for i in 2..LIMIT {
+            if !composite[i] {
+                count += 1;
+                let mut j = i * i;
+                while j < LIMIT {
+                    composite[j] = true;
+                    j += i;
+                }
+            }
+        }
+        count
+    })
+}
+
+fn matmul_unit() -> Box<dyn FnMut() -> u64> {
+    const N: usize = 64;
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let mut random = || (rng.next_u64() % 1000) as f32 / 1000.0;
+    let a: Vec<f32> = (0..N * N).map(|_| random()).collect();
+    let b: Vec<f32> = (0..N * N).map(|_| random()).collect();
+    let mut c = vec![0.0f32; N * N];
+    Box::new(move || {
+        c.fill(0.0);
+        // i-k-j order keeps the inner loop contiguous so it vectorizes
+        for i in 0..N {
+            for k in 0..N {
+                let aik = a[i * N + k];
+                let row = &b[k * N..(k + 1) * N];
+                let out = &mut c[i * N..(i + 1) * N];
+                for (o, bv) in out.iter_mut().zip(row) {
+                    *o += aik * bv;
+                }
+            }
+        }
+        c.iter().sum::<f32>().to_bits() as u64
+    })
+}
+
+// Text-like input: words from a small vocabulary, so the compressor finds matches
+fn sample_text(len: usize) -> Vec<u8> {
+    const WORDS: [&str; 16] = [
+        "processor", "memory", "graphics", "storage", "the", "and", "of", "benchmark",
+        "cache", "thread", "score", "device", "driver", "a", "is", "network",
+    ];
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let mut text = Vec::with_capacity(len + 16);
+    while text.len() < len {
+        text.extend_from_slice(WORDS[(rng.next_u64() % WORDS.len() as u64) as usize].as_bytes());
+        text.push(if rng.next_u64().is_multiple_of(9) { b'\n' } else { b' ' });
+    }
+    text.truncate(len);
+    text
+}
+
+// Greedy LZ77 with a 4-byte hash table, in the spirit of LZ4; returns the compressed size
+fn lz_compress(input: &[u8], table: &mut [u32], out: &mut Vec<u8>) -> usize {
+    const MIN_MATCH: usize = 4;
+    table.fill(u32::MAX);
+    out.clear();
+    let mut pos = 0;
+    let mut literal_start = 0;
+    while pos + MIN_MATCH <= input.len() {
+        let word = u32::from_le_bytes([input[pos], input[pos + 1], input[pos + 2], input[pos + 3]]);
+        let slot = (word.wrapping_mul(2_654_435_761) >> (32 - 14)) as usize;
+        let candidate = table[slot] as usize;
+        table[slot] = pos as u32;
+        if candidate != u32::MAX as usize && pos - candidate < 65536 && input[candidate..candidate + MIN_MATCH] == input[pos..pos + MIN_MATCH] {
+            let mut len = MIN_MATCH;
+            while pos + len < input.len() && input[candidate + len] == input[pos + len] && len < 255 {
+                len += 1;
+            }
+            out.push((pos - literal_start).min(255) as u8);
+            out.extend_from_slice(&input[literal_start..pos]);
+            out.extend_from_slice(&((pos - candidate) as u16).to_le_bytes());
+            out.push(len as u8);
+            pos += len;
+            literal_start = pos;
+        } else {
+            pos += 1;
+        }
+    }
+    out.extend_from_slice(&input[literal_start..]);
+    out.len()
+}
+
+fn compress_unit() -> Box<dyn FnMut() -> u64> {
+    let input = sample_text(64 * 1024);
+    let mut table = vec![u32::MAX; 1 << 14];
+    let mut out = Vec::with_capacity(input.len() * 2);
+    Box::new(move || lz_compress(&input, &mut table, &mut out) as u64)
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn sha256_block(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (i, chunk) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+    for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let mut blocks = data.chunks_exact(64);
+    for block in &mut blocks {
+        sha256_block(&mut state, block);
+    }
+    // Padding: 0x80, zeros, then the message length in bits
+    let rest = blocks.remainder();
+    let mut tail = [0u8; 128];
+    tail[..rest.len()].copy_from_slice(rest);
+    tail[rest.len()] = 0x80;
+    let tail_len = if rest.len() < 56 { 64 } else { 128 };
+    tail[tail_len - 8..tail_len].copy_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+    for block in tail[..tail_len].chunks_exact(64) {
+        sha256_block(&mut state, block);
+    }
+    let mut digest = [0u8; 32];
+    for (out, word) in digest.chunks_exact_mut(4).zip(state) {
+        out.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+fn sha256_unit() -> Box<dyn FnMut() -> u64> {
+    let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+    let data: Vec<u8> = (0..64 * 1024).map(|_| rng.next_u64() as u8).collect();
+    Box::new(move || {
+        let digest = sha256(&data);
+        u64::from_le_bytes([digest[0], digest[1], digest[2], digest[3], digest[4], digest[5], digest[6], digest[7]])
+    })
+}
+
+fn sort_unit() -> Box<dyn FnMut() -> u64> {
+    const LEN: usize = 64 * 1024;
+    let mut keys = vec![0u64; LEN];
+    Box::new(move || {
+        // Same sequence every unit, regenerating is part of the work
+        let mut rng = XorShift(0x8BB8_4B93_962E_ACC9);
+        for k in keys.iter_mut() {
+            *k = rng.next_u64();
+        }
+        keys.sort_unstable();
+        keys[LEN / 2]
+    })
+}
+
+// Units per second of one workload on `threads` threads
+fn measure(workload: &Workload, threads: usize, duration: Duration, cancel: &AtomicBool, tick: &mut dyn FnMut(f64)) -> Result<f64, Cancelled> {
+    super::run_timed(threads, duration, cancel, tick, workload.make, |unit| {
+        black_box(unit());
+        1
+    })
+}
+
+fn geometric_mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    (values.iter().map(|v| v.max(f64::MIN_POSITIVE).ln()).sum::<f64>() / values.len() as f64).exp()
+}
+
+pub fn database_points(multi_thread: u32) -> u32 {
+    (multi_thread as f64 / POINTS * DATABASE_POINTS_PER_THREAD).round() as u32
+}
+
+pub fn run(threads: usize, duration: Duration, cancel: &AtomicBool, progress: &dyn Fn(BenchmarkProgress)) -> Result<CpuBenchmarkResult, Cancelled> {
+    let threads = threads.max(1);
+    let steps = (WORKLOADS.len() * 2) as f64;
+    let mut tests = Vec::new();
+    for (index, workload) in WORKLOADS.iter().enumerate() {
+        let mut rates = [0.0; 2];
+        for (mode, count) in [1, threads].into_iter().enumerate() {
+            let step = (index * 2 + mode) as f64;
+            let phase = if mode == 0 { "single" } else { "multi" };
+            let mut tick = |fraction: f64| progress(BenchmarkProgress {
+                kind: "cpu".to_string(),
+                test: workload.id.to_string(),
+                phase: phase.to_string(),
+                percent: ((step + fraction) / steps * 100.0) as u32,
+            });
+            rates[mode] = measure(workload, count, duration, cancel, &mut tick)?;
+        }
+        tests.push(TestResult {
+            id: workload.id.to_string(),
+            name: workload.name.to_string(),
+            single_thread: (rates[0] / workload.reference * POINTS).round() as u32,
+            multi_thread: (rates[1] / workload.reference * POINTS).round() as u32,
+            single_rate: rates[0],
+            multi_rate: rates[1],
+        });
+    }
+
+    let single: Vec<f64> = tests.iter().map(|t| t.single_thread as f64).collect();
+    let multi: Vec<f64> = tests.iter().map(|t| t.multi_thread as f64).collect();
+    let multi_thread = geometric_mean(&multi).round() as u32;
+    Ok(CpuBenchmarkResult {
+        threads,
+        duration_ms: duration.as_millis() as u64,
+        single_thread: geometric_mean(&single).round() as u32,
+        multi_thread,
+        database_points: database_points(multi_thread),
+        tests,
+    })
+}
@@ -0,0 +1,347 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{self, Layout};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::os::windows::fs::{FileExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use winapi::um::winbase::{FILE_FLAG_NO_BUFFERING, FILE_FLAG_WRITE_THROUGH};
+use super::{BenchmarkProgress, Cancelled, XorShift};
+use crate::hardware::codes::MediaType;
+use crate::hardware::disk::DiskInfo;
+use crate::datetime::Timestamp;
+
+// Storage benchmark in the style of CrystalDiskMark: sequential 1 MiB and random 4 KiB reads and
+// writes against a test file, with and without queue depth. The file is opened unbuffered and
+// write-through so the Windows cache never answers for the drive. Queue depth is the number of
+// threads each keeping one request in flight on its own handle (synchronous handles serialize).
+
+pub const TEST_FILE_NAME: &str = "neko233-hardware-viewer.bench.tmp";
+// Latest result per physical disk, shown next to its DiskInfo
+pub const RESULTS_FILE: &str = "neko233-hardware-viewer.disk-bench.json";
+pub const DEFAULT_FILE_MB: u64 = 1024;
+pub const MIN_FILE_MB: u64 = 256;
+pub const MAX_FILE_MB: u64 = 16384;
+// Unbuffered I/O needs sector-aligned buffers, offsets and sizes; 4 KiB covers 512e and 4Kn drives
+const ALIGN: usize = 4096;
+const PREPARE_BLOCK: usize = 1024 * 1024;
+
+// SATA III tops out around 550 MB/s; an NVMe drive below this is not getting its PCIe lanes
+const SATA_LIMIT_MBPS: f64 = 600.0;
+const SATA_SSD_MIN_MBPS: f64 = 300.0;
+const HDD_MIN_MBPS: f64 = 80.0;
+
+pub struct DiskTest {
+    pub id: &'static str,
+    name: &'static str,
+    block: usize,
+    queue_depth: usize,
+    random: bool,
+}
+
+pub const TESTS: [DiskTest; 4] = [
+    DiskTest { id: "seq_q8", name: "SEQ1M Q8", block: 1024 * 1024, queue_depth: 8, random: false },
+    DiskTest { id: "seq_q1", name: "SEQ1M Q1", block: 1024 * 1024, queue_depth: 1, random: false },
+    DiskTest { id: "rnd4k_q32", name: "RND4K Q32", block: 4096, queue_depth: 32, random: true },
+    DiskTest { id: "rnd4k_q1", name: "RND4K Q1", block: 4096, queue_depth: 1, random: true },
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiskTestResult {
+    pub id: String,
+    pub name: String,
+    pub block_kb: u32,
+    pub queue_depth: u32,
+    // Decimal MB/s, like drive spec sheets
+    pub read_mbps: f64,
+    pub write_mbps: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    // Average per request, from queue depth over IOPS
+    pub read_latency_us: f64,
+    pub write_latency_us: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiskBenchmarkResult {
+    pub volume: String,
+    pub file_size_mb: u64,
+    pub duration_ms: u64,
+    pub date: Timestamp,
+    pub tests: Vec<DiskTestResult>,
+}
+
+struct AlignedBuffer {
+    ptr: *mut u8,
+    layout: Layout,
+}
+
+impl AlignedBuffer {
+    // Filled with random bytes so compressing controllers cannot shortcut the writes
+    fn random(size: usize, seed: u64) -> AlignedBuffer {
+        let layout = Layout::from_size_align(size, ALIGN).unwrap();
+        let ptr = unsafe { alloc::alloc(layout) };
+        if ptr.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+        let mut buffer = AlignedBuffer { ptr, layout };
+        let mut rng = XorShift(seed | 1);
+        for chunk in buffer.as_mut_slice().chunks_mut(8) {
+            chunk.copy_from_slice(&rng.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+        buffer
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.layout.size()) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.layout.size()) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        unsafe { alloc::dealloc(self.ptr, self.layout) }
+    }
+}
+
+// Removes the test file however the run ends
+pub struct TestFile(pub PathBuf);
+
+impl Drop for TestFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn open_unbuffered(path: &Path, create: bool) -> std::io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(create)
+        .truncate(create)
+        .custom_flags(FILE_FLAG_NO_BUFFERING | FILE_FLAG_WRITE_THROUGH)
+        .open(path)
+}
+
+// The temp folder when it lives on the volume (writing to a drive root may need admin), else the root
+pub fn test_file_path(volume: &str) -> PathBuf {
+    let temp = std::env::temp_dir();
+    let on_volume = temp.to_string_lossy().to_uppercase().starts_with(&volume.to_uppercase());
+    let dir = if on_volume { temp } else { PathBuf::from(format!("{}\\", volume)) };
+    dir.join(TEST_FILE_NAME)
+}
+
+struct Worker {
+    file: File,
+    buffer: AlignedBuffer,
+    rng: XorShift,
+}
+
+fn measure(
+    path: &Path,
+    test: &DiskTest,
+    write: bool,
+    size_mb: u64,
+    duration: Duration,
+    cancel: &AtomicBool,
+    tick: &mut dyn FnMut(f64),
+) -> Result<f64, String> {
+    // Handles are opened up front so a failure surfaces as an error, not inside the timed threads
+    let mut handles = Vec::new();
+    for _ in 0..test.queue_depth {
+        handles.push(open_unbuffered(path, false).map_err(|e| e.to_string())?);
+    }
+    let handles = Mutex::new(handles);
+    let blocks = size_mb * PREPARE_BLOCK as u64 / test.block as u64;
+    let next_block = AtomicU64::new(0);
+    let failure: Mutex<Option<String>> = Mutex::new(None);
+    let seeds = AtomicU64::new(0x9E37_79B9_7F4A_7C15);
+
+    let rate = super::run_timed(test.queue_depth, duration, cancel, tick,
+        || Worker {
+            file: handles.lock().unwrap().pop().unwrap(),
+            buffer: AlignedBuffer::random(test.block, seeds.fetch_add(0x2545_F491, Ordering::Relaxed)),
+            rng: XorShift(seeds.fetch_add(0x2545_F491, Ordering::Relaxed) | 1),
+        },
+        |worker| {
+            let block = if test.random {
+                worker.rng.next_u64() % blocks
+            } else {
+                next_block.fetch_add(1, Ordering::Relaxed) % blocks
+            };
+            let offset = block * test.block as u64;
+            let done = if write {
+                worker.file.seek_write(worker.buffer.as_slice(), offset)
+            } else {
+                worker.file.seek_read(worker.buffer.as_mut_slice(), offset)
+            };
+            match done {
+                Ok(bytes) => bytes as u64,
+                Err(e) => {
+                    // Stops every thread at the next tick; the error wins over the cancellation below
+                    failure.lock().unwrap().get_or_insert(e.to_string());
+                    cancel.store(true, Ordering::Relaxed);
+                    0
+                }
+            }
+        },
+    );
+    if let Some(e) = failure.into_inner().unwrap() {
+        return Err(format!("Disk I/O failed: {}", e));
+    }
+    rate.map_err(|Cancelled| "Benchmark cancelled".to_string())
+}
+
+fn prepare(path: &Path, size_mb: u64, cancel: &AtomicBool, tick: &mut dyn FnMut(f64)) -> Result<(), String> {
+    let file = open_unbuffered(path, true).map_err(|e| e.to_string())?;
+    let buffer = AlignedBuffer::random(PREPARE_BLOCK, 0xD1B5_4A32_D192_ED03);
+    for block in 0..size_mb {
+        if cancel.load(Ordering::Relaxed) {
+            return Err("Benchmark cancelled".to_string());
+        }
+        file.seek_write(buffer.as_slice(), block * PREPARE_BLOCK as u64).map_err(|e| e.to_string())?;
+        if block % 64 == 0 {
+            tick(block as f64 / size_mb as f64);
+        }
+    }
+    Ok(())
+}
+
+// One write-then-verify sweep over a `size_mb` test file, for the stress test. Returns the
+// number of 1 MiB blocks that did not read back as written; stops early once `stop` is set.
+pub fn verify_pass(path: &Path, size_mb: u64, seed: u64, stop: &AtomicBool) -> Result<u64, String> {
+    let file = open_unbuffered(path, true).map_err(|e| e.to_string())?;
+    let mut written = AlignedBuffer::random(PREPARE_BLOCK, seed);
+    let mut read = AlignedBuffer::random(PREPARE_BLOCK, !seed);
+    let tag = |buffer: &mut AlignedBuffer, block: u64| buffer.as_mut_slice()[..8].copy_from_slice(&block.to_le_bytes());
+    for block in 0..size_mb {
+        if stop.load(Ordering::Relaxed) {
+            return Ok(0);
+        }
+        tag(&mut written, block);
+        file.seek_write(written.as_slice(), block * PREPARE_BLOCK as u64).map_err(|e| e.to_string())?;
+    }
+    let mut bad = 0;
+    for block in 0..size_mb {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+        tag(&mut written, block);
+        file.seek_read(read.as_mut_slice(), block * PREPARE_BLOCK as u64).map_err(|e| e.to_string())?;
+        if read.as_slice() != written.as_slice() {
+            bad += 1;
+        }
+    }
+    Ok(bad)
+}
+
+fn mbps(bytes_per_second: f64) -> f64 {
+    (bytes_per_second / 1e6 * 10.0).round() / 10.0
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+// `volume` is a drive letter such as "D:"; the test file takes `size_mb` of free space for the run
+pub fn run(volume: &str, size_mb: u64, duration: Duration, cancel: &AtomicBool, progress: &dyn Fn(BenchmarkProgress)) -> Result<DiskBenchmarkResult, String> {
+    let path = test_file_path(volume);
+    let _cleanup = TestFile(path.clone());
+    // Preparing the file counts as one step, then a read and a write per test
+    let steps = 1.0 + TESTS.len() as f64 * 2.0;
+    let report = |test: &str, phase: &str, step: f64| progress(BenchmarkProgress {
+        kind: "disk".to_string(),
+        test: test.to_string(),
+        phase: phase.to_string(),
+        percent: (step / steps * 100.0) as u32,
+    });
+
+    prepare(&path, size_mb, cancel, &mut |fraction| report("prepare", "write", fraction))?;
+
+    let mut tests = Vec::new();
+    for (index, test) in TESTS.iter().enumerate() {
+        let mut rates = [0.0; 2];
+        for (pass, write) in [false, true].into_iter().enumerate() {
+            let phase = if write { "write" } else { "read" };
+            let step = 1.0 + (index * 2 + pass) as f64;
+            rates[pass] = measure(&path, test, write, size_mb, duration, cancel, &mut |fraction| report(test.id, phase, step + fraction))?;
+        }
+        let iops = rates.map(|r| r / test.block as f64);
+        let latency = iops.map(|i| if i > 0.0 { round1(test.queue_depth as f64 / i * 1e6) } else { 0.0 });
+        tests.push(DiskTestResult {
+            id: test.id.to_string(),
+            name: test.name.to_string(),
+            block_kb: (test.block / 1024) as u32,
+            queue_depth: test.queue_depth as u32,
+            read_mbps: mbps(rates[0]),
+            write_mbps: mbps(rates[1]),
+            read_iops: iops[0].round(),
+            write_iops: iops[1].round(),
+            read_latency_us: latency[0],
+            write_latency_us: latency[1],
+        });
+    }
+    report("done", "read", steps);
+
+    Ok(DiskBenchmarkResult {
+        volume: volume.to_string(),
+        file_size_mb: size_mb,
+        duration_ms: duration.as_millis() as u64,
+        date: Timestamp::now(),
+        tests,
+    })
+}
+
+// Compares sequential reads with what the drive type and bus should deliver
+pub fn assess(result: &DiskBenchmarkResult, disk: Option<&DiskInfo>) -> Vec<String> {
+    let mut findings = Vec::new();
+    let (Some(disk), Some(seq)) = (disk, result.tests.iter().find(|t| t.id == "seq_q8")) else {
+        return findings;
+    };
+    let bus = disk.bus_type.to_lowercase();
+    if bus.contains("nvme") && seq.read_mbps < SATA_LIMIT_MBPS {
+        findings.push(format!(
+            "Sequential reads reach {} MB/s, SATA speed for an NVMe drive. Check the M.2 slot: some run through the chipset at PCIe 2.0 or x1, or share lanes with SATA ports. An overheating or nearly full drive also slows down",
+            seq.read_mbps
+        ));
+    } else if bus.contains("sata") && disk.media_type.is_solid_state() && seq.read_mbps < SATA_SSD_MIN_MBPS {
+        findings.push(format!(
+            "Sequential reads reach {} MB/s, low for a SATA SSD. Check that it is on a 6 Gb/s port and the controller runs in AHCI mode",
+            seq.read_mbps
+        ));
+    } else if disk.media_type == MediaType::Hdd && seq.read_mbps < HDD_MIN_MBPS {
+        findings.push(format!(
+            "Sequential reads reach {} MB/s, slow even for a hard drive. Check its SMART status, the drive may be failing",
+            seq.read_mbps
+        ));
+    }
+    if bus.contains("usb") {
+        findings.push("The drive is attached over USB, the port and enclosure limit these results".to_string());
+    }
+    findings
+}
+
+// Serial numbers survive drive letter changes; some USB bridges report none
+pub fn disk_key(disk: &DiskInfo) -> String {
+    let serial = disk.serial_number.trim();
+    if serial.is_empty() { disk.model.trim().to_string() } else { serial.to_string() }
+}
+
+pub fn load_results() -> HashMap<String, DiskBenchmarkResult> {
+    fs::read_to_string(RESULTS_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_result(disk: &DiskInfo, result: &DiskBenchmarkResult) -> Result<(), String> {
+    let mut results = load_results();
+    results.insert(disk_key(disk), result.clone());
+    let json = serde_json::to_string_pretty(&results).map_err(|e| e.to_string())?;
+    fs::write(RESULTS_FILE, json).map_err(|e| e.to_string())
+}
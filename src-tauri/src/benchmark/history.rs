@@ -0,0 +1,458 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+use super::{cpu, disk, memory, stress};
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::codes::MediaType;
+use crate::hardware::disk::DiskInfo;
+use crate::model_name::ModelName;
+use crate::datetime::Timestamp;
+
+// Local history of benchmark runs. Every run keeps its full report plus a flat set of numeric
+// metrics ("multi_thread", "seq_q8.read_mbps" ...) that comparisons line up by name, and is
+// tagged with a fingerprint of the hardware it ran on and the software versions at the time.
+
+pub const HISTORY_FILE: &str = "neko233-hardware-viewer.benchmarks.json";
+// Oldest runs are dropped past this
+const MAX_RUNS: usize = 500;
+// Differences smaller than this are measurement noise, neither better nor worse
+const NOISE_PCT: f64 = 2.0;
+const MIN_SIMILARITY: f32 = 0.5;
+const MAX_REFERENCES: usize = 3;
+
+// Reference runs for common hardware. CPU entries are derived from the benchmark database
+// (multi-thread) and published single-thread ratios against the reference i5-12400F; memory and
+// storage entries are typical results for each class.
+const BUNDLED_REFERENCES: &str = include_str!("references.json");
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct HardwareFingerprint {
+    pub cpu: Option<String>,
+    pub gpus: Vec<String>,
+    pub memory_gb: Option<u64>,
+    pub memory_modules: u32,
+    pub memory_mts: Option<u32>,
+    pub board: Option<String>,
+    // Disk the run used, storage benchmarks only
+    pub disk: Option<String>,
+    // Short hash over the fields above: equal hashes mean the same configuration
+    pub hash: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SoftwareVersions {
+    pub app: String,
+    pub os: Option<String>,
+    pub os_build: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BenchmarkRun {
+    pub id: String,
+    // "cpu", "memory", "disk" or "stress"
+    pub kind: String,
+    pub date: Timestamp,
+    pub fingerprint: HardwareFingerprint,
+    pub software: SoftwareVersions,
+    pub metrics: BTreeMap<String, f64>,
+    // The report as the run command returned it; left out of listings
+    #[serde(default)]
+    pub result: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct HistoryFile {
+    runs: Vec<BenchmarkRun>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ReferenceEntry {
+    kind: String,
+    hardware: String,
+    memory_mts: Option<u32>,
+    dual_channel: Option<bool>,
+    disk_class: Option<String>,
+    metrics: BTreeMap<String, f64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MetricDelta {
+    pub name: String,
+    pub base: f64,
+    pub other: f64,
+    // `other` against `base`
+    pub change_pct: Option<f64>,
+    pub higher_is_better: bool,
+    // None within measurement noise
+    pub better: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RunComparison {
+    // Run id, or the hardware name of a bundled reference
+    pub base: String,
+    pub other: String,
+    pub reference: bool,
+    pub same_hardware: bool,
+    pub hardware_changes: Vec<String>,
+    pub metrics: Vec<MetricDelta>,
+}
+
+fn load() -> HistoryFile {
+    fs::read_to_string(HISTORY_FILE)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(history: &HistoryFile) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(history).map_err(|e| e.to_string())?;
+    fs::write(HISTORY_FILE, json).map_err(|e| e.to_string())
+}
+
+fn hash(text: &str) -> String {
+    cpu::sha256(text.as_bytes())[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn fingerprint(ctx: &mut HardwareContext, disk: Option<&DiskInfo>) -> HardwareFingerprint {
+    let cpu = hardware::cpu::get_cpu_info(ctx).ok().and_then(|c| c.into_iter().next()).map(|c| c.name.trim().to_string());
+    let mut gpus: Vec<String> = hardware::gpu::get_gpu_info(ctx).unwrap_or_default().into_iter().map(|g| g.name.trim().to_string()).collect();
+    gpus.sort();
+    let modules = hardware::memory::get_memory_info(ctx).unwrap_or_default();
+    let board = hardware::motherboard::get_motherboard_info(ctx).ok()
+        .and_then(|b| b.into_iter().next())
+        .map(|b| format!("{} {}", b.manufacturer.trim(), b.product.trim()));
+    let mut fingerprint = HardwareFingerprint {
+        cpu,
+        gpus,
+        memory_gb: Some(modules.iter().map(|m| m.capacity).sum::<u64>() / 1024 / 1024 / 1024).filter(|gb| *gb > 0),
+        memory_modules: modules.len() as u32,
+        memory_mts: modules.iter().filter_map(|m| m.configured_clock_speed).filter(|s| *s > 0).min(),
+        board,
+        disk: disk.map(|d| d.model.trim().to_string()),
+        hash: String::new(),
+    };
+    fingerprint.hash = hash(&serde_json::to_string(&fingerprint).unwrap_or_default());
+    fingerprint
+}
+
+pub fn software(ctx: &HardwareContext) -> SoftwareVersions {
+    let os = hardware::os::get_os_info(ctx).ok();
+    SoftwareVersions {
+        app: env!("CARGO_PKG_VERSION").to_string(),
+        os: os.as_ref().map(|o| o.caption.trim().to_string()),
+        os_build: os.map(|o| o.build_number),
+    }
+}
+
+// Stores a finished run and returns its id. `disk` tags storage runs with the disk they used.
+pub fn record(kind: &str, result: &impl Serialize, metrics: BTreeMap<String, f64>, disk: Option<&DiskInfo>) -> Result<String, String> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi().map_err(|e| e.to_string())?;
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+    let run = BenchmarkRun {
+        id: format!("{}-{}", kind, millis),
+        kind: kind.to_string(),
+        date: Timestamp::from_unix((millis / 1000) as i64),
+        fingerprint: fingerprint(&mut ctx, disk),
+        software: software(&ctx),
+        metrics,
+        result: serde_json::to_value(result).map_err(|e| e.to_string())?,
+    };
+    let id = run.id.clone();
+    let mut history = load();
+    history.runs.push(run);
+    let excess = history.runs.len().saturating_sub(MAX_RUNS);
+    history.runs.drain(..excess);
+    save(&history)?;
+    Ok(id)
+}
+
+pub fn cpu_metrics(result: &cpu::CpuBenchmarkResult) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::from([
+        ("single_thread".to_string(), result.single_thread as f64),
+        ("multi_thread".to_string(), result.multi_thread as f64),
+        ("database_points".to_string(), result.database_points as f64),
+    ]);
+    for test in &result.tests {
+        metrics.insert(format!("{}.single_thread", test.id), test.single_thread as f64);
+        metrics.insert(format!("{}.multi_thread", test.id), test.multi_thread as f64);
+    }
+    metrics
+}
+
+pub fn memory_metrics(result: &memory::MemoryBenchmarkResult) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::from([
+        ("read_gbps".to_string(), result.read_gbps),
+        ("write_gbps".to_string(), result.write_gbps),
+        ("copy_gbps".to_string(), result.copy_gbps),
+    ]);
+    if let Some(latency) = result.dram_latency_ns {
+        metrics.insert("dram_latency_ns".to_string(), latency);
+    }
+    metrics
+}
+
+pub fn disk_metrics(result: &disk::DiskBenchmarkResult) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::new();
+    for test in &result.tests {
+        for (name, value) in [
+            ("read_mbps", test.read_mbps),
+            ("write_mbps", test.write_mbps),
+            ("read_iops", test.read_iops),
+            ("write_iops", test.write_iops),
+            ("read_latency_us", test.read_latency_us),
+            ("write_latency_us", test.write_latency_us),
+        ] {
+            metrics.insert(format!("{}.{}", test.id, name), value);
+        }
+    }
+    metrics
+}
+
+pub fn stress_metrics(report: &stress::StressReport) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::from([
+        ("duration_secs".to_string(), report.duration_secs),
+        ("throttled_secs".to_string(), report.throttled_secs as f64),
+        ("errors".to_string(), (report.cpu_errors + report.memory_errors + report.storage_errors) as f64),
+    ]);
+    for (name, value) in [
+        ("max_temp_c", report.max_temp_c),
+        ("start_clock_mhz", report.start_clock_mhz),
+        ("sustained_clock_mhz", report.sustained_clock_mhz),
+        ("clock_drop_pct", report.clock_drop_pct),
+    ] {
+        if let Some(value) = value {
+            metrics.insert(name.to_string(), value);
+        }
+    }
+    metrics
+}
+
+// Newest first, without the full reports
+pub fn list(kind: Option<&str>) -> Vec<BenchmarkRun> {
+    let mut runs: Vec<BenchmarkRun> = load().runs.into_iter()
+        .filter(|r| kind.is_none_or(|k| r.kind == k))
+        .map(|r| BenchmarkRun { result: serde_json::Value::Null, ..r })
+        .collect();
+    runs.reverse();
+    runs
+}
+
+pub fn get(id: &str) -> Result<BenchmarkRun, String> {
+    load().runs.into_iter().find(|r| r.id == id).ok_or_else(|| format!("Benchmark run '{}' not found", id))
+}
+
+pub fn delete(id: &str) -> Result<(), String> {
+    let mut history = load();
+    let before = history.runs.len();
+    history.runs.retain(|r| r.id != id);
+    if history.runs.len() == before {
+        return Err(format!("Benchmark run '{}' not found", id));
+    }
+    save(&history)
+}
+
+fn higher_is_better(metric: &str) -> bool {
+    !["latency", "temp", "drop", "throttled", "errors"].iter().any(|word| metric.contains(word))
+}
+
+fn compare_metrics(base: &BTreeMap<String, f64>, other: &BTreeMap<String, f64>) -> Vec<MetricDelta> {
+    base.iter()
+        .filter_map(|(name, b)| other.get(name).map(|o| (name, *b, *o)))
+        .map(|(name, base, other)| {
+            let change_pct = Some(base).filter(|b| *b != 0.0).map(|b| ((other - b) / b * 1000.0).round() / 10.0);
+            let higher_is_better = higher_is_better(name);
+            MetricDelta {
+                name: name.clone(),
+                base,
+                other,
+                change_pct,
+                higher_is_better,
+                better: change_pct.filter(|c| c.abs() >= NOISE_PCT).map(|c| (c > 0.0) == higher_is_better),
+            }
+        })
+        .collect()
+}
+
+fn hardware_changes(base: &HardwareFingerprint, other: &HardwareFingerprint) -> Vec<String> {
+    let text = |value: &Option<String>| value.clone().unwrap_or_else(|| "unknown".to_string());
+    let number = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_else(|| "unknown".to_string());
+    let mut changes = Vec::new();
+    if base.cpu != other.cpu {
+        changes.push(format!("CPU: {} → {}", text(&base.cpu), text(&other.cpu)));
+    }
+    if base.gpus != other.gpus {
+        changes.push(format!("GPU: {} → {}", base.gpus.join(", "), other.gpus.join(", ")));
+    }
+    if base.memory_gb != other.memory_gb || base.memory_modules != other.memory_modules {
+        changes.push(format!(
+            "Memory: {} GB in {} modules → {} GB in {} modules",
+            number(base.memory_gb), base.memory_modules, number(other.memory_gb), other.memory_modules
+        ));
+    }
+    if base.memory_mts != other.memory_mts {
+        changes.push(format!("Memory speed: {} → {} MT/s", number(base.memory_mts.map(u64::from)), number(other.memory_mts.map(u64::from))));
+    }
+    if base.board != other.board {
+        changes.push(format!("Motherboard: {} → {}", text(&base.board), text(&other.board)));
+    }
+    if base.disk != other.disk {
+        changes.push(format!("Disk: {} → {}", text(&base.disk), text(&other.disk)));
+    }
+    changes
+}
+
+// How `other` did against `base`
+pub fn compare(base_id: &str, other_id: &str) -> Result<RunComparison, String> {
+    let base = get(base_id)?;
+    let other = get(other_id)?;
+    if base.kind != other.kind {
+        return Err(format!("Cannot compare a {} run with a {} run", base.kind, other.kind));
+    }
+    Ok(RunComparison {
+        base: base.id,
+        other: other.id,
+        reference: false,
+        same_hardware: base.fingerprint.hash == other.fingerprint.hash,
+        hardware_changes: hardware_changes(&base.fingerprint, &other.fingerprint),
+        metrics: compare_metrics(&base.metrics, &other.metrics),
+    })
+}
+
+fn references() -> Vec<ReferenceEntry> {
+    serde_json::from_str(BUNDLED_REFERENCES).unwrap_or_default()
+}
+
+// "NVMe", "SATA SSD" or "HDD" from the DiskInfo stored in a storage run
+fn disk_class(run: &BenchmarkRun) -> Option<&'static str> {
+    let disk: DiskInfo = serde_json::from_value(run.result.get("disk")?.clone()).ok()?;
+    if disk.bus_type.to_lowercase().contains("nvme") {
+        Some("NVMe")
+    } else if disk.media_type.is_solid_state() {
+        Some("SATA SSD")
+    } else if disk.media_type == MediaType::Hdd {
+        Some("HDD")
+    } else {
+        None
+    }
+}
+
+fn similar_references(run: &BenchmarkRun) -> Vec<ReferenceEntry> {
+    let mut candidates: Vec<ReferenceEntry> = references().into_iter().filter(|r| r.kind == run.kind).collect();
+    match run.kind.as_str() {
+        "cpu" => {
+            // The same model when bundled, then the parts closest in database score
+            let query = run.fingerprint.cpu.as_deref().map(ModelName::parse);
+            let same = query.and_then(|q| candidates.iter()
+                .map(|r| (q.similarity(&ModelName::parse(&r.hardware)), r))
+                .filter(|(sim, _)| *sim >= MIN_SIMILARITY)
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, r)| r.hardware.clone()));
+            let points = run.metrics.get("database_points").copied().unwrap_or(0.0);
+            candidates.sort_by(|a, b| {
+                let distance = |r: &ReferenceEntry| (r.metrics.get("database_points").copied().unwrap_or(0.0) - points).abs();
+                let rank = |r: &ReferenceEntry| if Some(&r.hardware) == same.as_ref() { 0 } else { 1 };
+                rank(a).cmp(&rank(b)).then(distance(a).total_cmp(&distance(b)))
+            });
+        }
+        "memory" => {
+            // Same DDR generation, then the channel layout, then the closest speed
+            let mts = run.fingerprint.memory_mts.unwrap_or(0);
+            let ddr5 = mts >= 4800;
+            let dual = run.fingerprint.memory_modules >= 2;
+            candidates.retain(|r| r.memory_mts.is_some_and(|m| (m >= 4800) == ddr5));
+            candidates.sort_by_key(|r| (r.dual_channel != Some(dual), r.memory_mts.unwrap_or(0).abs_diff(mts)));
+        }
+        "disk" => {
+            let class = disk_class(run);
+            candidates.retain(|r| class.is_some() && r.disk_class.as_deref() == class);
+        }
+        _ => candidates.clear(),
+    }
+    candidates.truncate(MAX_REFERENCES);
+    candidates
+}
+
+// The run against bundled references for similar hardware, closest match first
+pub fn compare_with_references(id: &str) -> Result<Vec<RunComparison>, String> {
+    let run = get(id)?;
+    Ok(similar_references(&run).into_iter()
+        .map(|reference| RunComparison {
+            base: reference.hardware,
+            other: run.id.clone(),
+            reference: true,
+            same_hardware: false,
+            hardware_changes: Vec::new(),
+            metrics: compare_metrics(&reference.metrics, &run.metrics),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every metric a run can produce, one result per kind with all workloads and tests filled in
+    fn produced_metrics(kind: &str) -> Vec<String> {
+        let metrics = match kind {
+            "cpu" => cpu_metrics(&cpu::CpuBenchmarkResult {
+                threads: 1,
+                duration_ms: 0,
+                single_thread: 0,
+                multi_thread: 0,
+                database_points: 0,
+                tests: cpu::WORKLOADS.iter().map(|w| cpu::TestResult {
+                    id: w.id.to_string(),
+                    name: w.name.to_string(),
+                    single_thread: 0,
+                    multi_thread: 0,
+                    single_rate: 0.0,
+                    multi_rate: 0.0,
+                }).collect(),
+            }),
+            "memory" => memory_metrics(&memory::MemoryBenchmarkResult {
+                threads: 1,
+                read_gbps: 0.0,
+                write_gbps: 0.0,
+                copy_gbps: 0.0,
+                latency: Vec::new(),
+                dram_latency_ns: Some(0.0),
+            }),
+            "disk" => disk_metrics(&disk::DiskBenchmarkResult {
+                volume: String::new(),
+                file_size_mb: 0,
+                duration_ms: 0,
+                date: crate::datetime::Timestamp::from_unix(0),
+                tests: disk::TESTS.iter().map(|t| disk::DiskTestResult {
+                    id: t.id.to_string(),
+                    name: String::new(),
+                    block_kb: 0,
+                    queue_depth: 0,
+                    read_mbps: 0.0,
+                    write_mbps: 0.0,
+                    read_iops: 0.0,
+                    write_iops: 0.0,
+                    read_latency_us: 0.0,
+                    write_latency_us: 0.0,
+                }).collect(),
+            }),
+            _ => BTreeMap::new(),
+        };
+        metrics.into_keys().collect()
+    }
+
+    #[test]
+    fn bundled_references_use_known_metrics() {
+        let references: Vec<ReferenceEntry> = serde_json::from_str(BUNDLED_REFERENCES).unwrap();
+        assert!(!references.is_empty());
+        for reference in references {
+            let known = produced_metrics(&reference.kind);
+            assert!(!known.is_empty(), "{}: unknown kind '{}'", reference.hardware, reference.kind);
+            for name in reference.metrics.keys() {
+                assert!(known.contains(name), "{}: no {} benchmark produces '{}'", reference.hardware, reference.kind, name);
+            }
+        }
+    }
+}
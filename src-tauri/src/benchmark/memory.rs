@@ -0,0 +1,232 @@
+use serde::Serialize;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use super::{BenchmarkProgress, Cancelled, XorShift};
+use crate::hardware::cpu::CacheLevel;
+
+// Memory benchmark: multi-threaded read/write/copy bandwidth over buffers far larger than any
+// CPU cache, and single-thread pointer-chasing latency over growing working sets, where the
+// steps between cache levels and DRAM show up as jumps in the curve.
+
+// Split across all threads, several times the largest L3 on desktop parts
+const BANDWIDTH_BYTES: usize = 512 * 1024 * 1024;
+const LINE: usize = 64;
+const LATENCY_SIZES_KB: [u64; 15] = [16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536, 131072, 262144];
+const LATENCY_TIME: Duration = Duration::from_millis(150);
+const CHASE_BATCH: usize = 100_000;
+
+// Measured read bandwidth under this share of the dual-channel figure means one channel is idle
+const SINGLE_CHANNEL_RATIO: f64 = 0.55;
+// Speeds modules fall back to without XMP/EXPO (JEDEC base for DDR4 and DDR5)
+const JEDEC_BASE_MTS: [u32; 3] = [2133, 2400, 4800];
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LatencyPoint {
+    pub size_kb: u64,
+    pub latency_ns: f64,
+    // "L1", "L2", "L3" or "DRAM", from the reported cache sizes
+    pub level: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemoryBenchmarkResult {
+    pub threads: usize,
+    // Decimal GB/s; copy counts the bytes copied once
+    pub read_gbps: f64,
+    pub write_gbps: f64,
+    pub copy_gbps: f64,
+    pub latency: Vec<LatencyPoint>,
+    // Largest working set, well outside the caches
+    pub dram_latency_ns: Option<f64>,
+}
+
+// What the installed modules should deliver, from Win32_PhysicalMemory
+#[derive(Debug, Clone, Default)]
+pub struct MemorySetup {
+    pub modules: u32,
+    pub rated_mts: Option<u32>,
+    pub configured_mts: Option<u32>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemoryAssessment {
+    pub configured_mts: Option<u32>,
+    // 8 bytes per transfer per channel
+    pub single_channel_gbps: Option<f64>,
+    pub dual_channel_gbps: Option<f64>,
+    // Measured read bandwidth over the dual-channel figure
+    pub efficiency: Option<f64>,
+    pub dual_channel: Option<bool>,
+    pub findings: Vec<String>,
+}
+
+fn gbps(bytes_per_second: f64) -> f64 {
+    (bytes_per_second / 1e9 * 100.0).round() / 100.0
+}
+
+fn thread_buffer(threads: usize) -> Vec<u64> {
+    // Non-zero so every page is really committed before the clock starts
+    vec![1u64; BANDWIDTH_BYTES / threads.max(1) / 8]
+}
+
+fn bandwidth(mode: &str, threads: usize, duration: Duration, cancel: &AtomicBool, tick: &mut dyn FnMut(f64)) -> Result<f64, Cancelled> {
+    let rate = match mode {
+        "read" => super::run_timed(threads, duration, cancel, tick, || thread_buffer(threads), |buf| {
+            black_box(buf.iter().fold(0u64, |acc, v| acc.wrapping_add(*v)));
+            (buf.len() * 8) as u64
+        })?,
+        "write" => super::run_timed(threads, duration, cancel, tick, || (thread_buffer(threads), 0u64), |(buf, pass)| {
+            *pass += 1;
+            buf.fill(*pass);
+            black_box(&buf);
+            (buf.len() * 8) as u64
+        })?,
+        _ => super::run_timed(threads, duration, cancel, tick, || thread_buffer(threads), |buf| {
+            let half = buf.len() / 2;
+            let (src, dst) = buf.split_at_mut(half);
+            let len = src.len().min(dst.len());
+            dst[..len].copy_from_slice(&src[..len]);
+            black_box(&dst);
+            (len * 8) as u64
+        })?,
+    };
+    Ok(gbps(rate))
+}
+
+// One random cycle through every cache line of the working set, so the prefetcher cannot guess
+fn chase_buffer(size_bytes: usize) -> Vec<usize> {
+    let lines = (size_bytes / LINE).max(2);
+    let stride = LINE / std::mem::size_of::<usize>();
+    let mut order: Vec<usize> = (0..lines).collect();
+    // Sattolo's algorithm: a single cycle covering all lines
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    for i in (1..lines).rev() {
+        let j = (rng.next_u64() % i as u64) as usize;
+        order.swap(i, j);
+    }
+    let mut buffer = vec![0usize; lines * stride];
+    for i in 0..lines {
+        buffer[order[i] * stride] = order[(i + 1) % lines] * stride;
+    }
+    buffer
+}
+
+fn chase(buffer: &[usize], start: usize, steps: usize) -> usize {
+    let mut index = start;
+    for _ in 0..steps {
+        index = buffer[index];
+    }
+    index
+}
+
+pub fn cache_level(size_kb: u64, caches: &[CacheLevel]) -> String {
+    caches.iter()
+        .find(|c| size_kb <= c.size_kb as u64)
+        .map(|c| format!("L{}", c.level))
+        .unwrap_or_else(|| if caches.is_empty() { "Unknown".to_string() } else { "DRAM".to_string() })
+}
+
+fn latency(size_kb: u64, cancel: &AtomicBool) -> Result<f64, Cancelled> {
+    let buffer = chase_buffer(size_kb as usize * 1024);
+    // Warm up: bring the working set into whatever level it fits
+    let mut index = chase(&buffer, 0, (buffer.len() / 8).min(1_000_000));
+    let mut steps = 0usize;
+    let start = Instant::now();
+    while start.elapsed() < LATENCY_TIME {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(Cancelled);
+        }
+        index = chase(&buffer, index, CHASE_BATCH);
+        steps += CHASE_BATCH;
+    }
+    black_box(index);
+    Ok((start.elapsed().as_nanos() as f64 / steps as f64 * 100.0).round() / 100.0)
+}
+
+pub fn run(threads: usize, duration: Duration, caches: &[CacheLevel], cancel: &AtomicBool, progress: &dyn Fn(BenchmarkProgress)) -> Result<MemoryBenchmarkResult, Cancelled> {
+    let threads = threads.max(1);
+    // Three bandwidth tests, then the latency sweep counted as one more step
+    let steps = 4.0;
+    let report = |test: &str, step: f64| progress(BenchmarkProgress {
+        kind: "memory".to_string(),
+        test: test.to_string(),
+        phase: if test == "latency" { "single".to_string() } else { "multi".to_string() },
+        percent: (step / steps * 100.0) as u32,
+    });
+
+    let mut rates = [0.0; 3];
+    for (index, mode) in ["read", "write", "copy"].into_iter().enumerate() {
+        let mut tick = |fraction: f64| report(mode, index as f64 + fraction);
+        rates[index] = bandwidth(mode, threads, duration, cancel, &mut tick)?;
+    }
+
+    let mut points = Vec::new();
+    for (index, size_kb) in LATENCY_SIZES_KB.iter().enumerate() {
+        report("latency", 3.0 + index as f64 / LATENCY_SIZES_KB.len() as f64);
+        points.push(LatencyPoint {
+            size_kb: *size_kb,
+            latency_ns: latency(*size_kb, cancel)?,
+            level: cache_level(*size_kb, caches),
+        });
+    }
+    report("latency", steps);
+
+    Ok(MemoryBenchmarkResult {
+        threads,
+        read_gbps: rates[0],
+        write_gbps: rates[1],
+        copy_gbps: rates[2],
+        dram_latency_ns: points.last().map(|p| p.latency_ns),
+        latency: points,
+    })
+}
+
+// Compares the measurement with what the modules should deliver, to confirm dual-channel and XMP
+pub fn assess(result: &MemoryBenchmarkResult, setup: &MemorySetup) -> MemoryAssessment {
+    let mut findings = Vec::new();
+    let configured = setup.configured_mts.or(setup.rated_mts).filter(|s| *s > 0);
+    let single = configured.map(|mts| gbps(mts as f64 * 1e6 * 8.0));
+    let dual = single.map(|s| s * 2.0);
+    let efficiency = dual.map(|d| (result.read_gbps / d * 100.0).round() / 100.0);
+
+    let dual_channel = match (setup.modules, efficiency) {
+        (0, _) | (_, None) => None,
+        (1, _) => {
+            findings.push("Only one memory module is installed, it runs in single-channel mode. A second matching module doubles the bandwidth".to_string());
+            Some(false)
+        }
+        (_, Some(e)) if e < SINGLE_CHANNEL_RATIO => {
+            findings.push(format!(
+                "Read bandwidth is {:.0}% of the dual-channel figure, which matches a single channel. Check that the modules sit in the slots the board manual lists for dual-channel (usually A2 and B2)",
+                e * 100.0
+            ));
+            Some(false)
+        }
+        _ => Some(true),
+    };
+
+    let below_rated = match (setup.rated_mts, setup.configured_mts) {
+        (Some(rated), Some(configured)) if configured > 0 && configured < rated => {
+            findings.push(format!("Memory runs at {} MT/s, below its rated {} MT/s. Enable XMP/EXPO in the BIOS", configured, rated));
+            true
+        }
+        _ => false,
+    };
+    // Many kits report their JEDEC speed as the rating, so the profile being off does not always show above
+    if let Some(mts) = configured.filter(|m| !below_rated && JEDEC_BASE_MTS.contains(m)) {
+        findings.push(format!("{} MT/s is a JEDEC default speed. If the kit is sold as faster, XMP/EXPO is not enabled", mts));
+    }
+    if findings.is_empty() && dual_channel == Some(true) {
+        findings.push("Bandwidth matches dual-channel operation at the configured speed".to_string());
+    }
+
+    MemoryAssessment {
+        configured_mts: configured,
+        single_channel_gbps: single,
+        dual_channel_gbps: dual,
+        efficiency,
+        dual_channel,
+        findings,
+    }
+}
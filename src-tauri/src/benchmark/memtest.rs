@@ -0,0 +1,360 @@
+use serde::Serialize;
+use std::ptr::{read_volatile, write_volatile};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use super::{BenchmarkProgress, Cancelled};
+
+// User-space memory tester. A share of the free RAM is allocated, locked into physical memory
+// where Windows allows it, split across all cores and run through classic test patterns.
+// Every access is volatile, otherwise the compiler would answer the verify passes from the
+// values it just wrote. Addresses are virtual: user space cannot see physical addresses.
+
+const TICK: Duration = Duration::from_millis(100);
+// Failing words kept for the report, the total is always counted
+const MAX_REPORTED_ERRORS: usize = 100;
+const WORDS_PER_LINE: usize = 8;
+// Cancellation is checked at least this often (in words) inside long sweeps
+const CANCEL_CHECK_WORDS: usize = 1 << 20;
+
+pub const DEFAULT_BIT_FADE_SECS: u64 = 30;
+
+// (id, name, full sweeps over the region for progress)
+pub const TESTS: [(&str, &str, u64); 5] = [
+    ("walking", "Walking ones / zeros", 129),
+    ("moving_inversions", "Moving inversions", 12),
+    ("march_c", "March C-", 6),
+    ("random_address", "Random address", 4),
+    ("bit_fade", "Bit fade", 4),
+];
+
+const INVERSION_PATTERNS: [u64; 4] = [0, 0xAAAA_AAAA_AAAA_AAAA, 0xCCCC_CCCC_CCCC_CCCC, 0xF0F0_F0F0_F0F0_F0F0];
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemoryError {
+    pub test: String,
+    // Hex strings: 64-bit values do not survive a trip through JavaScript numbers
+    pub address: String,
+    pub expected: String,
+    pub actual: String,
+    // Failing bit positions within the 64-bit word
+    pub bits: Vec<u8>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TestOutcome {
+    pub id: String,
+    pub name: String,
+    pub errors: u64,
+    pub duration_ms: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemtestResult {
+    pub tested_bytes: u64,
+    // False when Windows refused to pin the pages, parts may have been paged out during the run
+    pub locked: bool,
+    pub threads: usize,
+    pub tests: Vec<TestOutcome>,
+    pub error_count: u64,
+    pub errors: Vec<MemoryError>,
+    pub passed: bool,
+}
+
+// Shared by all worker threads of one test
+struct Shared<'a> {
+    test: &'static str,
+    cancel: &'a AtomicBool,
+    // Words processed, for progress
+    done: AtomicU64,
+    errors: AtomicU64,
+    // Caller's running total, bumped as soon as an error is found
+    live_errors: Option<&'a AtomicU64>,
+    samples: &'a Mutex<Vec<MemoryError>>,
+    bit_fade: Duration,
+}
+
+impl Shared<'_> {
+    fn cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    fn report(&self, chunk: &[u64], index: usize, expected: u64, actual: u64) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+        if let Some(live) = self.live_errors {
+            live.fetch_add(1, Ordering::Relaxed);
+        }
+        let mut samples = self.samples.lock().unwrap();
+        if samples.len() < MAX_REPORTED_ERRORS {
+            let diff = expected ^ actual;
+            samples.push(MemoryError {
+                test: self.test.to_string(),
+                address: format!("{:#018x}", chunk.as_ptr() as usize + index * 8),
+                expected: format!("{:#018x}", expected),
+                actual: format!("{:#018x}", actual),
+                bits: (0..64).filter(|b| diff & (1u64 << b) != 0).collect(),
+            });
+        }
+    }
+
+    fn swept(&self, chunk: &[u64]) {
+        self.done.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+    }
+}
+
+fn read(chunk: &[u64], index: usize) -> u64 {
+    unsafe { read_volatile(chunk.as_ptr().add(index)) }
+}
+
+fn write(chunk: &mut [u64], index: usize, value: u64) {
+    unsafe { write_volatile(chunk.as_mut_ptr().add(index), value) }
+}
+
+fn fill(chunk: &mut [u64], shared: &Shared, pattern: impl Fn(usize) -> u64) {
+    for i in 0..chunk.len() {
+        write(chunk, i, pattern(i));
+    }
+    shared.swept(chunk);
+}
+
+// Verifies `expected(i)` and writes `next(i)` in the same pass, in either direction
+fn verify_write(chunk: &mut [u64], shared: &Shared, descending: bool, expected: impl Fn(usize) -> u64, next: Option<&dyn Fn(usize) -> u64>) {
+    let len = chunk.len();
+    for step in 0..len {
+        let i = if descending { len - 1 - step } else { step };
+        let actual = read(chunk, i);
+        let want = expected(i);
+        if actual != want {
+            shared.report(chunk, i, want, actual);
+        }
+        if let Some(next) = next {
+            write(chunk, i, next(i));
+        }
+    }
+    shared.swept(chunk);
+}
+
+// A single 1 (or 0) rotating through all 64 bit positions; neighbouring words are offset by
+// one position so every data line toggles on every pass
+fn walking(chunk: &mut [u64], shared: &Shared) {
+    let ones = |shift: usize| move |i: usize| 1u64.rotate_left(((i + shift) % 64) as u32);
+    fill(chunk, shared, ones(0));
+    for shift in 0..64 {
+        if shared.cancelled() {
+            return;
+        }
+        let zeros = move |i: usize| !ones(shift)(i);
+        let next = ones(shift + 1);
+        verify_write(chunk, shared, false, ones(shift), Some(&zeros));
+        verify_write(chunk, shared, false, zeros, Some(&next));
+    }
+}
+
+// memtest86 style: fill, then walk up checking and inverting, then walk down inverting back
+fn moving_inversions(chunk: &mut [u64], shared: &Shared) {
+    for pattern in INVERSION_PATTERNS {
+        if shared.cancelled() {
+            return;
+        }
+        fill(chunk, shared, |_| pattern);
+        verify_write(chunk, shared, false, |_| pattern, Some(&|_| !pattern));
+        verify_write(chunk, shared, true, |_| !pattern, Some(&|_| pattern));
+    }
+}
+
+// {⇕(w0); ⇑(r0,w1); ⇑(r1,w0); ⇓(r0,w1); ⇓(r1,w0); ⇕(r0)}
+fn march_c(chunk: &mut [u64], shared: &Shared) {
+    fill(chunk, shared, |_| 0);
+    for (descending, expected, next) in [(false, 0, u64::MAX), (false, u64::MAX, 0), (true, 0, u64::MAX), (true, u64::MAX, 0)] {
+        if shared.cancelled() {
+            return;
+        }
+        verify_write(chunk, shared, descending, |_| expected, Some(&|_| next));
+    }
+    verify_write(chunk, shared, false, |_| 0, None);
+}
+
+// Value derived from the word index, so verification can recompute it
+fn scramble(index: usize, seed: u64) -> u64 {
+    let mut x = (index as u64).wrapping_add(seed).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    x ^= x >> 31;
+    x = x.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x ^ (x >> 29)
+}
+
+// Visits every cache line once in a scrambled order: an odd multiplier modulo a power of two
+// is a permutation, indexes past the end are skipped
+fn scrambled_lines(lines: usize, seed: u64) -> impl Iterator<Item = usize> {
+    let span = lines.next_power_of_two().max(1);
+    let multiplier = (seed as usize & (span - 1)) | 1;
+    let offset = (seed >> 32) as usize;
+    (0..span).map(move |n| (n.wrapping_mul(multiplier).wrapping_add(offset)) & (span - 1)).filter(move |l| *l < lines)
+}
+
+fn random_address(chunk: &mut [u64], shared: &Shared) {
+    let lines = chunk.len() / WORDS_PER_LINE;
+    for seed in [0x2545_F491_4F6C_DD1Du64, 0xD1B5_4A32_D192_ED03] {
+        for (pass, order_seed) in [seed, seed.rotate_left(17)].into_iter().enumerate() {
+            for (n, line) in scrambled_lines(lines, order_seed).enumerate() {
+                if n % (CANCEL_CHECK_WORDS / WORDS_PER_LINE) == 0 && shared.cancelled() {
+                    return;
+                }
+                for i in line * WORDS_PER_LINE..(line + 1) * WORDS_PER_LINE {
+                    let value = scramble(i, seed);
+                    if pass == 0 {
+                        write(chunk, i, value);
+                    } else {
+                        let actual = read(chunk, i);
+                        if actual != value {
+                            shared.report(chunk, i, value, actual);
+                        }
+                    }
+                }
+            }
+            shared.swept(chunk);
+        }
+    }
+}
+
+// Leaves a pattern untouched for a while to catch cells that slowly lose their charge
+fn bit_fade(chunk: &mut [u64], shared: &Shared) {
+    for pattern in [0u64, u64::MAX] {
+        fill(chunk, shared, |_| pattern);
+        let start = Instant::now();
+        while start.elapsed() < shared.bit_fade {
+            if shared.cancelled() {
+                return;
+            }
+            std::thread::sleep(TICK.min(shared.bit_fade.saturating_sub(start.elapsed())));
+        }
+        verify_write(chunk, shared, false, |_| pattern, None);
+    }
+}
+
+fn test_fn(id: &str) -> fn(&mut [u64], &Shared) {
+    match id {
+        "walking" => walking,
+        "moving_inversions" => moving_inversions,
+        "march_c" => march_c,
+        "random_address" => random_address,
+        _ => bit_fade,
+    }
+}
+
+// `region` is split into one chunk per thread (whole cache lines); `tests` are ids from TESTS.
+// `live_errors` also counts the errors of a run that ends up cancelled.
+pub fn run(
+    region: &mut [u64],
+    threads: usize,
+    tests: &[&'static str],
+    bit_fade: Duration,
+    cancel: &AtomicBool,
+    live_errors: Option<&AtomicU64>,
+    progress: &dyn Fn(BenchmarkProgress),
+) -> Result<MemtestResult, Cancelled> {
+    let threads = threads.max(1);
+    let chunk_words = (region.len() / threads / WORDS_PER_LINE).max(1) * WORDS_PER_LINE;
+    let selected: Vec<(&'static str, &'static str, u64)> = TESTS.iter().copied().filter(|(id, _, _)| tests.contains(id)).collect();
+    let total_work: u64 = selected.iter().map(|(_, _, sweeps)| sweeps * region.len() as u64).sum::<u64>().max(1);
+    let samples = Mutex::new(Vec::new());
+    let mut outcomes = Vec::new();
+    let mut finished_work = 0u64;
+
+    for (id, name, sweeps) in selected {
+        let shared = Shared {
+            test: id,
+            cancel,
+            done: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            live_errors,
+            samples: &samples,
+            bit_fade,
+        };
+        let start = Instant::now();
+        let test = test_fn(id);
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = region.chunks_mut(chunk_words)
+                .map(|chunk| {
+                    let shared = &shared;
+                    scope.spawn(move || test(chunk, shared))
+                })
+                .collect();
+            while workers.iter().any(|w| !w.is_finished()) {
+                std::thread::sleep(TICK);
+                let done = finished_work + shared.done.load(Ordering::Relaxed);
+                progress(BenchmarkProgress {
+                    kind: "memtest".to_string(),
+                    test: id.to_string(),
+                    phase: "multi".to_string(),
+                    percent: (done * 100 / total_work).min(100) as u32,
+                });
+            }
+        });
+        if cancel.load(Ordering::Relaxed) {
+            return Err(Cancelled);
+        }
+        finished_work += sweeps * region.len() as u64;
+        outcomes.push(TestOutcome {
+            id: id.to_string(),
+            name: name.to_string(),
+            errors: shared.errors.load(Ordering::Relaxed),
+            duration_ms: start.elapsed().as_millis() as u64,
+        });
+    }
+
+    let error_count = outcomes.iter().map(|o| o.errors).sum();
+    Ok(MemtestResult {
+        tested_bytes: region.len() as u64 * 8,
+        locked: false,
+        threads,
+        tests: outcomes,
+        error_count,
+        errors: samples.into_inner().unwrap(),
+        passed: error_count == 0,
+    })
+}
+
+// Test memory allocated straight from the OS and, where possible, locked into RAM
+pub struct TestRegion {
+    ptr: *mut u64,
+    words: usize,
+    pub locked: bool,
+}
+
+impl TestRegion {
+    pub fn allocate(bytes: usize) -> Result<TestRegion, String> {
+        use winapi::um::memoryapi::{SetProcessWorkingSetSizeEx, VirtualAlloc, VirtualLock};
+        use winapi::um::processthreadsapi::GetCurrentProcess;
+        use winapi::um::winnt::{MEM_COMMIT, MEM_RESERVE, PAGE_READWRITE};
+
+        let bytes = bytes / (WORDS_PER_LINE * 8) * (WORDS_PER_LINE * 8);
+        let ptr = unsafe { VirtualAlloc(std::ptr::null_mut(), bytes, MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE) } as *mut u64;
+        if ptr.is_null() || bytes == 0 {
+            return Err(format!("Could not allocate {} MB for the memory test", bytes / 1024 / 1024));
+        }
+        // VirtualLock is capped by the minimum working set, raise it to cover the region
+        let slack = 64 * 1024 * 1024;
+        let locked = unsafe {
+            SetProcessWorkingSetSizeEx(GetCurrentProcess(), bytes + slack, bytes + 2 * slack, 0) != 0
+                && VirtualLock(ptr as _, bytes) != 0
+        };
+        Ok(TestRegion { ptr, words: bytes / 8, locked })
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u64] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.words) }
+    }
+}
+
+impl Drop for TestRegion {
+    fn drop(&mut self) {
+        use winapi::um::memoryapi::{VirtualFree, VirtualUnlock};
+        use winapi::um::winnt::MEM_RELEASE;
+        unsafe {
+            if self.locked {
+                VirtualUnlock(self.ptr as _, self.words * 8);
+            }
+            VirtualFree(self.ptr as _, 0, MEM_RELEASE);
+        }
+    }
+}
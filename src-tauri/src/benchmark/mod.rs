@@ -0,0 +1,452 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Barrier};
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::cpu::CacheLevel;
+use crate::scoring::{self, benchmarks::{self, BenchmarkMatch}, explain::Explanation};
+
+pub mod cpu;
+pub mod disk;
+pub mod history;
+pub mod memory;
+pub mod memtest;
+pub mod stress;
+
+// Built-in benchmarks and the memory tester. Only one runs at a time; progress goes out as
+// `benchmark_progress` events and `cancel_benchmark` stops the running one at its next progress tick.
+
+pub const PROGRESS_EVENT: &str = "benchmark_progress";
+
+const DEFAULT_DURATION_MS: u64 = 2000;
+const MIN_DURATION_MS: u64 = 500;
+const MAX_DURATION_MS: u64 = 10000;
+// How often the coordinating thread reports progress and looks for cancellation
+const TICK: Duration = Duration::from_millis(100);
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BenchmarkProgress {
+    pub kind: String,
+    pub test: String,
+    pub phase: String,
+    // Whole run, 0-100
+    pub percent: u32,
+}
+
+#[derive(Debug)]
+pub struct Cancelled;
+
+// Small deterministic generator for test inputs and access patterns; the seed must not be zero
+pub struct XorShift(pub u64);
+
+impl XorShift {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+// Runs `step` on `threads` threads for `duration` and returns the summed amount per second.
+// `setup` prepares each thread's state before the clock starts; `step` does one unit of work and
+// returns how much it did (units, bytes ...). `tick` gets the elapsed fraction of the run.
+pub fn run_timed<S>(
+    threads: usize,
+    duration: Duration,
+    cancel: &AtomicBool,
+    tick: &mut dyn FnMut(f64),
+    setup: impl Fn() -> S + Sync,
+    step: impl Fn(&mut S) -> u64 + Sync,
+) -> Result<f64, Cancelled> {
+    let done = AtomicU64::new(0);
+    let stop = AtomicBool::new(false);
+    let ready = Barrier::new(threads + 1);
+    let mut elapsed = Duration::ZERO;
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                let mut state = setup();
+                ready.wait();
+                let mut amount = 0;
+                while !stop.load(Ordering::Relaxed) {
+                    amount += step(&mut state);
+                }
+                done.fetch_add(amount, Ordering::Relaxed);
+            });
+        }
+        ready.wait();
+        let start = Instant::now();
+        while start.elapsed() < duration && !cancel.load(Ordering::Relaxed) {
+            std::thread::sleep(TICK.min(duration.saturating_sub(start.elapsed())));
+            tick((start.elapsed().as_secs_f64() / duration.as_secs_f64()).min(1.0));
+        }
+        stop.store(true, Ordering::Relaxed);
+        elapsed = start.elapsed();
+    });
+    if cancel.load(Ordering::Relaxed) {
+        return Err(Cancelled);
+    }
+    Ok(done.load(Ordering::Relaxed) as f64 / elapsed.as_secs_f64())
+}
+
+#[derive(Default)]
+pub struct BenchmarkState {
+    running: AtomicBool,
+    cancel: Arc<AtomicBool>,
+}
+
+// Clears the running flag however the benchmark ends
+struct RunGuard<'a>(&'a AtomicBool);
+
+impl Drop for RunGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+impl BenchmarkState {
+    fn start(&self) -> Result<RunGuard<'_>, String> {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Err("A benchmark is already running".to_string());
+        }
+        self.cancel.store(false, Ordering::SeqCst);
+        Ok(RunGuard(&self.running))
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CpuBenchmarkReport {
+    pub cpu_name: Option<String>,
+    pub result: cpu::CpuBenchmarkResult,
+    // Database entry of the installed CPU, to compare the measurement against
+    pub database: Option<BenchmarkMatch>,
+    pub score: String,
+    pub explanation: Explanation,
+    pub profile: String,
+    // Entry in the run history, None when it could not be stored
+    pub history_id: Option<String>,
+}
+
+fn duration(duration_ms: Option<u64>) -> Duration {
+    Duration::from_millis(duration_ms.unwrap_or(DEFAULT_DURATION_MS).clamp(MIN_DURATION_MS, MAX_DURATION_MS))
+}
+
+// `duration_ms` applies to each workload in each mode; `threads` defaults to every logical processor
+#[tauri::command]
+pub async fn run_cpu_benchmark(
+    window: tauri::Window,
+    state: tauri::State<'_, BenchmarkState>,
+    duration_ms: Option<u64>,
+    threads: Option<usize>,
+    profile: Option<String>,
+) -> Result<CpuBenchmarkReport, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
+    let _guard = state.start()?;
+    let cancel = state.cancel.clone();
+    let duration = duration(duration_ms);
+    let threads = threads.unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1));
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = cpu::run(threads, duration, &cancel, &|p| window.emit(PROGRESS_EVENT, p).unwrap_or(()))
+            .map_err(|_| "Benchmark cancelled".to_string())?;
+        let mut ctx = HardwareContext::new();
+        let cpu_name = hardware::cpu::get_cpu_info(&mut ctx).ok().and_then(|c| c.into_iter().next()).map(|c| c.name);
+        let database = cpu_name.as_deref().and_then(benchmarks::match_cpu);
+        let rating = scoring::rate_cpu_measured(&profile, result.database_points, database.as_ref());
+
+        let mut report = CpuBenchmarkReport {
+            cpu_name,
+            result,
+            database,
+            score: format!("{:?}", rating.score),
+            explanation: rating.explanation,
+            profile: profile.id,
+            history_id: None,
+        };
+        report.history_id = history::record("cpu", &report, history::cpu_metrics(&report.result), None).ok();
+        Ok(report)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemoryBenchmarkReport {
+    pub result: memory::MemoryBenchmarkResult,
+    pub caches: Vec<CacheLevel>,
+    pub assessment: memory::MemoryAssessment,
+    pub history_id: Option<String>,
+}
+
+fn memory_setup() -> anyhow::Result<memory::MemorySetup> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi()?;
+    let modules = hardware::memory::get_memory_info(&mut ctx)?;
+    // The slowest module sets the pace for all of them
+    Ok(memory::MemorySetup {
+        modules: modules.len() as u32,
+        rated_mts: modules.iter().map(|m| m.speed).filter(|s| *s > 0).min(),
+        configured_mts: modules.iter().filter_map(|m| m.configured_clock_speed).filter(|s| *s > 0).min(),
+    })
+}
+
+// `duration_ms` applies to each bandwidth test; the latency sweep has a fixed length
+#[tauri::command]
+pub async fn run_memory_benchmark(
+    window: tauri::Window,
+    state: tauri::State<'_, BenchmarkState>,
+    duration_ms: Option<u64>,
+    threads: Option<usize>,
+) -> Result<MemoryBenchmarkReport, String> {
+    let _guard = state.start()?;
+    let cancel = state.cancel.clone();
+    let duration = duration(duration_ms);
+    let threads = threads.unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1));
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let caches = hardware::cpu::get_cache_levels();
+        let result = memory::run(threads, duration, &caches, &cancel, &|p| window.emit(PROGRESS_EVENT, p).unwrap_or(()))
+            .map_err(|_| "Benchmark cancelled".to_string())?;
+        let setup = memory_setup().unwrap_or_default();
+        let assessment = memory::assess(&result, &setup);
+        let mut report = MemoryBenchmarkReport { result, caches, assessment, history_id: None };
+        report.history_id = history::record("memory", &report, history::memory_metrics(&report.result), None).ok();
+        Ok(report)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+const DEFAULT_MEMTEST_FRACTION: f64 = 0.5;
+// Never grab all free memory, Windows starts paging everything else out
+const MAX_MEMTEST_FRACTION: f64 = 0.9;
+const MAX_BIT_FADE_SECS: u64 = 600;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct MemtestReport {
+    pub result: memtest::MemtestResult,
+    pub available_bytes: u64,
+    pub notes: Vec<String>,
+}
+
+// Tests `fraction` of the currently free RAM with the tests in `tests` (ids from memtest::TESTS, all by default)
+#[tauri::command]
+pub async fn run_memory_test(
+    window: tauri::Window,
+    state: tauri::State<'_, BenchmarkState>,
+    fraction: Option<f64>,
+    tests: Option<Vec<String>>,
+    bit_fade_secs: Option<u64>,
+) -> Result<MemtestReport, String> {
+    let selected: Vec<&'static str> = memtest::TESTS.iter()
+        .map(|(id, _, _)| *id)
+        .filter(|id| tests.as_ref().is_none_or(|t| t.iter().any(|s| s == id)))
+        .collect();
+    if selected.is_empty() {
+        return Err("No known memory test selected".to_string());
+    }
+    let _guard = state.start()?;
+    let cancel = state.cancel.clone();
+    let fraction = fraction.unwrap_or(DEFAULT_MEMTEST_FRACTION).clamp(0.01, MAX_MEMTEST_FRACTION);
+    let bit_fade = Duration::from_secs(bit_fade_secs.unwrap_or(memtest::DEFAULT_BIT_FADE_SECS).min(MAX_BIT_FADE_SECS));
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut sys = sysinfo::System::new();
+        sys.refresh_memory();
+        let available_bytes = sys.available_memory();
+        let mut region = memtest::TestRegion::allocate((available_bytes as f64 * fraction) as usize)?;
+        let mut notes = Vec::new();
+        if !region.locked {
+            notes.push("The test memory could not be locked into RAM, Windows may have paged parts of it out during the run. Run as administrator or close other programs for full coverage".to_string());
+        }
+        let mut result = memtest::run(region.as_mut_slice(), threads, &selected, bit_fade, &cancel, None, &|p| window.emit(PROGRESS_EVENT, p).unwrap_or(()))
+            .map_err(|_| "Memory test cancelled".to_string())?;
+        result.locked = region.locked;
+        if !result.passed {
+            notes.push("Memory errors were found. Re-test each module on its own (and at stock speed, with XMP/EXPO off) to find the faulty one".to_string());
+        }
+        Ok(MemtestReport { result, available_bytes, notes })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BenchmarkVolume {
+    pub volume: String,
+    pub label: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+    pub removable: bool,
+}
+
+#[tauri::command]
+pub fn get_benchmark_volumes() -> Vec<BenchmarkVolume> {
+    sysinfo::Disks::new_with_refreshed_list().iter()
+        .map(|d| BenchmarkVolume {
+            volume: d.mount_point().to_string_lossy().trim_end_matches('\\').to_string(),
+            label: d.name().to_string_lossy().to_string(),
+            total_bytes: d.total_space(),
+            available_bytes: d.available_space(),
+            removable: d.is_removable(),
+        })
+        .collect()
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DiskBenchmarkReport {
+    // Physical disk behind the volume, when WMI can tell
+    pub disk: Option<hardware::disk::DiskInfo>,
+    pub result: disk::DiskBenchmarkResult,
+    pub findings: Vec<String>,
+    pub history_id: Option<String>,
+}
+
+// Free space to leave on the volume besides the test file
+const DISK_BENCH_SPARE_MB: u64 = 1024;
+
+// "d:\" and "D:" both become "D:"
+fn drive_letter(volume: &str) -> Result<String, String> {
+    let volume = volume.trim().trim_end_matches(['\\', '/']).to_uppercase();
+    if volume.len() != 2 || !volume.ends_with(':') || !volume.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(format!("'{}' is not a drive letter", volume));
+    }
+    Ok(volume)
+}
+
+fn check_free_space(volume: &str, size_mb: u64) -> Result<(), String> {
+    let available_mb = get_benchmark_volumes().into_iter()
+        .find(|v| v.volume == volume)
+        .map(|v| v.available_bytes / 1024 / 1024)
+        .ok_or_else(|| format!("Volume {} not found", volume))?;
+    if available_mb < size_mb + DISK_BENCH_SPARE_MB {
+        return Err(format!("{} needs {} MB free for the test file, {} MB available", volume, size_mb + DISK_BENCH_SPARE_MB, available_mb));
+    }
+    Ok(())
+}
+
+// `volume` is a drive letter ("D:"); `size_mb` sets the test file, `duration_ms` each read and write pass
+#[tauri::command]
+pub async fn run_disk_benchmark(
+    window: tauri::Window,
+    state: tauri::State<'_, BenchmarkState>,
+    volume: String,
+    size_mb: Option<u64>,
+    duration_ms: Option<u64>,
+) -> Result<DiskBenchmarkReport, String> {
+    let volume = drive_letter(&volume)?;
+    let size_mb = size_mb.unwrap_or(disk::DEFAULT_FILE_MB).clamp(disk::MIN_FILE_MB, disk::MAX_FILE_MB);
+    check_free_space(&volume, size_mb)?;
+    let _guard = state.start()?;
+    let cancel = state.cancel.clone();
+    let duration = duration(duration_ms);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = disk::run(&volume, size_mb, duration, &cancel, &|p| window.emit(PROGRESS_EVENT, p).unwrap_or(()))?;
+        let mut ctx = HardwareContext::new();
+        let disk_info = ctx.init_wmi().ok()
+            .and_then(|_| hardware::disk::get_volume_disk_index(&ctx, &volume))
+            .and_then(|index| hardware::disk::get_disk_info(&ctx).ok()?.into_iter().find(|d| d.index == Some(index)));
+        let mut findings = disk::assess(&result, disk_info.as_ref());
+        // The run itself is done, a failed save must not throw it away
+        if let Some(Err(e)) = disk_info.as_ref().map(|info| disk::save_result(info, &result)) {
+            findings.push(format!("The result could not be saved, the hardware view will not show it: {}", e));
+        }
+        let mut report = DiskBenchmarkReport { disk: disk_info, result, findings, history_id: None };
+        report.history_id = history::record("disk", &report, history::disk_metrics(&report.result), report.disk.as_ref()).ok();
+        Ok(report)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// Every option has a default; `storage_volume` adds a write/verify loop on that drive.
+// Sensor samples go out as `stress_sample` events next to the usual progress events.
+#[tauri::command]
+pub async fn run_stress_test(
+    window: tauri::Window,
+    state: tauri::State<'_, BenchmarkState>,
+    options: Option<stress::StressOptions>,
+) -> Result<stress::StressReport, String> {
+    let mut options = options.unwrap_or_default().clamped();
+    if !options.cpu && !options.memory && options.storage_volume.is_none() {
+        return Err("Select at least one load to run".to_string());
+    }
+    if let Some(volume) = &options.storage_volume {
+        let volume = drive_letter(volume)?;
+        check_free_space(&volume, stress::STORAGE_FILE_MB)?;
+        options.storage_volume = Some(volume);
+    }
+    let _guard = state.start()?;
+    let cancel = state.cancel.clone();
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut region = if options.memory {
+            let mut sys = sysinfo::System::new();
+            sys.refresh_memory();
+            Some(memtest::TestRegion::allocate((sys.available_memory() as f64 * options.memory_fraction) as usize)?)
+        } else {
+            None
+        };
+        let storage = options.storage_volume.as_deref().map(disk::test_file_path);
+        let mut ctx = HardwareContext::new();
+        let wmi = ctx.init_wmi().is_ok();
+        let mut read_sensors = || if wmi { hardware::sensors::get_sensor_reading(&ctx).unwrap_or_default() } else { Default::default() };
+        let planned = options.duration_secs as f64;
+        let on_sample = |sample: &stress::StressSample| {
+            window.emit(stress::SAMPLE_EVENT, sample).unwrap_or(());
+            let progress = BenchmarkProgress {
+                kind: "stress".to_string(),
+                test: "stress".to_string(),
+                phase: "multi".to_string(),
+                percent: (sample.elapsed_secs / planned * 100.0).min(100.0) as u32,
+            };
+            window.emit(PROGRESS_EVENT, progress).unwrap_or(());
+        };
+        let mut report = stress::run(&options, threads, region.as_mut().map(|r| r.as_mut_slice()), storage.as_deref(), &cancel, &mut read_sensors, &on_sample);
+        // Cancelled runs say nothing about stability
+        if report.stop_reason != stress::StopReason::Cancelled {
+            report.history_id = history::record("stress", &report, history::stress_metrics(&report), None).ok();
+        }
+        Ok(report)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+pub fn cancel_benchmark(state: tauri::State<'_, BenchmarkState>) {
+    state.cancel.store(true, Ordering::SeqCst);
+}
+
+// Stored runs, newest first and without their full reports; `kind` filters by benchmark
+#[tauri::command]
+pub fn list_benchmark_runs(kind: Option<String>) -> Vec<history::BenchmarkRun> {
+    history::list(kind.as_deref())
+}
+
+#[tauri::command]
+pub fn get_benchmark_run(id: String) -> Result<history::BenchmarkRun, String> {
+    history::get(&id)
+}
+
+#[tauri::command]
+pub fn delete_benchmark_run(id: String) -> Result<(), String> {
+    history::delete(&id)
+}
+
+// Metric by metric change from `base` to `other`, both runs of the same kind
+#[tauri::command]
+pub fn compare_benchmark_runs(base: String, other: String) -> Result<history::RunComparison, String> {
+    history::compare(&base, &other)
+}
+
+// The run against bundled reference results for the closest matching hardware
+#[tauri::command]
+pub fn compare_benchmark_reference(id: String) -> Result<Vec<history::RunComparison>, String> {
+    history::compare_with_references(&id)
+}
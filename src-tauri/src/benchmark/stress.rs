@@ -0,0 +1,395 @@
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use super::{cpu, disk, memtest};
+use crate::hardware::sensors::SensorReading;
+
+// Stability test: the CPU benchmark workloads with every result checked against a known-good
+// run, memtest patterns over part of the free RAM and optionally a write/verify loop on a disk,
+// all at once for a set time. Sensors are sampled every second and the run stops hard when the
+// CPU reaches the temperature limit.
+
+pub const SAMPLE_EVENT: &str = "stress_sample";
+pub const DEFAULT_DURATION_SECS: u64 = 600;
+pub const MIN_DURATION_SECS: u64 = 30;
+pub const MAX_DURATION_SECS: u64 = 24 * 3600;
+pub const DEFAULT_TEMP_LIMIT_C: f64 = 95.0;
+pub const MIN_TEMP_LIMIT_C: f64 = 60.0;
+pub const MAX_TEMP_LIMIT_C: f64 = 105.0;
+pub const DEFAULT_MEMORY_FRACTION: f64 = 0.25;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const TICK: Duration = Duration::from_millis(100);
+// Samples taken before heat builds up, where the boost clock shows
+const WARMUP_SAMPLES: usize = 10;
+// Clock lost between warm-up and the sustained part worth reporting
+const CLOCK_DROP_FINDING: f64 = 0.10;
+// Capped at or above this: the CPU is near TjMax (95-105 °C), and thermal zones read a bit low
+const THERMAL_THROTTLE_C: f64 = 90.0;
+pub const STORAGE_FILE_MB: u64 = 1024;
+const MEMORY_TESTS: [&str; 2] = ["moving_inversions", "random_address"];
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StressOptions {
+    pub duration_secs: u64,
+    pub cpu: bool,
+    pub memory: bool,
+    // Share of the free RAM the memory load tests
+    pub memory_fraction: f64,
+    // Drive letter for the storage loop, none to leave storage out
+    pub storage_volume: Option<String>,
+    pub temp_limit_c: f64,
+}
+
+impl Default for StressOptions {
+    fn default() -> Self {
+        StressOptions {
+            duration_secs: DEFAULT_DURATION_SECS,
+            cpu: true,
+            memory: true,
+            memory_fraction: DEFAULT_MEMORY_FRACTION,
+            storage_volume: None,
+            temp_limit_c: DEFAULT_TEMP_LIMIT_C,
+        }
+    }
+}
+
+impl StressOptions {
+    pub fn clamped(mut self) -> Self {
+        self.duration_secs = self.duration_secs.clamp(MIN_DURATION_SECS, MAX_DURATION_SECS);
+        self.memory_fraction = self.memory_fraction.clamp(0.01, 0.9);
+        self.temp_limit_c = self.temp_limit_c.clamp(MIN_TEMP_LIMIT_C, MAX_TEMP_LIMIT_C);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct StressSample {
+    pub elapsed_secs: f64,
+    pub sensors: SensorReading,
+    // Running totals since the start
+    pub cpu_errors: u64,
+    pub memory_errors: u64,
+    pub storage_errors: u64,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    Completed,
+    TemperatureLimit,
+    Cancelled,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct StressReport {
+    pub planned_secs: u64,
+    pub duration_secs: f64,
+    pub stop_reason: StopReason,
+    pub threads: usize,
+    pub memory_tested_bytes: u64,
+    // Full passes over the memory region; errors count from the first access, coverage only per pass
+    pub memory_passes: u64,
+    pub samples: Vec<StressSample>,
+    pub max_temp_c: Option<f64>,
+    // Highest clock during warm-up and the median of the second half
+    pub start_clock_mhz: Option<f64>,
+    pub sustained_clock_mhz: Option<f64>,
+    pub clock_drop_pct: Option<f64>,
+    // Seconds with "% Performance Limit" under 100
+    pub throttled_secs: u64,
+    pub thermal_throttling: bool,
+    pub cpu_errors: u64,
+    pub memory_errors: u64,
+    pub storage_errors: u64,
+    // No errors, no load died early, the memory load finished a pass and the full duration ran
+    pub stable: bool,
+    pub findings: Vec<String>,
+    // Entry in the run history, set by the command
+    pub history_id: Option<String>,
+}
+
+#[derive(Default)]
+struct Counters {
+    cpu: AtomicU64,
+    memory: AtomicU64,
+    memory_passes: AtomicU64,
+    storage: AtomicU64,
+}
+
+fn cpu_load(expected: &[u64], counters: &Counters, stop: &AtomicBool) {
+    let mut units: Vec<_> = cpu::WORKLOADS.iter().map(|w| w.unit()).collect();
+    while !stop.load(Ordering::Relaxed) {
+        for (unit, want) in units.iter_mut().zip(expected) {
+            if black_box(unit()) != *want {
+                counters.cpu.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+fn memory_load(region: &mut [u64], threads: usize, counters: &Counters, stop: &AtomicBool) {
+    // Errors go straight into the counters, so a pass cut short by the stop flag still reports them
+    while memtest::run(region, threads, &MEMORY_TESTS, Duration::ZERO, stop, Some(&counters.memory), &|_| {}).is_ok() {
+        counters.memory_passes.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn storage_load(path: &Path, counters: &Counters, stop: &AtomicBool, failure: &Mutex<Option<String>>) {
+    let _cleanup = disk::TestFile(path.to_path_buf());
+    let mut seed = 0x9E37_79B9_7F4A_7C15u64;
+    while !stop.load(Ordering::Relaxed) {
+        match disk::verify_pass(path, STORAGE_FILE_MB, seed, stop) {
+            Ok(bad) => counters.storage.fetch_add(bad, Ordering::Relaxed),
+            Err(e) => {
+                *failure.lock().unwrap() = Some(e);
+                return;
+            }
+        };
+        seed = seed.rotate_left(7).wrapping_add(1);
+    }
+}
+
+// Runs the selected loads until the duration is up, the temperature limit is reached or
+// `cancel` is set. `memory` is the region for the memory load, `storage` the test file path.
+pub fn run(
+    options: &StressOptions,
+    threads: usize,
+    memory: Option<&mut [u64]>,
+    storage: Option<&Path>,
+    cancel: &AtomicBool,
+    read_sensors: &mut dyn FnMut() -> SensorReading,
+    on_sample: &dyn Fn(&StressSample),
+) -> StressReport {
+    let threads = threads.max(1);
+    let counters = Counters::default();
+    let stop = AtomicBool::new(false);
+    let storage_failure = Mutex::new(None);
+    // Known-good results, taken before the CPU heats up
+    let expected: Vec<u64> = cpu::WORKLOADS.iter().map(|w| w.unit()()).collect();
+    let memory_tested_bytes = memory.as_ref().map_or(0, |m| m.len() as u64 * 8);
+    let mut samples = Vec::new();
+    let mut stop_reason = StopReason::Completed;
+    let start = Instant::now();
+
+    std::thread::scope(|scope| {
+        let (counters, stop, expected, storage_failure) = (&counters, &stop, &expected, &storage_failure);
+        if options.cpu {
+            for _ in 0..threads {
+                scope.spawn(move || cpu_load(expected, counters, stop));
+            }
+        }
+        if let Some(region) = memory {
+            scope.spawn(move || memory_load(region, (threads / 4).max(1), counters, stop));
+        }
+        if let Some(path) = storage {
+            scope.spawn(move || storage_load(path, counters, stop, storage_failure));
+        }
+
+        let duration = Duration::from_secs(options.duration_secs);
+        let mut next_sample = start + SAMPLE_INTERVAL;
+        loop {
+            if cancel.load(Ordering::Relaxed) {
+                stop_reason = StopReason::Cancelled;
+                break;
+            }
+            if Instant::now() < next_sample {
+                std::thread::sleep(TICK);
+                continue;
+            }
+            next_sample += SAMPLE_INTERVAL;
+            let sample = StressSample {
+                elapsed_secs: (start.elapsed().as_secs_f64() * 10.0).round() / 10.0,
+                sensors: read_sensors(),
+                cpu_errors: counters.cpu.load(Ordering::Relaxed),
+                memory_errors: counters.memory.load(Ordering::Relaxed),
+                storage_errors: counters.storage.load(Ordering::Relaxed),
+            };
+            on_sample(&sample);
+            let too_hot = sample.sensors.cpu_temp_c.is_some_and(|t| t >= options.temp_limit_c);
+            samples.push(sample);
+            if too_hot {
+                stop_reason = StopReason::TemperatureLimit;
+                break;
+            }
+            if start.elapsed() >= duration {
+                break;
+            }
+        }
+        stop.store(true, Ordering::Relaxed);
+    });
+
+    let mut report = StressReport {
+        planned_secs: options.duration_secs,
+        duration_secs: (start.elapsed().as_secs_f64() * 10.0).round() / 10.0,
+        stop_reason,
+        threads,
+        memory_tested_bytes,
+        memory_passes: counters.memory_passes.load(Ordering::Relaxed),
+        samples,
+        max_temp_c: None,
+        start_clock_mhz: None,
+        sustained_clock_mhz: None,
+        clock_drop_pct: None,
+        throttled_secs: 0,
+        thermal_throttling: false,
+        cpu_errors: counters.cpu.load(Ordering::Relaxed),
+        memory_errors: counters.memory.load(Ordering::Relaxed),
+        storage_errors: counters.storage.load(Ordering::Relaxed),
+        stable: false,
+        findings: Vec::new(),
+        history_id: None,
+    };
+    let storage_failure = storage_failure.into_inner().unwrap();
+    analyze(&mut report, options, storage_failure.as_deref());
+    report
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    Some(values[values.len() / 2])
+}
+
+// Fills the summary fields and findings from the samples and error counts. `storage_failure` is
+// the I/O error that ended the storage load early, if it did.
+pub fn analyze(report: &mut StressReport, options: &StressOptions, storage_failure: Option<&str>) {
+    let samples = &report.samples;
+    let findings = &mut report.findings;
+    report.max_temp_c = samples.iter().filter_map(|s| s.sensors.cpu_temp_c).reduce(f64::max);
+    report.start_clock_mhz = samples.iter().take(WARMUP_SAMPLES).filter_map(|s| s.sensors.clock_mhz).reduce(f64::max);
+    report.sustained_clock_mhz = median(samples.iter().skip(samples.len() / 2).filter_map(|s| s.sensors.clock_mhz).collect());
+    // Runs too short for a separate sustained phase compare warm-up with itself
+    if samples.len() > WARMUP_SAMPLES {
+        report.clock_drop_pct = report.start_clock_mhz
+            .zip(report.sustained_clock_mhz)
+            .filter(|(start, _)| *start > 0.0)
+            .map(|(start, sustained)| ((1.0 - sustained / start) * 1000.0).round() / 10.0);
+    }
+    let throttled: Vec<&StressSample> = samples.iter().filter(|s| s.sensors.performance_limit_pct.is_some_and(|p| p < 100)).collect();
+    report.throttled_secs = throttled.len() as u64 * SAMPLE_INTERVAL.as_secs();
+    let hot_throttled = throttled.iter().filter(|s| s.sensors.cpu_temp_c.is_some_and(|t| t >= THERMAL_THROTTLE_C)).count();
+    report.thermal_throttling = hot_throttled > 0;
+
+    if report.stop_reason == StopReason::TemperatureLimit {
+        findings.push(format!(
+            "Stopped after {:.0} s: the CPU reached {:.0} °C (limit {:.0} °C). Check the cooler mounting, thermal paste and case airflow",
+            report.duration_secs, report.max_temp_c.unwrap_or(options.temp_limit_c), options.temp_limit_c
+        ));
+    }
+    if report.thermal_throttling {
+        findings.push(format!(
+            "The CPU was clocked down for {} s at {:.0} °C and above: thermal throttling, the cooling cannot hold the sustained load",
+            hot_throttled, THERMAL_THROTTLE_C
+        ));
+    } else if report.throttled_secs > 0 {
+        findings.push(format!(
+            "The clock was capped for {} s below thermal limits, by the power or current limits (PL1/PL2, PPT/EDC) set in the firmware",
+            report.throttled_secs
+        ));
+    }
+    if let (Some(drop), Some(start), Some(sustained)) = (report.clock_drop_pct, report.start_clock_mhz, report.sustained_clock_mhz) {
+        if drop >= CLOCK_DROP_FINDING * 100.0 {
+            findings.push(format!("The clock fell from {:.0} MHz at the start to {:.0} MHz sustained ({:.0}% lower)", start, sustained, drop));
+        }
+    }
+    if report.cpu_errors > 0 {
+        findings.push(format!(
+            "CPU workloads returned wrong results {} times: the CPU is unstable. Undo overclocking or undervolting, or check the VRM cooling",
+            report.cpu_errors
+        ));
+    }
+    if report.memory_errors > 0 {
+        findings.push(format!(
+            "{} memory errors under load. Test at stock speed with XMP/EXPO off, then each module on its own",
+            report.memory_errors
+        ));
+    }
+    let memory_unverified = report.memory_tested_bytes > 0 && report.memory_passes == 0;
+    if memory_unverified {
+        findings.push(format!(
+            "The memory load did not finish a full pass over {} MB, so the memory is not verified. Run longer or test less memory",
+            report.memory_tested_bytes / 1024 / 1024
+        ));
+    }
+    if report.storage_errors > 0 {
+        findings.push(format!(
+            "{} blocks did not read back as written. Back up the drive and check its SMART status and cabling",
+            report.storage_errors
+        ));
+    }
+    if let Some(e) = storage_failure {
+        findings.push(format!("The storage load stopped early: {}", e));
+    }
+    if options.cpu && report.max_temp_c.is_none() {
+        findings.push("No temperature sensor was readable, so the temperature limit could not be enforced. Watch temperatures with the board vendor's tool".to_string());
+    }
+
+    report.stable = report.stop_reason == StopReason::Completed
+        && report.cpu_errors == 0
+        && report.memory_errors == 0
+        && !memory_unverified
+        && report.storage_errors == 0
+        && storage_failure.is_none();
+    if report.stable && findings.is_empty() {
+        findings.push(format!("No errors and no throttling in {:.0} s under load", report.duration_secs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(memory_passes: u64) -> StressReport {
+        StressReport {
+            planned_secs: 30,
+            duration_secs: 30.0,
+            stop_reason: StopReason::Completed,
+            threads: 4,
+            memory_tested_bytes: 4 << 30,
+            memory_passes,
+            samples: Vec::new(),
+            max_temp_c: None,
+            start_clock_mhz: None,
+            sustained_clock_mhz: None,
+            clock_drop_pct: None,
+            throttled_secs: 0,
+            thermal_throttling: false,
+            cpu_errors: 0,
+            memory_errors: 0,
+            storage_errors: 0,
+            stable: false,
+            findings: Vec::new(),
+            history_id: None,
+        }
+    }
+
+    fn options() -> StressOptions {
+        StressOptions { cpu: false, ..StressOptions::default() }
+    }
+
+    #[test]
+    fn memory_without_a_full_pass_is_unverified() {
+        let mut unverified = report(0);
+        analyze(&mut unverified, &options(), None);
+        assert!(!unverified.stable);
+        assert!(unverified.findings.iter().any(|f| f.contains("not verified")));
+
+        let mut verified = report(1);
+        analyze(&mut verified, &options(), None);
+        assert!(verified.stable);
+    }
+
+    #[test]
+    fn storage_failure_is_not_stable() {
+        let mut failed = report(1);
+        analyze(&mut failed, &options(), Some("disk full"));
+        assert!(!failed.stable);
+        assert!(failed.findings.iter().any(|f| f.contains("disk full")));
+    }
+}
@@ -0,0 +1,350 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use winreg::enums::*;
+use winreg::RegKey;
+
+// Dates come from many places in different shapes:
+//   WMI (CIM_DATETIME)        20240115000000.000000-000
+//   Registry InstallDate      20240115
+//   pnputil / locale strings  1/15/2024, 15.01.2024, 2024/1/15
+// Everything is normalized to a UTC Unix timestamp, serialized as ISO 8601 so it sorts as text too.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's days_from_civil)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl Timestamp {
+    pub fn from_unix(seconds: i64) -> Self {
+        Timestamp(seconds)
+    }
+
+    pub fn unix(&self) -> i64 {
+        self.0
+    }
+
+    pub fn now() -> Self {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0);
+        Timestamp(secs)
+    }
+
+    // Validates the calendar date, years outside 1601..=9999 (the FILETIME/CIM range) are rejected
+    pub fn from_ymd_hms(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<Self> {
+        if !(1601..=9999).contains(&year) || month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+        let days = days_from_civil(year, month, day);
+        Some(Timestamp(days * SECONDS_PER_DAY + hour as i64 * 3600 + minute as i64 * 60 + second.min(59) as i64))
+    }
+
+    pub fn from_ymd(year: i64, month: u32, day: u32) -> Option<Self> {
+        Self::from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    pub fn to_ymd_hms(&self) -> (i64, u32, u32, u32, u32, u32) {
+        let days = self.0.div_euclid(SECONDS_PER_DAY);
+        let secs = self.0.rem_euclid(SECONDS_PER_DAY);
+        let (y, m, d) = civil_from_days(days);
+        (y, m, d, (secs / 3600) as u32, ((secs % 3600) / 60) as u32, (secs % 60) as u32)
+    }
+
+    pub fn to_iso8601(&self) -> String {
+        let (y, mo, d, h, mi, s) = self.to_ymd_hms();
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, mo, d, h, mi, s)
+    }
+
+    pub fn to_date_string(&self) -> String {
+        let (y, mo, d, ..) = self.to_ymd_hms();
+        format!("{:04}-{:02}-{:02}", y, mo, d)
+    }
+
+    // Whole days between this timestamp and `now`, zero if it lies in the future
+    pub fn age_days(&self, now: Timestamp) -> u32 {
+        ((now.0 - self.0).max(0) / SECONDS_PER_DAY) as u32
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_iso8601())
+    }
+}
+
+fn parse_digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+// CIM_DATETIME: yyyymmddHHMMSS.mmmmmmsUUU, where sUUU is the UTC offset in minutes.
+// Fields the provider does not know are filled with '*'.
+pub fn parse_cim_datetime(s: &str) -> Option<Timestamp> {
+    let s = s.trim();
+    if s.len() < 14 || !s.is_ascii() {
+        return None;
+    }
+    let year = parse_digits(&s[0..4])? as i64;
+    let month = parse_digits(&s[4..6])?;
+    let day = parse_digits(&s[6..8])?;
+    let hour = parse_digits(&s[8..10]).unwrap_or(0);
+    let minute = parse_digits(&s[10..12]).unwrap_or(0);
+    let second = parse_digits(&s[12..14]).unwrap_or(0);
+    let local = Timestamp::from_ymd_hms(year, month, day, hour, minute, second)?;
+
+    // "+480" means local time is UTC+8
+    let offset_minutes = s.get(21..25).and_then(|tz| {
+        let sign = match tz.chars().next()? {
+            '+' => 1,
+            '-' => -1,
+            _ => return None,
+        };
+        parse_digits(&tz[1..]).map(|m| sign * m as i64)
+    }).unwrap_or(0);
+
+    Some(Timestamp(local.0 - offset_minutes * 60))
+}
+
+// Registry InstallDate and similar compact dates: YYYYMMDD
+pub fn parse_compact_date(s: &str) -> Option<Timestamp> {
+    let s = s.trim();
+    if s.len() != 8 || !s.is_ascii() {
+        return None;
+    }
+    Timestamp::from_ymd(parse_digits(&s[0..4])? as i64, parse_digits(&s[4..6])?, parse_digits(&s[6..8])?)
+}
+
+fn expand_year(year: u32, digits: usize) -> i64 {
+    if digits <= 2 {
+        // Two digit years: 70-99 are 19xx, everything else 20xx
+        if year >= 70 { 1900 + year as i64 } else { 2000 + year as i64 }
+    } else {
+        year as i64
+    }
+}
+
+// Locale formatted dates (optionally followed by a time, which is ignored).
+// Year-first is detected from a 4 digit leading field; for the other two orders
+// a field above 12 decides, otherwise `order` (the system short date order) is used.
+pub fn parse_locale_date(s: &str, order: DateOrder) -> Option<Timestamp> {
+    let date_part = s.trim().split(|c: char| c.is_whitespace() || c == 'T').next()?;
+    let fields: Vec<&str> = date_part
+        .split(['/', '.', '-'])
+        .filter(|f| !f.is_empty())
+        .collect();
+    if fields.len() != 3 {
+        return None;
+    }
+    let a = parse_digits(fields[0])?;
+    let b = parse_digits(fields[1])?;
+    let c = parse_digits(fields[2])?;
+
+    if fields[0].len() == 4 || order == DateOrder::YearMonthDay && fields[2].len() <= 2 {
+        return Timestamp::from_ymd(expand_year(a, fields[0].len()), b, c);
+    }
+
+    let year = expand_year(c, fields[2].len());
+    let (month, day) = if a > 12 {
+        (b, a)
+    } else if b > 12 {
+        (a, b)
+    } else if order == DateOrder::DayMonthYear {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    Timestamp::from_ymd(year, month, day)
+}
+
+// Tries every known format, most specific first
+pub fn parse_any(s: &str, order: DateOrder) -> Option<Timestamp> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    if s.len() >= 14 && s.as_bytes()[..8].iter().all(|b| b.is_ascii_digit()) {
+        if let Some(ts) = parse_cim_datetime(s) {
+            return Some(ts);
+        }
+    }
+    if let Some(ts) = parse_compact_date(s) {
+        return Some(ts);
+    }
+    // ISO 8601 with time, e.g. our own serialized output
+    if s.len() >= 19 && s.is_ascii() && s.as_bytes()[4] == b'-' && s.as_bytes()[10] == b'T' {
+        let date = parse_locale_date(&s[..10], DateOrder::YearMonthDay)?;
+        let time: Vec<u32> = s[11..19].split(':').filter_map(parse_digits).collect();
+        if time.len() == 3 {
+            return Some(Timestamp(date.0 + time[0] as i64 * 3600 + time[1] as i64 * 60 + time[2] as i64));
+        }
+        return Some(date);
+    }
+    parse_locale_date(s, order)
+}
+
+// HKCU\Control Panel\International\iDate: 0 = M/D/Y, 1 = D/M/Y, 2 = Y/M/D
+pub fn system_date_order() -> DateOrder {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let value = hkcu.open_subkey("Control Panel\\International")
+        .and_then(|key| key.get_value::<String, _>("iDate"))
+        .unwrap_or_default();
+    match value.trim() {
+        "1" => DateOrder::DayMonthYear,
+        "2" => DateOrder::YearMonthDay,
+        _ => DateOrder::MonthDayYear,
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_iso8601())
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a date string or Unix timestamp")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Timestamp, E> {
+        Ok(Timestamp(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Timestamp, E> {
+        Ok(Timestamp(v as i64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Timestamp, E> {
+        parse_any(v, DateOrder::YearMonthDay).ok_or_else(|| E::custom(format!("unrecognized date: {}", v)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+}
+
+// For fields filled straight from WMI: an odd date string must not fail the whole row
+pub fn deserialize_lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Timestamp>, D::Error> {
+    let raw: Option<String> = Option::deserialize(deserializer)?;
+    Ok(raw.and_then(|s| parse_any(&s, DateOrder::YearMonthDay)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i64, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Timestamp {
+        Timestamp::from_ymd_hms(year, month, day, hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn cim_datetime_with_offsets() {
+        assert_eq!(parse_cim_datetime("20240115083000.000000+000"), Some(utc(2024, 1, 15, 8, 30, 0)));
+        // UTC+8: 08:30 local is 00:30 UTC
+        assert_eq!(parse_cim_datetime("20240115083000.000000+480"), Some(utc(2024, 1, 15, 0, 30, 0)));
+        // UTC-5: 22:00 local is 03:00 UTC the next day
+        assert_eq!(parse_cim_datetime("20240115220000.000000-300"), Some(utc(2024, 1, 16, 3, 0, 0)));
+        // No offset at all counts as UTC
+        assert_eq!(parse_cim_datetime("20240115083000"), Some(utc(2024, 1, 15, 8, 30, 0)));
+    }
+
+    #[test]
+    fn cim_datetime_wildcards() {
+        // Unknown time and offset fall back to midnight UTC
+        assert_eq!(parse_cim_datetime("20240115******.******+***"), Some(utc(2024, 1, 15, 0, 0, 0)));
+        // Without a date there is nothing to return
+        assert_eq!(parse_cim_datetime("********083000.000000+000"), None);
+    }
+
+    #[test]
+    fn invalid_dates_are_rejected() {
+        assert_eq!(parse_cim_datetime("20230229000000.000000+000"), None);
+        assert_eq!(parse_cim_datetime("2024011"), None);
+        assert_eq!(parse_compact_date("20241301"), None);
+        assert_eq!(parse_compact_date("2024011"), None);
+        assert_eq!(parse_locale_date("31/4/2024", DateOrder::DayMonthYear), None);
+        assert_eq!(parse_any("not a date", DateOrder::MonthDayYear), None);
+        assert!(Timestamp::from_ymd(1600, 1, 1).is_none());
+        assert!(Timestamp::from_ymd(2024, 2, 29).is_some());
+    }
+
+    #[test]
+    fn compact_registry_date() {
+        assert_eq!(parse_compact_date("20240115"), Timestamp::from_ymd(2024, 1, 15));
+        assert_eq!(parse_compact_date(" 20240115 "), Timestamp::from_ymd(2024, 1, 15));
+    }
+
+    #[test]
+    fn locale_dates_from_pnputil() {
+        let expected = Timestamp::from_ymd(2024, 1, 15);
+        assert_eq!(parse_locale_date("1/15/2024", DateOrder::MonthDayYear), expected);
+        assert_eq!(parse_locale_date("15.01.2024", DateOrder::MonthDayYear), expected);
+        assert_eq!(parse_locale_date("2024/1/15", DateOrder::DayMonthYear), expected);
+        // Ambiguous fields follow the system order
+        assert_eq!(parse_locale_date("02/03/2024", DateOrder::MonthDayYear), Timestamp::from_ymd(2024, 2, 3));
+        assert_eq!(parse_locale_date("02/03/2024", DateOrder::DayMonthYear), Timestamp::from_ymd(2024, 3, 2));
+        assert_eq!(parse_locale_date("1/15/24 10:30:00", DateOrder::MonthDayYear), expected);
+    }
+
+    #[test]
+    fn iso8601_round_trip() {
+        let ts = utc(2024, 2, 29, 23, 59, 58);
+        assert_eq!(ts.to_iso8601(), "2024-02-29T23:59:58Z");
+        assert_eq!(parse_any(&ts.to_iso8601(), DateOrder::MonthDayYear), Some(ts));
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), ts);
+        assert_eq!(Timestamp::from_unix(0).to_iso8601(), "1970-01-01T00:00:00Z");
+    }
+}
@@ -0,0 +1,197 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Numeric WMI/CIM status codes mapped to named variants.
+// Serialized as the variant name, deserialized from either the raw code or a name/label,
+// so WMI rows, PowerShell output and our own exports all load into the same type.
+// Codes that are not listed (including the CIM "Unknown" codes) become `Unknown`.
+macro_rules! wmi_code_enum {
+    ($name:ident { $($variant:ident = $code:literal => $label:literal),+ $(,)? }) => {
+        #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub enum $name {
+            $($variant,)+
+            #[default]
+            Unknown,
+        }
+
+        impl $name {
+            pub fn from_code(code: u16) -> Self {
+                match code {
+                    $($code => $name::$variant,)+
+                    _ => $name::Unknown,
+                }
+            }
+
+            pub fn code(&self) -> Option<u16> {
+                match self {
+                    $($name::$variant => Some($code),)+
+                    $name::Unknown => None,
+                }
+            }
+
+            pub fn label(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $label,)+
+                    $name::Unknown => "Unknown",
+                }
+            }
+
+            pub fn from_label(text: &str) -> Self {
+                let text = text.trim();
+                $(
+                    if text.eq_ignore_ascii_case($label) || text.eq_ignore_ascii_case(stringify!($variant)) {
+                        return $name::$variant;
+                    }
+                )+
+                match text.parse::<u16>() {
+                    Ok(code) => $name::from_code(code),
+                    Err(_) => $name::Unknown,
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct CodeVisitor;
+
+                impl<'de> Visitor<'de> for CodeVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "a {} code or name", stringify!($name))
+                    }
+
+                    fn visit_u64<E: de::Error>(self, v: u64) -> Result<$name, E> {
+                        Ok(u16::try_from(v).map($name::from_code).unwrap_or($name::Unknown))
+                    }
+
+                    fn visit_i64<E: de::Error>(self, v: i64) -> Result<$name, E> {
+                        Ok(u16::try_from(v).map($name::from_code).unwrap_or($name::Unknown))
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$name, E> {
+                        Ok($name::from_label(v))
+                    }
+                }
+
+                deserializer.deserialize_any(CodeVisitor)
+            }
+        }
+    };
+}
+
+// Win32_NetworkAdapter.NetConnectionStatus
+wmi_code_enum!(NetConnectionStatus {
+    Disconnected = 0 => "Disconnected",
+    Connecting = 1 => "Connecting",
+    Connected = 2 => "Connected",
+    Disconnecting = 3 => "Disconnecting",
+    HardwareNotPresent = 4 => "Hardware Not Present",
+    HardwareDisabled = 5 => "Hardware Disabled",
+    HardwareMalfunction = 6 => "Hardware Malfunction",
+    MediaDisconnected = 7 => "Media Disconnected",
+    Authenticating = 8 => "Authenticating",
+    AuthenticationSucceeded = 9 => "Authentication Succeeded",
+    AuthenticationFailed = 10 => "Authentication Failed",
+    InvalidAddress = 11 => "Invalid Address",
+    CredentialsRequired = 12 => "Credentials Required",
+});
+
+// Win32_NetworkAdapter.AdapterTypeId (labels are the AdapterType strings)
+wmi_code_enum!(AdapterType {
+    Ethernet = 0 => "Ethernet 802.3",
+    TokenRing = 1 => "Token Ring 802.5",
+    Fddi = 2 => "Fiber Distributed Data Interface (FDDI)",
+    Wan = 3 => "Wide Area Network (WAN)",
+    LocalTalk = 4 => "LocalTalk",
+    EthernetDix = 5 => "Ethernet using DIX header format",
+    Arcnet = 6 => "ARCNET",
+    ArcnetStandard = 7 => "ARCNET (878.2)",
+    Atm = 8 => "ATM",
+    Wireless = 9 => "Wireless",
+    Infrared = 10 => "Infrared Wireless",
+    Bpc = 11 => "Bpc",
+    CoWan = 12 => "CoWan",
+    Ieee1394 = 13 => "1394",
+});
+
+// Win32_SystemSlot.CurrentUsage
+wmi_code_enum!(SlotUsage {
+    Other = 1 => "Other",
+    Available = 3 => "Available",
+    InUse = 4 => "In Use",
+    Unavailable = 5 => "Unavailable",
+});
+
+// Win32_PhysicalMemory.FormFactor
+wmi_code_enum!(MemoryFormFactor {
+    Other = 1 => "Other",
+    Sip = 2 => "SIP",
+    Dip = 3 => "DIP",
+    Zip = 4 => "ZIP",
+    Soj = 5 => "SOJ",
+    Proprietary = 6 => "Proprietary",
+    Simm = 7 => "SIMM",
+    Dimm = 8 => "DIMM",
+    Tsop = 9 => "TSOP",
+    Pga = 10 => "PGA",
+    Rimm = 11 => "RIMM",
+    SoDimm = 12 => "SODIMM",
+    Srimm = 13 => "SRIMM",
+    Smd = 14 => "SMD",
+    Ssmp = 15 => "SSMP",
+    Qfp = 16 => "QFP",
+    Tqfp = 17 => "TQFP",
+    Soic = 18 => "SOIC",
+    Lcc = 19 => "LCC",
+    Plcc = 20 => "PLCC",
+    Bga = 21 => "BGA",
+    Fpbga = 22 => "FPBGA",
+    Lga = 23 => "LGA",
+});
+
+// MSFT_PhysicalDisk.MediaType (root\Microsoft\Windows\Storage)
+wmi_code_enum!(MediaType {
+    Hdd = 3 => "HDD",
+    Ssd = 4 => "SSD",
+    Scm = 5 => "SCM",
+});
+
+impl MediaType {
+    // Win32_DiskDrive.MediaType is a free text description and never says SSD,
+    // it only tells fixed from removable. Prefer MSFT_PhysicalDisk when available.
+    pub fn from_win32_description(text: &str) -> Self {
+        let lower = text.to_lowercase();
+        if lower.contains("ssd") || lower.contains("solid") {
+            MediaType::Ssd
+        } else {
+            MediaType::from_label(text)
+        }
+    }
+
+    pub fn is_solid_state(&self) -> bool {
+        matches!(self, MediaType::Ssd | MediaType::Scm)
+    }
+}
+
+// MSFT_PhysicalDisk.BusType
+wmi_code_enum!(BusType {
+    Scsi = 1 => "SCSI",
+    Atapi = 2 => "ATAPI",
+    Ata = 3 => "ATA",
+    Ieee1394 = 4 => "1394",
+    Ssa = 5 => "SSA",
+    FibreChannel = 6 => "Fibre Channel",
+    Usb = 7 => "USB",
+    Raid = 8 => "RAID",
+    Iscsi = 9 => "iSCSI",
+    Sas = 10 => "SAS",
+    Sata = 11 => "SATA",
+    Sd = 12 => "SD",
+    Mmc = 13 => "MMC",
+    Virtual = 14 => "Virtual",
+    FileBackedVirtual = 15 => "File Backed Virtual",
+    StorageSpaces = 16 => "Storage Spaces",
+    Nvme = 17 => "NVMe",
+});
@@ -87,3 +87,42 @@ fn get_cpu_info_wmi(ctx: &HardwareContext) -> Result<Vec<CpuInfo>> {
 
     Ok(results)
 }
+
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct CacheLevel {
+    pub level: u8,
+    pub size_kb: u32,
+}
+
+// Data/unified caches as one core sees them: L1 and L2 per core, L3 shared.
+// Straight from the OS topology, Win32_CacheMemory only has package totals.
+pub fn get_cache_levels() -> Vec<CacheLevel> {
+    use winapi::um::sysinfoapi::GetLogicalProcessorInformation;
+    use winapi::um::winnt::{CacheData, CacheUnified, RelationCache, SYSTEM_LOGICAL_PROCESSOR_INFORMATION};
+
+    let entry_size = std::mem::size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>();
+    let mut len: u32 = 0;
+    // First call only reports the buffer size
+    unsafe { GetLogicalProcessorInformation(std::ptr::null_mut(), &mut len) };
+    let mut buffer: Vec<SYSTEM_LOGICAL_PROCESSOR_INFORMATION> = Vec::with_capacity(len as usize / entry_size);
+    if buffer.capacity() == 0 || unsafe { GetLogicalProcessorInformation(buffer.as_mut_ptr(), &mut len) } == 0 {
+        return Vec::new();
+    }
+    unsafe { buffer.set_len(len as usize / entry_size) };
+
+    let mut levels: Vec<CacheLevel> = Vec::new();
+    for info in buffer.iter().filter(|i| i.Relationship == RelationCache) {
+        let cache = unsafe { info.u.Cache() };
+        if cache.Type != CacheData && cache.Type != CacheUnified {
+            continue;
+        }
+        // Hybrid CPUs have different L1/L2 sizes per core type, keep the largest
+        let size_kb = cache.Size / 1024;
+        match levels.iter_mut().find(|l| l.level == cache.Level) {
+            Some(existing) => existing.size_kb = existing.size_kb.max(size_kb),
+            None => levels.push(CacheLevel { level: cache.Level, size_kb }),
+        }
+    }
+    levels.sort_by_key(|l| l.level);
+    levels
+}
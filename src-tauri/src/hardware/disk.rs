@@ -1,13 +1,16 @@
 use serde::{Deserialize, Serialize};
 use super::HardwareContext;
 use anyhow::Result;
+use super::codes::{BusType, MediaType};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct DiskInfo {
+    // Win32_DiskDrive.Index, the N in \\.\PhysicalDriveN
+    pub index: Option<u32>,
     pub model: String,
     pub size: u64,
-    pub media_type: String,
+    pub media_type: MediaType,
     pub bus_type: String,
     pub health_status: String,
     pub operational_status: String,
@@ -16,21 +19,42 @@ pub struct DiskInfo {
     pub partitions: Option<u32>,
     pub status: Option<String>,
     pub pcie_profile: Option<String>,
+    // Holds the Windows volume (%SystemDrive%)
+    pub is_system_disk: bool,
 }
 
 pub fn get_disk_info(ctx: &HardwareContext) -> Result<Vec<DiskInfo>> {
     // PowerShell is too slow (50x slower). Reverting to WMI.
     // We lose PCIe info but gain speed.
     let wmi = ctx.get_wmi()?;
-    let disks: Vec<WmiDisk> = wmi.raw_query("SELECT Model, Size, MediaType, InterfaceType, Status, SerialNumber, FirmwareRevision, Partitions FROM Win32_DiskDrive")?;
+    let disks: Vec<WmiDisk> = wmi.raw_query("SELECT Index, Model, Size, MediaType, InterfaceType, Status, SerialNumber, FirmwareRevision, Partitions FROM Win32_DiskDrive")?;
+
+    // Win32_DiskDrive cannot tell SSD from HDD and reports NVMe drives as SCSI.
+    // The Storage namespace can, and its DeviceId is the Win32_DiskDrive index.
+    let physical: Vec<StoragePhysicalDisk> = ctx.wmi_namespace("root\\Microsoft\\Windows\\Storage")
+        .and_then(|con| con.raw_query("SELECT DeviceId, MediaType, BusType FROM MSFT_PhysicalDisk").map_err(anyhow::Error::from))
+        .unwrap_or_default();
     
+    let system_index = get_system_disk_index(ctx);
+
     let mut results = Vec::new();
     for d in disks {
+        let storage = physical.iter().find(|p| d.index.is_some() && p.device_id.as_deref() == d.index.map(|i| i.to_string()).as_deref());
+        let media_type = storage
+            .and_then(|p| p.media_type)
+            .filter(|m| *m != MediaType::Unknown)
+            .unwrap_or_else(|| MediaType::from_win32_description(d.media_type.as_deref().unwrap_or_default()));
+        let bus_type = storage
+            .and_then(|p| p.bus_type)
+            .filter(|b| *b != BusType::Unknown)
+            .map(|b| b.label().to_string())
+            .unwrap_or(d.interface_type.unwrap_or("Unknown".to_string()));
         results.push(DiskInfo {
+            index: d.index,
             model: d.model,
             size: d.size,
-            media_type: d.media_type.unwrap_or("Unknown".to_string()),
-            bus_type: d.interface_type.unwrap_or("Unknown".to_string()),
+            media_type,
+            bus_type,
             health_status: d.status.clone().unwrap_or("Unknown".to_string()),
             operational_status: "OK".to_string(),
             serial_number: d.serial_number.unwrap_or_default(),
@@ -38,6 +62,7 @@ pub fn get_disk_info(ctx: &HardwareContext) -> Result<Vec<DiskInfo>> {
             partitions: d.partitions,
             status: d.status,
             pcie_profile: None,
+            is_system_disk: system_index.is_some() && d.index == system_index,
         });
     }
     Ok(results)
@@ -46,6 +71,7 @@ pub fn get_disk_info(ctx: &HardwareContext) -> Result<Vec<DiskInfo>> {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 struct WmiDisk {
+    index: Option<u32>,
     model: String,
     size: u64,
     media_type: Option<String>,
@@ -55,3 +81,84 @@ struct WmiDisk {
     firmware_revision: Option<String>,
     partitions: Option<u32>,
 }
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct StoragePhysicalDisk {
+    device_id: Option<String>,
+    media_type: Option<MediaType>,
+    bus_type: Option<BusType>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiLogicalDisk {
+    size: Option<u64>,
+    free_space: Option<u64>,
+}
+
+pub fn get_system_volume_size(ctx: &HardwareContext) -> Result<Option<u64>> {
+    let wmi = ctx.get_wmi()?;
+    let system_drive = std::env::var("SystemDrive").unwrap_or("C:".to_string());
+    let volumes: Vec<WmiLogicalDisk> = wmi.raw_query(&format!("SELECT Size FROM Win32_LogicalDisk WHERE DeviceID = '{}'", system_drive))?;
+    Ok(volumes.first().and_then(|v| v.size))
+}
+
+// Most free space on any local fixed volume, where a game or app could be installed
+pub fn get_largest_free_space(ctx: &HardwareContext) -> Result<Option<u64>> {
+    let wmi = ctx.get_wmi()?;
+    let volumes: Vec<WmiLogicalDisk> = wmi.raw_query("SELECT Size, FreeSpace FROM Win32_LogicalDisk WHERE DriveType = 3")?;
+    Ok(volumes.iter().filter_map(|v| v.free_space).max())
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiPartition {
+    disk_index: Option<u32>,
+}
+
+// Physical disk index behind %SystemDrive%
+pub fn get_system_disk_index(ctx: &HardwareContext) -> Option<u32> {
+    let system_drive = std::env::var("SystemDrive").unwrap_or("C:".to_string());
+    get_volume_disk_index(ctx, &system_drive)
+}
+
+// Physical disk index behind a drive letter ("D:"), following LogicalDisk -> DiskPartition.
+// Volumes spanning several disks report the first one.
+pub fn get_volume_disk_index(ctx: &HardwareContext, volume: &str) -> Option<u32> {
+    let wmi = ctx.get_wmi().ok()?;
+    let query = format!("ASSOCIATORS OF {{Win32_LogicalDisk.DeviceID='{}'}} WHERE AssocClass = Win32_LogicalDiskToPartition", volume);
+    let partitions: Vec<WmiPartition> = wmi.raw_query(&query).ok()?;
+    partitions.first().and_then(|p| p.disk_index)
+}
+
+// Cumulative I/O counters of one physical disk ("0 C:") or of all of them ("_Total"), straight
+// from the raw performance class. Rates come from the difference between two readings.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DiskIoCounters {
+    pub name: String,
+    pub disk_read_bytes_persec: u64,
+    pub disk_write_bytes_persec: u64,
+    // Completed operations
+    pub disk_reads_persec: u64,
+    pub disk_writes_persec: u64,
+    // Idle time in 100 ns units, against `timestamp`
+    pub percent_idle_time: u64,
+    #[serde(rename = "Timestamp_Sys100NS")]
+    pub timestamp: u64,
+}
+
+impl DiskIoCounters {
+    // Win32_DiskDrive.Index of the disk, None for "_Total"
+    pub fn disk_index(&self) -> Option<u32> {
+        self.name.split_whitespace().next()?.parse().ok()
+    }
+}
+
+pub fn get_disk_io_counters(ctx: &HardwareContext) -> Result<Vec<DiskIoCounters>> {
+    let wmi = ctx.get_wmi()?;
+    Ok(wmi.raw_query(
+        "SELECT Name, DiskReadBytesPersec, DiskWriteBytesPersec, DiskReadsPersec, DiskWritesPersec, PercentIdleTime, Timestamp_Sys100NS FROM Win32_PerfRawData_PerfDisk_PhysicalDisk",
+    )?)
+}
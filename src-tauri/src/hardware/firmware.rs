@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use super::HardwareContext;
+use anyhow::Result;
+use winreg::enums::*;
+use winreg::RegKey;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct FirmwareInfo {
+    pub uefi: Option<bool>,
+    pub secure_boot: Option<bool>,
+    pub tpm_present: Option<bool>,
+    pub tpm_enabled: Option<bool>,
+    pub tpm_version: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiTpm {
+    spec_version: Option<String>,
+    #[serde(rename = "IsEnabled_InitialValue")]
+    is_enabled_initial_value: Option<bool>,
+}
+
+pub fn get_firmware_info(ctx: &HardwareContext) -> Result<FirmwareInfo> {
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    let mut info = FirmwareInfo::default();
+
+    // PEFirmwareType: 1 = Legacy BIOS, 2 = UEFI
+    if let Ok(control) = hklm.open_subkey("SYSTEM\\CurrentControlSet\\Control") {
+        if let Ok(fw_type) = control.get_value::<u32, _>("PEFirmwareType") {
+            info.uefi = Some(fw_type == 2);
+        }
+    }
+
+    // This key only exists on UEFI systems, the value tells whether Secure Boot is currently on
+    if let Ok(state) = hklm.open_subkey("SYSTEM\\CurrentControlSet\\Control\\SecureBoot\\State") {
+        if let Ok(enabled) = state.get_value::<u32, _>("UEFISecureBootEnabled") {
+            info.secure_boot = Some(enabled == 1);
+        }
+        if info.uefi.is_none() {
+            info.uefi = Some(true);
+        }
+    }
+
+    // Win32_Tpm needs admin rights, which the manifest already requests
+    if let Ok(tpm_con) = ctx.wmi_namespace("root\\CIMV2\\Security\\MicrosoftTpm") {
+        if let Ok(tpms) = tpm_con.raw_query::<WmiTpm>("SELECT SpecVersion, IsEnabled_InitialValue FROM Win32_Tpm") {
+            info.tpm_present = Some(!tpms.is_empty());
+            if let Some(tpm) = tpms.first() {
+                info.tpm_enabled = tpm.is_enabled_initial_value;
+                // SpecVersion looks like "2.0, 0, 1.38", the first entry is the highest supported version
+                info.tpm_version = tpm.spec_version.as_ref()
+                    .and_then(|v| v.split(',').next())
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty());
+            }
+        }
+    }
+
+    Ok(info)
+}
@@ -3,6 +3,20 @@ use super::HardwareContext;
 use anyhow::Result;
 use winreg::enums::*;
 use winreg::RegKey;
+use crate::datetime::{self, Timestamp};
+
+// Where AdapterRAM came from, the scoring explanation lowers its confidence for guesses
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum VramSource {
+    Wmi,
+    Registry,
+    GuessedFromName,
+    #[default]
+    Unknown,
+}
+
+// Win32_VideoController.AdapterRAM is a uint32, anything at or above this is clipped
+pub const WMI_VRAM_CAP: u64 = 0xFFF0_0000;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -12,11 +26,14 @@ pub struct GpuInfo {
     pub adapter_ram: Option<u64>,
     pub video_processor: Option<String>,
     pub adapter_compatibility: Option<String>,
-    pub driver_date: Option<String>,
+    #[serde(default, deserialize_with = "datetime::deserialize_lenient")]
+    pub driver_date: Option<Timestamp>,
     pub video_mode_description: Option<String>,
     pub current_refresh_rate: Option<u32>,
     pub current_horizontal_resolution: Option<u32>,
     pub current_vertical_resolution: Option<u32>,
+    #[serde(default)]
+    pub vram_source: VramSource,
 }
 
 fn get_registry_vram(driver_desc: &str) -> Option<u64> {
@@ -51,6 +68,9 @@ pub fn get_gpu_info(ctx: &HardwareContext) -> Result<Vec<GpuInfo>> {
     let mut results: Vec<GpuInfo> = wmi.raw_query("SELECT Name, DriverVersion, AdapterRAM, VideoProcessor, AdapterCompatibility, DriverDate, VideoModeDescription, CurrentRefreshRate, CurrentHorizontalResolution, CurrentVerticalResolution FROM Win32_VideoController")?;
     
     for gpu in &mut results {
+        if gpu.adapter_ram.is_some() {
+            gpu.vram_source = VramSource::Wmi;
+        }
         // If AdapterRAM is missing or small (likely wrong for dedicated GPU), try registry
         // 1GB = 1073741824 bytes. If < 1GB, it might be wrong or iGPU.
         // We try registry to see if we can get a better value.
@@ -59,6 +79,7 @@ pub fn get_gpu_info(ctx: &HardwareContext) -> Result<Vec<GpuInfo>> {
                  // Only update if registry value is larger (more likely to be correct for dGPU)
                  if vram > gpu.adapter_ram.unwrap_or(0) {
                      gpu.adapter_ram = Some(vram);
+                     gpu.vram_source = VramSource::Registry;
                  }
              } else {
                 // Fallback: Try to guess from Name (Very rough, but better than 0)
@@ -71,6 +92,9 @@ pub fn get_gpu_info(ctx: &HardwareContext) -> Result<Vec<GpuInfo>> {
                 else if gpu.name.contains("RTX 3080") { gpu.adapter_ram = Some(10 * 1024 * 1024 * 1024); }
                 else if gpu.name.contains("RTX 3070") { gpu.adapter_ram = Some(8 * 1024 * 1024 * 1024); }
                 else if gpu.name.contains("RTX 3060") { gpu.adapter_ram = Some(12 * 1024 * 1024 * 1024); }
+                if gpu.adapter_ram.unwrap_or(0) >= 1073741824 {
+                    gpu.vram_source = VramSource::GuessedFromName;
+                }
              }
         }
     }
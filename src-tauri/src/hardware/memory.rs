@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use super::HardwareContext;
 use anyhow::Result;
+use super::codes::MemoryFormFactor;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -18,7 +19,7 @@ pub struct MemoryInfo {
     pub bank_label: Option<String>,
     pub data_width: Option<u16>,
     pub total_width: Option<u16>,
-    pub form_factor: Option<u16>,
+    pub form_factor: Option<MemoryFormFactor>,
     pub status: Option<String>,
 }
 
@@ -34,3 +35,14 @@ pub fn get_memory_info(ctx: &mut HardwareContext) -> Result<Vec<MemoryInfo>> {
     let results: Vec<MemoryInfo> = wmi.raw_query("SELECT * FROM Win32_PhysicalMemory")?;
     Ok(results)
 }
+
+// Installed module capacity, falling back to visible memory (slightly lower than installed)
+pub fn get_installed_memory_bytes(ctx: &mut HardwareContext) -> Option<u64> {
+    match get_memory_info(ctx) {
+        Ok(mems) if !mems.is_empty() => Some(mems.iter().map(|m| m.capacity).sum()),
+        _ => {
+            ctx.sys.refresh_memory();
+            Some(ctx.sys.total_memory()).filter(|t| *t > 0)
+        }
+    }
+}
@@ -7,6 +7,11 @@ pub mod sound;
 pub mod monitor;
 pub mod network;
 pub mod peripherals;
+pub mod codes;
+pub mod firmware;
+pub mod virtualization;
+pub mod os;
+pub mod sensors;
 
 use wmi::{COMLibrary, WMIConnection, WMIError};
 use anyhow::Result;
@@ -38,4 +43,12 @@ impl HardwareContext {
     pub fn get_wmi(&self) -> Result<&WMIConnection> {
         self.wmi_con.as_ref().ok_or_else(|| anyhow::anyhow!("WMI not initialized"))
     }
+
+    // Some classes (e.g. Win32_Tpm) live outside root\cimv2 and need their own connection.
+    // COM must already be initialized on this thread via init_wmi().
+    pub fn wmi_namespace(&self, namespace_path: &str) -> Result<WMIConnection> {
+        self.get_wmi()?;
+        let com_con = unsafe { COMLibrary::assume_initialized() };
+        Ok(WMIConnection::with_namespace_path(namespace_path, com_con)?)
+    }
 }
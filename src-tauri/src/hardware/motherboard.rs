@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use super::HardwareContext;
 use anyhow::Result;
+use super::codes::SlotUsage;
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -17,6 +18,9 @@ pub struct MotherboardInfo {
     pub gpu_slots: SlotInfo,
     #[serde(skip_deserializing, default)]
     pub ram_slots: SlotInfo,
+    // Maximum memory the board accepts (Win32_PhysicalMemoryArray)
+    #[serde(skip_deserializing, default)]
+    pub max_ram_gb: Option<u64>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
@@ -30,7 +34,7 @@ pub struct SlotInfo {
 #[serde(rename_all = "PascalCase")]
 struct SystemSlot {
     slot_designation: Option<String>,
-    current_usage: Option<u16>, // 3=Available, 4=In Use
+    current_usage: Option<SlotUsage>,
     description: Option<String>,
 }
 
@@ -46,9 +50,16 @@ pub fn get_motherboard_info(ctx: &HardwareContext) -> Result<Vec<MotherboardInfo
     #[serde(rename_all = "PascalCase")]
     struct MemArray {
         memory_devices: Option<u16>,
+        // Both in KB, MaxCapacity overflows above 4 TB and some firmware only fills one of them
+        max_capacity: Option<u32>,
+        max_capacity_ex: Option<u64>,
     }
-    let mem_arrays: Vec<MemArray> = wmi.raw_query("SELECT MemoryDevices FROM Win32_PhysicalMemoryArray").unwrap_or_default();
+    let mem_arrays: Vec<MemArray> = wmi.raw_query("SELECT MemoryDevices, MaxCapacity, MaxCapacityEx FROM Win32_PhysicalMemoryArray").unwrap_or_default();
     let total_ram_slots = mem_arrays.first().and_then(|m| m.memory_devices).unwrap_or(0) as u32;
+    let max_ram_gb = mem_arrays.first()
+        .and_then(|m| m.max_capacity_ex.filter(|c| *c > 0).or(m.max_capacity.map(|c| c as u64)))
+        .map(|kb| kb / 1024 / 1024)
+        .filter(|gb| *gb > 0);
     
     #[derive(Deserialize, Debug)]
     #[serde(rename_all = "PascalCase")]
@@ -82,7 +93,7 @@ pub fn get_motherboard_info(ctx: &HardwareContext) -> Result<Vec<MotherboardInfo
 
         for slot in &slots {
             let des = slot.slot_designation.clone().unwrap_or_default().to_uppercase();
-            let is_used = slot.current_usage.unwrap_or(3) == 4; // 4 = In Use
+            let is_used = slot.current_usage == Some(SlotUsage::InUse);
 
             // GPU Slots (PCIEX16)
             if des.contains("PCIEX16") || des.contains("PCIE_16") || des.contains("PCI-E X16") {
@@ -110,7 +121,202 @@ pub fn get_motherboard_info(ctx: &HardwareContext) -> Result<Vec<MotherboardInfo
             used: used_ram_slots,
             details: vec![format!("Used {} of {} slots", used_ram_slots, total_ram_slots)],
         };
+        board.max_ram_gb = max_ram_gb;
     }
 
     Ok(boards)
 }
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum FormFactor {
+    Desktop,
+    Laptop,
+    Convertible,
+    Tablet,
+    Handheld,
+    AllInOne,
+    MiniPc,
+    Server,
+    Unknown,
+}
+
+impl FormFactor {
+    // SMBIOS type 3 chassis type codes (DSP0134, table "System Enclosure or Chassis Types")
+    pub fn from_chassis_type(code: u16) -> FormFactor {
+        match code {
+            3 | 4 | 6 | 7 | 15 | 24 => FormFactor::Desktop,
+            8 | 9 | 10 | 14 => FormFactor::Laptop,
+            31 | 32 => FormFactor::Convertible,
+            30 => FormFactor::Tablet,
+            11 => FormFactor::Handheld,
+            13 => FormFactor::AllInOne,
+            5 | 16 | 34 | 35 | 36 => FormFactor::MiniPc,
+            17 | 23 | 25 | 28 | 29 => FormFactor::Server,
+            _ => FormFactor::Unknown,
+        }
+    }
+
+    // Win32_ComputerSystem.PCSystemType, used when the enclosure reports Other/Unknown
+    pub fn from_pc_system_type(code: u16) -> FormFactor {
+        match code {
+            1 | 3 => FormFactor::Desktop,
+            2 => FormFactor::Laptop,
+            4 | 5 | 7 => FormFactor::Server,
+            6 => FormFactor::MiniPc,
+            _ => FormFactor::Unknown,
+        }
+    }
+
+    pub fn is_portable(&self) -> bool {
+        matches!(self, FormFactor::Laptop | FormFactor::Convertible | FormFactor::Tablet | FormFactor::Handheld)
+    }
+
+    // Whether a standard PCIe graphics card can be added or swapped
+    pub fn supports_gpu_upgrade(&self) -> bool {
+        matches!(self, FormFactor::Desktop | FormFactor::Server)
+    }
+
+    // Laptop, mini PC and all-in-one processors are soldered (BGA) or need a matching cooling design
+    pub fn supports_cpu_upgrade(&self) -> bool {
+        matches!(self, FormFactor::Desktop | FormFactor::Server)
+    }
+
+    // Laptops usually still have SO-DIMM and M.2 slots, tablets and handhelds are soldered
+    pub fn supports_memory_upgrade(&self) -> bool {
+        matches!(self, FormFactor::Desktop | FormFactor::Server | FormFactor::Laptop | FormFactor::MiniPc | FormFactor::Unknown)
+    }
+}
+
+pub fn chassis_type_name(code: u16) -> &'static str {
+    match code {
+        1 => "Other",
+        3 => "Desktop",
+        4 => "Low Profile Desktop",
+        5 => "Pizza Box",
+        6 => "Mini Tower",
+        7 => "Tower",
+        8 => "Portable",
+        9 => "Laptop",
+        10 => "Notebook",
+        11 => "Hand Held",
+        12 => "Docking Station",
+        13 => "All in One",
+        14 => "Sub Notebook",
+        15 => "Space-saving",
+        16 => "Lunch Box",
+        17 => "Main Server Chassis",
+        18 => "Expansion Chassis",
+        19 => "SubChassis",
+        20 => "Bus Expansion Chassis",
+        21 => "Peripheral Chassis",
+        22 => "RAID Chassis",
+        23 => "Rack Mount Chassis",
+        24 => "Sealed-case PC",
+        25 => "Multi-system Chassis",
+        26 => "Compact PCI",
+        27 => "Advanced TCA",
+        28 => "Blade",
+        29 => "Blade Enclosure",
+        30 => "Tablet",
+        31 => "Convertible",
+        32 => "Detachable",
+        33 => "IoT Gateway",
+        34 => "Embedded PC",
+        35 => "Mini PC",
+        36 => "Stick PC",
+        _ => "Unknown",
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChassisInfo {
+    pub chassis_types: Vec<u16>,
+    pub chassis_type_names: Vec<String>,
+    pub form_factor: FormFactor,
+    pub is_portable: bool,
+    pub has_battery: bool,
+    // SMBIOS type 1
+    pub system_vendor: Option<String>,
+    pub system_model: Option<String>,
+    pub system_sku: Option<String>,
+    pub system_family: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct SystemEnclosure {
+    chassis_types: Option<Vec<u16>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct ComputerSystem {
+    manufacturer: Option<String>,
+    model: Option<String>,
+    #[serde(rename = "SystemSKUNumber")]
+    system_sku_number: Option<String>,
+    system_family: Option<String>,
+    #[serde(rename = "PCSystemType")]
+    pc_system_type: Option<u16>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct BatteryPresence {
+    #[allow(dead_code)]
+    name: Option<String>,
+}
+
+pub fn derive_form_factor(chassis_types: &[u16], pc_system_type: Option<u16>) -> FormFactor {
+    // Some enclosures list several types (e.g. Notebook + Docking Station), the first known one wins
+    let from_chassis = chassis_types.iter()
+        .map(|c| FormFactor::from_chassis_type(*c))
+        .find(|f| *f != FormFactor::Unknown);
+    from_chassis
+        .or_else(|| pc_system_type.map(FormFactor::from_pc_system_type))
+        .unwrap_or(FormFactor::Unknown)
+}
+
+pub fn get_chassis_info(ctx: &HardwareContext) -> Result<ChassisInfo> {
+    let wmi = ctx.get_wmi()?;
+    let enclosures: Vec<SystemEnclosure> = wmi.raw_query("SELECT ChassisTypes FROM Win32_SystemEnclosure").unwrap_or_default();
+    let systems: Vec<ComputerSystem> = wmi.raw_query("SELECT Manufacturer, Model, SystemSKUNumber, SystemFamily, PCSystemType FROM Win32_ComputerSystem").unwrap_or_default();
+    let batteries: Vec<BatteryPresence> = wmi.raw_query("SELECT Name FROM Win32_Battery").unwrap_or_default();
+
+    let chassis_types: Vec<u16> = enclosures.into_iter().flat_map(|e| e.chassis_types.unwrap_or_default()).collect();
+    let system = systems.into_iter().next();
+    let pc_system_type = system.as_ref().and_then(|s| s.pc_system_type);
+
+    let mut form_factor = derive_form_factor(&chassis_types, pc_system_type);
+    // Enclosure reported nothing useful, but a battery means it is almost certainly a laptop
+    if form_factor == FormFactor::Unknown && !batteries.is_empty() {
+        form_factor = FormFactor::Laptop;
+    }
+
+    Ok(ChassisInfo {
+        chassis_type_names: chassis_types.iter().map(|c| chassis_type_name(*c).to_string()).collect(),
+        chassis_types,
+        form_factor,
+        is_portable: form_factor.is_portable(),
+        has_battery: !batteries.is_empty(),
+        system_vendor: system.as_ref().and_then(|s| s.manufacturer.clone()),
+        system_model: system.as_ref().and_then(|s| s.model.clone()),
+        system_sku: system.as_ref().and_then(|s| s.system_sku_number.clone()),
+        system_family: system.as_ref().and_then(|s| s.system_family.clone()),
+    })
+}
+
+#[derive(Deserialize, Debug)]
+struct FanPresence {
+    #[allow(dead_code)]
+    #[serde(rename = "DeviceID")]
+    device_id: Option<String>,
+}
+
+// Only fans the firmware exposes, many boards report none at all
+pub fn get_fan_count(ctx: &HardwareContext) -> Result<usize> {
+    let wmi = ctx.get_wmi()?;
+    let fans: Vec<FanPresence> = wmi.raw_query("SELECT DeviceID FROM Win32_Fan")?;
+    Ok(fans.len())
+}
@@ -1,17 +1,18 @@
 use serde::{Deserialize, Serialize};
 use super::HardwareContext;
 use anyhow::Result;
+use super::codes::{AdapterType, NetConnectionStatus};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct NetworkInfo {
     pub name: String,
     pub manufacturer: Option<String>,
-    pub adapter_type: Option<String>,
+    pub adapter_type: Option<AdapterType>,
     pub net_connection_id: Option<String>,
     pub speed: Option<u64>,
     pub mac_address: Option<String>,
-    pub net_connection_status: Option<u16>, // 2=Connected
+    pub net_connection_status: Option<NetConnectionStatus>,
 }
 
 pub fn get_network_info(ctx: &HardwareContext) -> Result<Vec<NetworkInfo>> {
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use super::HardwareContext;
+use anyhow::Result;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct OsInfo {
+    pub caption: String,
+    pub version: String,
+    pub build_number: String,
+    pub os_architecture: Option<String>,
+}
+
+impl OsInfo {
+    pub fn build(&self) -> Option<u32> {
+        self.build_number.trim().parse().ok()
+    }
+
+    pub fn is_64bit(&self) -> Option<bool> {
+        self.os_architecture.as_ref().map(|a| a.contains("64"))
+    }
+}
+
+pub fn get_os_info(ctx: &HardwareContext) -> Result<OsInfo> {
+    let wmi = ctx.get_wmi()?;
+    let results: Vec<OsInfo> = wmi.raw_query("SELECT Caption, Version, BuildNumber, OSArchitecture FROM Win32_OperatingSystem")?;
+    results.into_iter().next().ok_or_else(|| anyhow::anyhow!("Win32_OperatingSystem returned no rows"))
+}
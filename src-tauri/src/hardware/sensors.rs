@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use super::HardwareContext;
+use anyhow::Result;
+
+// Live CPU sensors that Windows exposes without a kernel driver. Temperatures come from the ACPI
+// thermal zones: on most boards one of them tracks the CPU package, but it can lag or read a few
+// degrees off what vendor tools show, and some desktops report no zone at all.
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct SensorReading {
+    // Hottest thermal zone
+    pub cpu_temp_c: Option<f64>,
+    // Effective clock across all cores: base frequency scaled by "% Processor Performance"
+    pub clock_mhz: Option<f64>,
+    pub base_mhz: Option<u32>,
+    // "% Performance Limit": under 100 while firmware or Windows caps the clock (thermal, power, current)
+    pub performance_limit_pct: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct ThermalZone {
+    // Kelvin
+    temperature: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct AcpiThermalZone {
+    // Tenths of a Kelvin
+    current_temperature: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct ProcessorPerformance {
+    processor_frequency: Option<u32>,
+    percent_processor_performance: Option<u32>,
+    percent_performance_limit: Option<u32>,
+}
+
+const KELVIN: f64 = 273.15;
+
+// Zones reporting 0 K or absurd values are firmware placeholders
+fn plausible(celsius: f64) -> Option<f64> {
+    Some((celsius * 10.0).round() / 10.0).filter(|c| (1.0..=130.0).contains(c))
+}
+
+pub fn get_cpu_temperature(ctx: &HardwareContext) -> Result<Option<f64>> {
+    let wmi = ctx.get_wmi()?;
+    let zones: Vec<ThermalZone> = wmi.raw_query("SELECT Temperature FROM Win32_PerfFormattedData_Counters_ThermalZoneInformation").unwrap_or_default();
+    let hottest = zones.iter()
+        .filter_map(|z| z.temperature)
+        .filter_map(|k| plausible(k as f64 - KELVIN))
+        .reduce(f64::max);
+    if hottest.is_some() {
+        return Ok(hottest);
+    }
+    // The older ACPI class needs admin rights, but some firmware only fills this one
+    let acpi: Vec<AcpiThermalZone> = ctx.wmi_namespace("root\\WMI")
+        .and_then(|con| con.raw_query("SELECT CurrentTemperature FROM MSAcpi_ThermalZoneTemperature").map_err(anyhow::Error::from))
+        .unwrap_or_default();
+    Ok(acpi.iter()
+        .filter_map(|z| z.current_temperature)
+        .filter_map(|t| plausible(t as f64 / 10.0 - KELVIN))
+        .reduce(f64::max))
+}
+
+pub fn get_sensor_reading(ctx: &HardwareContext) -> Result<SensorReading> {
+    let wmi = ctx.get_wmi()?;
+    let perf: Vec<ProcessorPerformance> = wmi.raw_query(
+        "SELECT ProcessorFrequency, PercentProcessorPerformance, PercentPerformanceLimit FROM Win32_PerfFormattedData_Counters_ProcessorInformation WHERE Name = '_Total'"
+    )?;
+    let perf = perf.into_iter().next();
+    let base_mhz = perf.as_ref().and_then(|p| p.processor_frequency).filter(|f| *f > 0);
+    let clock_mhz = base_mhz
+        .zip(perf.as_ref().and_then(|p| p.percent_processor_performance))
+        .map(|(base, pct)| (base as f64 * pct as f64 / 100.0).round());
+    Ok(SensorReading {
+        cpu_temp_c: get_cpu_temperature(ctx).unwrap_or(None),
+        clock_mhz,
+        base_mhz,
+        performance_limit_pct: perf.and_then(|p| p.percent_performance_limit),
+    })
+}
@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+use super::HardwareContext;
+use anyhow::Result;
+use std::sync::Mutex;
+use winreg::enums::*;
+use winreg::RegKey;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum Hypervisor {
+    None,
+    Kvm,
+    HyperV,
+    VMware,
+    VirtualBox,
+    Xen,
+    Qemu,
+    Parallels,
+    Container,
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VirtualizationInfo {
+    pub is_virtual_machine: bool,
+    pub hypervisor: Hypervisor,
+    // Raw CPUID data
+    pub cpuid_hypervisor_bit: bool,
+    pub cpuid_vendor: Option<String>,
+    // Hyper-V/VBS also sets the hypervisor bit on the host itself (root partition)
+    pub hyperv_root_partition: bool,
+    // SMBIOS type 1
+    pub system_manufacturer: Option<String>,
+    pub system_model: Option<String>,
+    pub virtual_devices: Vec<String>,
+    pub in_container: bool,
+    // Host side: can this machine run VMs itself
+    pub vm_monitor_mode_extensions: Option<bool>,
+    pub second_level_address_translation: Option<bool>,
+    pub virtualization_firmware_enabled: Option<bool>,
+    pub evidence: Vec<String>,
+}
+
+impl VirtualizationInfo {
+    // Short remark attached to benchmark style scores when they were taken inside a guest
+    pub fn score_note(&self) -> Option<String> {
+        if !self.is_virtual_machine {
+            return None;
+        }
+        Some(format!("Measured inside a {:?} guest, scores reflect the virtual hardware assigned by the host", self.hypervisor))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiComputerSystem {
+    manufacturer: Option<String>,
+    model: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiProcessorVirt {
+    #[serde(rename = "VMMonitorModeExtensions")]
+    vm_monitor_mode_extensions: Option<bool>,
+    second_level_address_translation_extensions: Option<bool>,
+    virtualization_firmware_enabled: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiPnPId {
+    name: Option<String>,
+    #[serde(rename = "PNPDeviceID")]
+    pnp_device_id: Option<String>,
+}
+
+// PCI vendor IDs that only appear on emulated or paravirtual devices
+const VIRTUAL_PCI_VENDORS: [(&str, Hypervisor); 5] = [
+    ("VEN_15AD", Hypervisor::VMware),
+    ("VEN_80EE", Hypervisor::VirtualBox),
+    ("VEN_1AF4", Hypervisor::Kvm), // virtio (Red Hat)
+    ("VEN_1414", Hypervisor::HyperV),
+    ("VEN_5853", Hypervisor::Xen),
+];
+
+pub struct CpuidInfo {
+    pub hypervisor_bit: bool,
+    pub vendor: Option<String>,
+    pub hyperv_root_partition: bool,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)] // __cpuid is only a safe fn on newer toolchains
+pub fn read_cpuid() -> CpuidInfo {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // Leaf 1, ECX bit 31 is reserved for hypervisors to announce themselves
+    let leaf1 = unsafe { __cpuid(1) };
+    let hypervisor_bit = (leaf1.ecx >> 31) & 1 == 1;
+    if !hypervisor_bit {
+        return CpuidInfo { hypervisor_bit, vendor: None, hyperv_root_partition: false };
+    }
+
+    // Leaf 0x40000000 returns the 12 byte vendor signature in EBX, ECX, EDX
+    let leaf = unsafe { __cpuid(0x4000_0000) };
+    let mut bytes = Vec::with_capacity(12);
+    for reg in [leaf.ebx, leaf.ecx, leaf.edx] {
+        bytes.extend_from_slice(&reg.to_le_bytes());
+    }
+    let vendor = String::from_utf8_lossy(&bytes).trim_end_matches('\0').to_string();
+
+    // Hyper-V: leaf 0x40000003 EBX bit 0 (CreatePartitions) is only granted to the root partition
+    let mut hyperv_root_partition = false;
+    if vendor == "Microsoft Hv" && leaf.eax >= 0x4000_0003 {
+        let features = unsafe { __cpuid(0x4000_0003) };
+        hyperv_root_partition = features.ebx & 1 == 1;
+    }
+
+    CpuidInfo { hypervisor_bit, vendor: Some(vendor), hyperv_root_partition }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+pub fn read_cpuid() -> CpuidInfo {
+    CpuidInfo { hypervisor_bit: false, vendor: None, hyperv_root_partition: false }
+}
+
+pub fn hypervisor_from_cpuid_vendor(vendor: &str) -> Hypervisor {
+    match vendor {
+        "KVMKVMKVM" | "Linux KVM Hv" => Hypervisor::Kvm,
+        "Microsoft Hv" => Hypervisor::HyperV,
+        "VMwareVMware" => Hypervisor::VMware,
+        "VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        "XenVMMXenVMM" => Hypervisor::Xen,
+        "TCGTCGTCGTCG" => Hypervisor::Qemu,
+        " lrpepyh  vr" | "prl hyperv  " => Hypervisor::Parallels,
+        _ => Hypervisor::Unknown,
+    }
+}
+
+pub fn hypervisor_from_smbios(manufacturer: &str, model: &str) -> Option<Hypervisor> {
+    let man = manufacturer.to_lowercase();
+    let model = model.to_lowercase();
+    if man.contains("vmware") || model.contains("vmware") {
+        Some(Hypervisor::VMware)
+    } else if man.contains("innotek") || model.contains("virtualbox") {
+        Some(Hypervisor::VirtualBox)
+    } else if man.contains("qemu") || model.contains("kvm") || model.contains("standard pc (") {
+        Some(Hypervisor::Kvm)
+    } else if man.contains("xen") {
+        Some(Hypervisor::Xen)
+    } else if man.contains("parallels") {
+        Some(Hypervisor::Parallels)
+    } else if man.contains("microsoft corporation") && model.contains("virtual machine") {
+        Some(Hypervisor::HyperV)
+    } else {
+        None
+    }
+}
+
+// Windows containers mark themselves in the registry and run the Container Execution Agent
+fn detect_container_markers(evidence: &mut Vec<String>) -> Option<Hypervisor> {
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    if let Ok(container_type) = hklm.open_subkey("SYSTEM\\CurrentControlSet\\Control").and_then(|key| key.get_value::<u32, _>("ContainerType")) {
+        evidence.push(format!("Registry ContainerType = {}", container_type));
+        return Some(Hypervisor::Container);
+    }
+    if hklm.open_subkey("SYSTEM\\CurrentControlSet\\Services\\CExecSvc").is_ok() {
+        evidence.push("Container Execution Agent (CExecSvc) service installed".to_string());
+        return Some(Hypervisor::Container);
+    }
+    // HostProcess containers share the host kernel and registry, only the environment tells
+    if std::env::var("CONTAINER_SANDBOX_MOUNT_POINT").is_ok() {
+        evidence.push("Windows container environment".to_string());
+        return Some(Hypervisor::Container);
+    }
+    None
+}
+
+pub fn get_virtualization_info(ctx: &HardwareContext) -> Result<VirtualizationInfo> {
+    let wmi = ctx.get_wmi()?;
+    let mut evidence = Vec::new();
+
+    let cpuid = read_cpuid();
+    if cpuid.hypervisor_bit {
+        evidence.push(format!("CPUID hypervisor bit set, vendor \"{}\"", cpuid.vendor.clone().unwrap_or_default()));
+    }
+    if cpuid.hyperv_root_partition {
+        evidence.push("Hyper-V root partition (host with Hyper-V/VBS enabled)".to_string());
+    }
+
+    let system: Vec<WmiComputerSystem> = wmi.raw_query("SELECT Manufacturer, Model FROM Win32_ComputerSystem").unwrap_or_default();
+    let system_manufacturer = system.first().and_then(|s| s.manufacturer.clone());
+    let system_model = system.first().and_then(|s| s.model.clone());
+    let smbios_hv = hypervisor_from_smbios(
+        system_manufacturer.as_deref().unwrap_or_default(),
+        system_model.as_deref().unwrap_or_default(),
+    );
+    if let Some(hv) = smbios_hv {
+        evidence.push(format!("SMBIOS system \"{} {}\" matches {:?}", system_manufacturer.clone().unwrap_or_default(), system_model.clone().unwrap_or_default(), hv));
+    }
+
+    let device_query = format!(
+        "SELECT Name, PNPDeviceID FROM Win32_PnPEntity WHERE {}",
+        VIRTUAL_PCI_VENDORS.iter().map(|(id, _)| format!("PNPDeviceID LIKE '%{}%'", id)).collect::<Vec<_>>().join(" OR ")
+    );
+    let devices: Vec<WmiPnPId> = wmi.raw_query(&device_query).unwrap_or_default();
+    let mut device_hv = None;
+    let mut virtual_devices = Vec::new();
+    for dev in &devices {
+        let id = dev.pnp_device_id.clone().unwrap_or_default().to_uppercase();
+        if let Some((_, hv)) = VIRTUAL_PCI_VENDORS.iter().find(|(ven, _)| id.contains(ven)) {
+            if *hv == Hypervisor::HyperV && cpuid.hyperv_root_partition {
+                continue;
+            }
+            device_hv.get_or_insert(*hv);
+            virtual_devices.push(dev.name.clone().unwrap_or(id));
+        }
+    }
+    if let Some(hv) = device_hv {
+        evidence.push(format!("{} virtual device(s) from {:?}", virtual_devices.len(), hv));
+    }
+
+    let container_hv = detect_container_markers(&mut evidence);
+
+    let procs: Vec<WmiProcessorVirt> = wmi.raw_query("SELECT VMMonitorModeExtensions, SecondLevelAddressTranslationExtensions, VirtualizationFirmwareEnabled FROM Win32_Processor").unwrap_or_default();
+    let proc_virt = procs.first();
+
+    // Containers take priority, then CPUID (most reliable), then SMBIOS and devices.
+    // A Hyper-V root partition is a host, not a guest.
+    let cpuid_hv = if cpuid.hypervisor_bit && !cpuid.hyperv_root_partition {
+        cpuid.vendor.as_deref().map(hypervisor_from_cpuid_vendor)
+    } else {
+        None
+    };
+    let hypervisor = container_hv
+        .or(cpuid_hv.filter(|hv| *hv != Hypervisor::Unknown))
+        .or(smbios_hv)
+        .or(device_hv)
+        .or(cpuid_hv)
+        .unwrap_or(Hypervisor::None);
+
+    Ok(VirtualizationInfo {
+        is_virtual_machine: hypervisor != Hypervisor::None,
+        hypervisor,
+        cpuid_hypervisor_bit: cpuid.hypervisor_bit,
+        cpuid_vendor: cpuid.vendor,
+        hyperv_root_partition: cpuid.hyperv_root_partition,
+        system_manufacturer,
+        system_model,
+        virtual_devices,
+        in_container: container_hv == Some(Hypervisor::Container),
+        vm_monitor_mode_extensions: proc_virt.and_then(|p| p.vm_monitor_mode_extensions),
+        second_level_address_translation: proc_virt.and_then(|p| p.second_level_address_translation_extensions),
+        virtualization_firmware_enabled: proc_virt.and_then(|p| p.virtualization_firmware_enabled),
+        evidence,
+    })
+}
+
+static VIRTUALIZATION_CACHE: Mutex<Option<VirtualizationInfo>> = Mutex::new(None);
+
+// The answer cannot change while the app is running, so every scoring path shares one detection.
+// Failures are not cached, WMI may just not be ready yet.
+pub fn get_virtualization_info_cached() -> Option<VirtualizationInfo> {
+    let mut cache = VIRTUALIZATION_CACHE.lock().unwrap();
+    if cache.is_none() {
+        let mut ctx = HardwareContext::new();
+        ctx.init_wmi().ok()?;
+        *cache = Some(get_virtualization_info(&ctx).ok()?);
+    }
+    cache.clone()
+}
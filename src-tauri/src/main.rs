@@ -5,28 +5,18 @@
 
 mod hardware;
 mod scoring;
+mod datetime;
+mod model_name;
 
 use hardware::HardwareContext;
+use scoring::profiles::{ProfileSet, ScoringProfile};
 use serde::{Deserialize, Serialize};
 use std::process::Command;
-use std::sync::Mutex;
 use std::fs;
 use sysinfo::System;
-use tauri::Emitter;
 use winreg::enums::*;
 use winreg::RegKey;
 
-struct AppState {
-    sys: Mutex<System>,
-}
-
-#[derive(Serialize)]
-struct SystemUsage {
-    cpu_usage: f32,
-    memory_used: u64,
-    memory_total: u64,
-}
-
 #[derive(Serialize, Deserialize)]
 struct AppConfig {
     tab_order: Vec<String>,
@@ -55,35 +45,32 @@ fn get_boot_time() -> u64 {
     System::boot_time()
 }
 
-#[tauri::command]
-fn get_system_usage(state: tauri::State<AppState>) -> SystemUsage {
-    let mut sys = state.sys.lock().unwrap();
-    sys.refresh_all();
-    
-    let cpu_usage = sys.global_cpu_info().cpu_usage();
-    let memory_used = sys.used_memory();
-    let memory_total = sys.total_memory();
-
-    SystemUsage {
-        cpu_usage,
-        memory_used,
-        memory_total,
-    }
-}
-
 // 定义前端响应的结构体
 #[derive(Serialize)]
 struct ScoredCpu {
     info: hardware::cpu::CpuInfo,
+    model: model_name::ModelName,
     score: String,
     score_num: u32,
+    score_source: scoring::ScoreSource,
+    benchmark: Option<scoring::benchmarks::BenchmarkMatch>,
+    // Id of the scoring profile the tiers come from
+    profile: String,
+    explanation: scoring::explain::Explanation,
+    note: Option<String>,
 }
 
 #[derive(Serialize)]
 struct ScoredGpu {
     info: hardware::gpu::GpuInfo,
+    model: model_name::ModelName,
     score: String,
     score_num: u32,
+    score_source: scoring::ScoreSource,
+    benchmark: Option<scoring::benchmarks::BenchmarkMatch>,
+    explanation: scoring::explain::Explanation,
+    profile: String,
+    note: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -93,6 +80,9 @@ struct ScoredRam {
     avg_speed: u32,
     score: String,
     score_num: u32,
+    explanation: scoring::explain::Explanation,
+    profile: String,
+    note: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -100,11 +90,17 @@ struct ScoredDisk {
     info: hardware::disk::DiskInfo,
     score: String,
     score_num: u32,
+    explanation: scoring::explain::Explanation,
+    profile: String,
+    note: Option<String>,
+    // Latest run of the built-in storage benchmark on this disk
+    benchmark: Option<benchmark::disk::DiskBenchmarkResult>,
 }
 
 #[derive(Serialize)]
 struct FullHardwareInfo {
     motherboard: Vec<hardware::motherboard::MotherboardInfo>,
+    chassis: Option<hardware::motherboard::ChassisInfo>,
     cpu: Vec<ScoredCpu>,
     gpu: Vec<ScoredGpu>,
     ram: ScoredRam,
@@ -115,100 +111,182 @@ struct FullHardwareInfo {
     usb: Vec<hardware::peripherals::PnPDevice>,
     camera: Vec<hardware::peripherals::PnPDevice>,
     bluetooth: Vec<hardware::peripherals::PnPDevice>,
+    virtualization: Option<hardware::virtualization::VirtualizationInfo>,
+    system_score: scoring::system::SystemScore,
+}
+
+// Scores are only meaningful on bare metal, inside a VM they describe whatever the host assigned
+fn vm_score_note() -> Option<String> {
+    hardware::virtualization::get_virtualization_info_cached().and_then(|v| v.score_note())
+}
+
+fn score_cpus(cpus: Vec<hardware::cpu::CpuInfo>, profile: &ScoringProfile) -> Vec<ScoredCpu> {
+    let note = vm_score_note();
+    cpus.into_iter().map(|cpu| {
+        let rating = scoring::rate_cpu(profile, &cpu.name, cpu.number_of_cores, cpu.max_clock_speed);
+        ScoredCpu {
+            model: model_name::ModelName::parse(&cpu.name),
+            info: cpu,
+            score: format!("{:?}", rating.score),
+            score_num: rating.score_num,
+            score_source: rating.source,
+            benchmark: rating.benchmark,
+            explanation: rating.explanation,
+            profile: profile.id.clone(),
+            note: note.clone(),
+        }
+    }).collect()
+}
+
+fn score_gpus(gpus: Vec<hardware::gpu::GpuInfo>, profile: &ScoringProfile) -> Vec<ScoredGpu> {
+    let note = vm_score_note();
+    gpus.into_iter().map(|gpu| {
+        let rating = scoring::rate_gpu(profile, &gpu.name, gpu.adapter_ram, gpu.vram_source);
+        ScoredGpu {
+            model: model_name::ModelName::parse(&gpu.name),
+            info: gpu,
+            score: format!("{:?}", rating.score),
+            score_num: rating.score_num,
+            score_source: rating.source,
+            benchmark: rating.benchmark,
+            explanation: rating.explanation,
+            profile: profile.id.clone(),
+            note: note.clone(),
+        }
+    }).collect()
+}
+
+fn score_memory(mems: Vec<hardware::memory::MemoryInfo>, profile: &ScoringProfile) -> ScoredRam {
+    let mut total_cap = 0;
+    let mut speeds = Vec::new();
+    for mem in &mems {
+        total_cap += mem.capacity;
+        let speed = mem.configured_clock_speed.unwrap_or(mem.speed);
+        speeds.push(if speed > 0 { speed } else { mem.speed });
+    }
+    let total_gb = total_cap / 1024 / 1024 / 1024;
+    let avg_speed = if !speeds.is_empty() {
+        speeds.iter().sum::<u32>() / speeds.len() as u32
+    } else {
+        0
+    };
+    let rating = scoring::rate_ram(profile, total_gb, avg_speed, mems.len());
+    ScoredRam {
+        info: mems,
+        total_gb,
+        avg_speed,
+        score: format!("{:?}", rating.score),
+        score_num: rating.score_num,
+        explanation: rating.explanation,
+        profile: profile.id.clone(),
+        note: vm_score_note(),
+    }
+}
+
+fn score_disks(disks: Vec<hardware::disk::DiskInfo>, profile: &ScoringProfile) -> Vec<ScoredDisk> {
+    let note = vm_score_note();
+    let mut results = benchmark::disk::load_results();
+    disks.into_iter().map(|disk| {
+        let is_nvme = disk.bus_type.to_lowercase().contains("nvme");
+        let rating = scoring::rate_disk(profile, disk.media_type, is_nvme, disk.size);
+        ScoredDisk {
+            benchmark: results.remove(&benchmark::disk::disk_key(&disk)),
+            info: disk,
+            score: format!("{:?}", rating.score),
+            score_num: rating.score_num,
+            explanation: rating.explanation,
+            profile: profile.id.clone(),
+            note: note.clone(),
+        }
+    }).collect()
+}
+
+fn system_inputs(cpus: &[ScoredCpu], gpus: &[ScoredGpu], ram: &ScoredRam, disks: &[ScoredDisk]) -> scoring::system::SystemInputs {
+    use scoring::system::{ComponentInput, CpuInput, DiskInput, SystemInputs};
+
+    let cpu = cpus.first().map(|c| CpuInput {
+        rating: ComponentInput {
+            tier: scoring::Score::from_label(&c.score),
+            benchmark: c.benchmark.as_ref().map(|b| b.score),
+        },
+        cores: c.info.number_of_cores,
+    });
+    // Hybrid laptops list the iGPU too, the strongest adapter is the one games run on
+    let gpu = gpus.iter()
+        .max_by_key(|g| g.benchmark.as_ref().map(|b| b.score).unwrap_or(0))
+        .map(|g| ComponentInput {
+            tier: scoring::Score::from_label(&g.score),
+            benchmark: g.benchmark.as_ref().map(|b| b.score),
+        });
+    let system_disk = disks.iter()
+        .find(|d| d.info.is_system_disk)
+        .or(disks.first())
+        .map(|d| DiskInput {
+            is_ssd: d.info.media_type.is_solid_state(),
+            is_nvme: d.info.bus_type.to_lowercase().contains("nvme"),
+        });
+
+    SystemInputs {
+        cpu,
+        gpu,
+        ram_gb: ram.total_gb,
+        ram_speed_mhz: ram.avg_speed,
+        ram_modules: ram.info.len(),
+        system_disk,
+        virtual_machine: hardware::virtualization::get_virtualization_info_cached().is_some_and(|v| v.is_virtual_machine),
+    }
+}
+
+fn system_score(cpus: &[ScoredCpu], gpus: &[ScoredGpu], ram: &ScoredRam, disks: &[ScoredDisk], profiles: &ProfileSet, profile: &ScoringProfile) -> scoring::system::SystemScore {
+    scoring::system::evaluate(&system_inputs(cpus, gpus, ram, disks), profile, &profiles.profiles)
 }
 
 #[tauri::command]
-fn get_hardware_info() -> Result<FullHardwareInfo, String> {
+fn get_hardware_info(profile: Option<String>) -> Result<FullHardwareInfo, String> {
     // Parallelize hardware scans to improve startup time
+    let profiles = scoring::profiles::load_profiles()?;
+    let active = profiles.get(profile.as_deref())?;
     
     let motherboard_handle = std::thread::spawn(|| {
         let mut ctx = HardwareContext::new();
         ctx.init_wmi().map_err(|e| e.to_string())?;
-        hardware::motherboard::get_motherboard_info(&ctx).map_err(|e| e.to_string())
+        let boards = hardware::motherboard::get_motherboard_info(&ctx).map_err(|e| e.to_string())?;
+        let chassis = hardware::motherboard::get_chassis_info(&ctx).ok();
+        Ok::<(Vec<hardware::motherboard::MotherboardInfo>, Option<hardware::motherboard::ChassisInfo>), String>((boards, chassis))
     });
 
-    let cpu_handle = std::thread::spawn(|| {
+    let cpu_profile = active.clone();
+    let cpu_handle = std::thread::spawn(move || {
         let mut ctx = HardwareContext::new();
         // CPU uses sysinfo primarily, so we don't init WMI unless fallback is needed inside get_cpu_info
         // But get_cpu_info takes &mut ctx and might call get_cpu_info_wmi which needs WMI.
         // We should update get_cpu_info to init WMI if needed.
         let cpus = hardware::cpu::get_cpu_info(&mut ctx).map_err(|e| e.to_string())?;
-        let scored_cpus: Vec<ScoredCpu> = cpus.into_iter().map(|cpu| {
-            let score = scoring::score_cpu(cpu.number_of_cores, cpu.max_clock_speed);
-            let score_num = scoring::calculate_cpu_score_num(cpu.number_of_cores, cpu.max_clock_speed);
-            ScoredCpu {
-                info: cpu,
-                score: format!("{:?}", score),
-                score_num,
-            }
-        }).collect();
-        Ok::<Vec<ScoredCpu>, String>(scored_cpus)
+        Ok::<Vec<ScoredCpu>, String>(score_cpus(cpus, &cpu_profile))
     });
 
-    let gpu_handle = std::thread::spawn(|| {
+    let gpu_profile = active.clone();
+    let gpu_handle = std::thread::spawn(move || {
         let mut ctx = HardwareContext::new();
         ctx.init_wmi().map_err(|e| e.to_string())?;
         let gpus = hardware::gpu::get_gpu_info(&ctx).map_err(|e| e.to_string())?;
-        let scored_gpus: Vec<ScoredGpu> = gpus.into_iter().map(|gpu| {
-            let (score, score_num) = if let Some(ram) = gpu.adapter_ram {
-                 (format!("{:?}", scoring::score_gpu(ram)), scoring::calculate_gpu_score_num(ram))
-            } else {
-                 ("Unknown".to_string(), 0)
-            };
-            ScoredGpu {
-                info: gpu,
-                score,
-                score_num,
-            }
-        }).collect();
-        Ok::<Vec<ScoredGpu>, String>(scored_gpus)
+        Ok::<Vec<ScoredGpu>, String>(score_gpus(gpus, &gpu_profile))
     });
 
-    let ram_handle = std::thread::spawn(|| {
+    let ram_profile = active.clone();
+    let ram_handle = std::thread::spawn(move || {
         let mut ctx = HardwareContext::new();
         ctx.init_wmi().map_err(|e| e.to_string())?;
         let mems = hardware::memory::get_memory_info(&mut ctx).map_err(|e| e.to_string())?;
-        let mut total_cap = 0;
-        let mut speeds = Vec::new();
-        for mem in &mems {
-            total_cap += mem.capacity;
-            let speed = mem.configured_clock_speed.unwrap_or(mem.speed);
-            speeds.push(if speed > 0 { speed } else { mem.speed });
-        }
-        let total_gb = total_cap / 1024 / 1024 / 1024;
-        let avg_speed = if !speeds.is_empty() {
-            speeds.iter().sum::<u32>() / speeds.len() as u32
-        } else {
-            0
-        };
-        let ram_score = format!("{:?}", scoring::score_ram(total_gb, avg_speed));
-        let ram_score_num = scoring::calculate_ram_score_num(total_gb, avg_speed);
-        Ok::<ScoredRam, String>(ScoredRam {
-            info: mems,
-            total_gb,
-            avg_speed,
-            score: ram_score,
-            score_num: ram_score_num,
-        })
+        Ok::<ScoredRam, String>(score_memory(mems, &ram_profile))
     });
 
-    let disk_handle = std::thread::spawn(|| {
+    let disk_profile = active.clone();
+    let disk_handle = std::thread::spawn(move || {
         let mut ctx = HardwareContext::new();
         ctx.init_wmi().map_err(|e| e.to_string())?;
         let disks = hardware::disk::get_disk_info(&ctx).map_err(|e| e.to_string())?;
-        let scored_disks: Vec<ScoredDisk> = disks.into_iter().map(|disk| {
-            let size = disk.size;
-            let is_ssd = disk.media_type.to_lowercase().contains("ssd");
-            let is_nvme = disk.bus_type.to_lowercase().contains("nvme");
-            let score = scoring::score_disk(is_ssd, is_nvme, size);
-            let score_num = scoring::calculate_disk_score_num(is_ssd, is_nvme, size);
-            ScoredDisk {
-                info: disk,
-                score: format!("{:?}", score),
-                score_num,
-            }
-        }).collect();
-        Ok::<Vec<ScoredDisk>, String>(scored_disks)
+        Ok::<Vec<ScoredDisk>, String>(score_disks(disks, &disk_profile))
     });
 
     let misc_handle = std::thread::spawn(|| {
@@ -230,17 +308,22 @@ fn get_hardware_info() -> Result<FullHardwareInfo, String> {
         Ok::<(Vec<hardware::peripherals::PnPDevice>, Vec<hardware::peripherals::PnPDevice>, Vec<hardware::peripherals::PnPDevice>), String>((vec![], vec![], vec![]))
     });
 
+    let virtualization_handle = std::thread::spawn(hardware::virtualization::get_virtualization_info_cached);
+
     // Join all threads and collect results
-    let motherboard = motherboard_handle.join().map_err(|_| "Motherboard thread panicked".to_string())??;
+    let (motherboard, chassis) = motherboard_handle.join().map_err(|_| "Motherboard thread panicked".to_string())??;
     let cpu = cpu_handle.join().map_err(|_| "CPU thread panicked".to_string())??;
     let gpu = gpu_handle.join().map_err(|_| "GPU thread panicked".to_string())??;
     let ram = ram_handle.join().map_err(|_| "RAM thread panicked".to_string())??;
     let disks = disk_handle.join().map_err(|_| "Disk thread panicked".to_string())??;
     let (sound, monitor, network) = misc_handle.join().map_err(|_| "Misc thread panicked".to_string())??;
     let (usb, camera, bluetooth) = peripherals_handle.join().map_err(|_| "Peripherals thread panicked".to_string())??;
+    let virtualization = virtualization_handle.join().map_err(|_| "Virtualization thread panicked".to_string())?;
+    let system_score = system_score(&cpu, &gpu, &ram, &disks, &profiles, &active);
 
     Ok(FullHardwareInfo {
         motherboard,
+        chassis,
         cpu,
         gpu,
         ram,
@@ -251,6 +334,8 @@ fn get_hardware_info() -> Result<FullHardwareInfo, String> {
         usb,
         camera,
         bluetooth,
+        virtualization,
+        system_score,
     })
 }
 
@@ -355,7 +440,8 @@ struct DriverInfo {
     provider_name: String,
     class_name: String,
     version: String,
-    date: String,
+    date: Option<datetime::Timestamp>,
+    age_days: Option<u32>,
 }
 
 #[tauri::command]
@@ -371,6 +457,8 @@ fn scan_graphic_drivers() -> Result<Vec<DriverInfo>, String> {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let mut drivers = Vec::new();
+    let date_order = datetime::system_date_order();
+    let now = datetime::Timestamp::now();
     
     let mut current_driver = DriverInfo {
         published_name: String::new(),
@@ -378,7 +466,8 @@ fn scan_graphic_drivers() -> Result<Vec<DriverInfo>, String> {
         provider_name: String::new(),
         class_name: String::new(),
         version: String::new(),
-        date: String::new(),
+        date: None,
+        age_days: None,
     };
     
     for line in stdout.lines() {
@@ -393,7 +482,8 @@ fn scan_graphic_drivers() -> Result<Vec<DriverInfo>, String> {
                 provider_name: String::new(),
                 class_name: String::new(),
                 version: String::new(),
-                date: String::new(),
+                date: None,
+                age_days: None,
             };
         } else if line.starts_with("Original Name:") {
             current_driver.original_name = line.replace("Original Name:", "").trim().to_string();
@@ -404,7 +494,10 @@ fn scan_graphic_drivers() -> Result<Vec<DriverInfo>, String> {
         } else if line.starts_with("Driver Version:") {
             current_driver.version = line.replace("Driver Version:", "").trim().to_string();
         } else if line.starts_with("Driver Date:") {
-            current_driver.date = line.replace("Driver Date:", "").trim().to_string();
+            // pnputil prints the date in the user's short date format
+            let raw = line.replace("Driver Date:", "");
+            current_driver.date = datetime::parse_any(&raw, date_order);
+            current_driver.age_days = current_driver.date.map(|d| d.age_days(now));
         }
     }
     if !current_driver.published_name.is_empty() {
@@ -412,11 +505,14 @@ fn scan_graphic_drivers() -> Result<Vec<DriverInfo>, String> {
     }
 
     // 过滤 NVIDIA 或 AMD
-    let filtered: Vec<DriverInfo> = drivers.into_iter().filter(|d| {
+    let mut filtered: Vec<DriverInfo> = drivers.into_iter().filter(|d| {
         let p = d.provider_name.to_lowercase();
         p.contains("nvidia") || p.contains("amd") || p.contains("advanced micro devices") || p.contains("intel")
     }).collect();
 
+    // 最新的驱动排在前面
+    filtered.sort_by(|a, b| b.date.cmp(&a.date));
+
     Ok(filtered)
 }
 
@@ -549,54 +645,6 @@ fn restart_explorer() -> Result<String, String> {
     Ok("Success".to_string())
 }
 
-#[tauri::command]
-async fn quick_memory_check(window: tauri::Window) -> Result<String, String> {
-    // Allocate 100MB chunks and test
-    let chunk_size = 100 * 1024 * 1024; // 100MB
-    let chunks_to_test = 10; // Test 1GB total (Increased for better demo)
-    
-    for i in 0..chunks_to_test {
-        // Emit progress event
-        let progress = ((i as f32 / chunks_to_test as f32) * 100.0) as u32;
-        window.emit("memory_progress", progress).unwrap_or(());
-
-        // Simulate some work/delay to make it visible and not freeze UI if it was sync (though this is async now)
-        // In a real heavy test, we might want to spawn a thread, but async here allows event loop to process
-        std::thread::sleep(std::time::Duration::from_millis(200));
-
-        let mut buffer: Vec<u8> = Vec::with_capacity(chunk_size);
-        // Write pattern
-        for _ in 0..chunk_size {
-            buffer.push(0xAA);
-        }
-        
-        // Verify pattern
-        for (index, byte) in buffer.iter().enumerate() {
-            if *byte != 0xAA {
-                return Err(format!("Memory error at chunk {}, byte {}", i, index));
-            }
-        }
-        
-        // Write another pattern
-        for j in 0..chunk_size {
-            buffer[j] = 0x55;
-        }
-        
-        // Verify
-        for (index, byte) in buffer.iter().enumerate() {
-            if *byte != 0x55 {
-                return Err(format!("Memory error at chunk {}, byte {}", i, index));
-            }
-        }
-        
-        // Drop to free memory
-        drop(buffer);
-    }
-    
-    window.emit("memory_progress", 100).unwrap_or(());
-    Ok("Memory check passed (Quick Test)".to_string())
-}
-
 #[tauri::command]
 fn get_uptime() -> u64 {
     System::uptime()
@@ -763,88 +811,81 @@ fn get_motherboard_info_command() -> Result<Vec<hardware::motherboard::Motherboa
 }
 
 #[tauri::command]
-fn get_cpu_info_command() -> Result<Vec<ScoredCpu>, String> {
+fn get_chassis_info_command() -> Result<hardware::motherboard::ChassisInfo, String> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi().map_err(|e| e.to_string())?;
+    hardware::motherboard::get_chassis_info(&ctx).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn get_cpu_info_command(profile: Option<String>) -> Result<Vec<ScoredCpu>, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
     let mut ctx = HardwareContext::new();
     let cpus = hardware::cpu::get_cpu_info(&mut ctx).map_err(|e| e.to_string())?;
-    let scored_cpus: Vec<ScoredCpu> = cpus.into_iter().map(|cpu| {
-        let score = scoring::score_cpu(cpu.number_of_cores, cpu.max_clock_speed);
-        let score_num = scoring::calculate_cpu_score_num(cpu.number_of_cores, cpu.max_clock_speed);
-        ScoredCpu {
-            info: cpu,
-            score: format!("{:?}", score),
-            score_num,
-        }
-    }).collect();
-    Ok(scored_cpus)
+    Ok(score_cpus(cpus, &profile))
 }
 
 #[tauri::command]
-fn get_gpu_info_command() -> Result<Vec<ScoredGpu>, String> {
+fn get_gpu_info_command(profile: Option<String>) -> Result<Vec<ScoredGpu>, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
     let mut ctx = HardwareContext::new();
     ctx.init_wmi().map_err(|e| e.to_string())?;
     let gpus = hardware::gpu::get_gpu_info(&ctx).map_err(|e| e.to_string())?;
-    let scored_gpus: Vec<ScoredGpu> = gpus.into_iter().map(|gpu| {
-        let (score, score_num) = if let Some(ram) = gpu.adapter_ram {
-             (format!("{:?}", scoring::score_gpu(ram)), scoring::calculate_gpu_score_num(ram))
-        } else {
-             ("Unknown".to_string(), 0)
-        };
-        ScoredGpu {
-            info: gpu,
-            score,
-            score_num,
-        }
-    }).collect();
-    Ok(scored_gpus)
+    Ok(score_gpus(gpus, &profile))
 }
 
 #[tauri::command]
-fn get_ram_info_command() -> Result<ScoredRam, String> {
+fn get_ram_info_command(profile: Option<String>) -> Result<ScoredRam, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
     let mut ctx = HardwareContext::new();
     ctx.init_wmi().map_err(|e| e.to_string())?;
     let mems = hardware::memory::get_memory_info(&mut ctx).map_err(|e| e.to_string())?;
-    let mut total_cap = 0;
-    let mut speeds = Vec::new();
-    for mem in &mems {
-        total_cap += mem.capacity;
-        let speed = mem.configured_clock_speed.unwrap_or(mem.speed);
-        speeds.push(if speed > 0 { speed } else { mem.speed });
-    }
-    let total_gb = total_cap / 1024 / 1024 / 1024;
-    let avg_speed = if !speeds.is_empty() {
-        speeds.iter().sum::<u32>() / speeds.len() as u32
-    } else {
-        0
-    };
-    let ram_score = format!("{:?}", scoring::score_ram(total_gb, avg_speed));
-    let ram_score_num = scoring::calculate_ram_score_num(total_gb, avg_speed);
-    Ok(ScoredRam {
-        info: mems,
-        total_gb,
-        avg_speed,
-        score: ram_score,
-        score_num: ram_score_num,
-    })
+    Ok(score_memory(mems, &profile))
 }
 
 #[tauri::command]
-fn get_disk_info_command() -> Result<Vec<ScoredDisk>, String> {
+fn get_disk_info_command(profile: Option<String>) -> Result<Vec<ScoredDisk>, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
     let mut ctx = HardwareContext::new();
     ctx.init_wmi().map_err(|e| e.to_string())?;
     let disks = hardware::disk::get_disk_info(&ctx).map_err(|e| e.to_string())?;
-    let scored_disks: Vec<ScoredDisk> = disks.into_iter().map(|disk| {
-        let size = disk.size;
-        let is_ssd = disk.media_type.to_lowercase().contains("ssd");
-        let is_nvme = disk.bus_type.to_lowercase().contains("nvme");
-        let score = scoring::score_disk(is_ssd, is_nvme, size);
-        let score_num = scoring::calculate_disk_score_num(is_ssd, is_nvme, size);
-        ScoredDisk {
-            info: disk,
-            score: format!("{:?}", score),
-            score_num,
-        }
-    }).collect();
-    Ok(scored_disks)
+    Ok(score_disks(disks, &profile))
+}
+
+#[tauri::command]
+fn get_system_score_command(profile: Option<String>) -> Result<scoring::system::SystemScore, String> {
+    let profiles = scoring::profiles::load_profiles()?;
+    let active = profiles.get(profile.as_deref())?;
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi().map_err(|e| e.to_string())?;
+    let cpus = score_cpus(hardware::cpu::get_cpu_info(&mut ctx).map_err(|e| e.to_string())?, &active);
+    let gpus = score_gpus(hardware::gpu::get_gpu_info(&ctx).unwrap_or_default(), &active);
+    let ram = score_memory(hardware::memory::get_memory_info(&mut ctx).unwrap_or_default(), &active);
+    let disks = score_disks(hardware::disk::get_disk_info(&ctx).unwrap_or_default(), &active);
+    Ok(system_score(&cpus, &gpus, &ram, &disks, &profiles, &active))
+}
+
+#[tauri::command]
+fn get_upgrade_advice(profile: Option<String>) -> Result<scoring::upgrade::UpgradeAdvice, String> {
+    let profile = scoring::profiles::resolve(profile.as_deref())?;
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi().map_err(|e| e.to_string())?;
+    let cpus = score_cpus(hardware::cpu::get_cpu_info(&mut ctx).map_err(|e| e.to_string())?, &profile);
+    let gpus = score_gpus(hardware::gpu::get_gpu_info(&ctx).unwrap_or_default(), &profile);
+    let ram = score_memory(hardware::memory::get_memory_info(&mut ctx).unwrap_or_default(), &profile);
+    let disks = score_disks(hardware::disk::get_disk_info(&ctx).unwrap_or_default(), &profile);
+    let board = hardware::motherboard::get_motherboard_info(&ctx).unwrap_or_default().into_iter().next();
+    let form_factor = hardware::motherboard::get_chassis_info(&ctx)
+        .map(|c| c.form_factor)
+        .unwrap_or(hardware::motherboard::FormFactor::Unknown);
+
+    Ok(scoring::upgrade::advise(&scoring::upgrade::UpgradeInputs {
+        system: system_inputs(&cpus, &gpus, &ram, &disks),
+        cpu_name: cpus.first().map(|c| c.info.name.clone()),
+        ram_modules_gb: ram.info.iter().map(|m| m.capacity / 1024 / 1024 / 1024).collect(),
+        board,
+        form_factor,
+    }, &profile))
 }
 
 #[tauri::command]
@@ -868,6 +909,20 @@ fn get_network_info_command() -> Result<Vec<hardware::network::NetworkInfo>, Str
     hardware::network::get_network_info(&ctx).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+fn get_virtualization_info_command() -> Result<hardware::virtualization::VirtualizationInfo, String> {
+    hardware::virtualization::get_virtualization_info_cached().ok_or("Virtualization detection failed".to_string())
+}
+
+#[tauri::command]
+fn get_benchmark_ranking(kind: String) -> Result<Vec<scoring::benchmarks::BenchmarkEntry>, String> {
+    match kind.as_str() {
+        "cpu" => Ok(scoring::benchmarks::CPU_BENCHMARKS.to_vec()),
+        "gpu" => Ok(scoring::benchmarks::GPU_BENCHMARKS.to_vec()),
+        _ => Err(format!("Unknown benchmark table: {}", kind)),
+    }
+}
+
 #[tauri::command]
 fn get_peripherals_info_command() -> Result<PeripheralsInfo, String> {
     let mut ctx = HardwareContext::new();
@@ -882,22 +937,47 @@ mod optimization;
 mod diagnostics;
 mod apps;
 mod network_tools;
+mod win11;
+mod requirements;
+mod psu;
+mod benchmark;
+mod usage;
+mod processes;
 
 fn main() {
-    let sys = System::new_all();
-    let app_state = AppState {
-        sys: Mutex::new(sys),
-    };
-
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_clipboard_manager::init())
-        .manage(app_state)
+        .manage(usage::Sampler::default())
+        .manage(benchmark::BenchmarkState::default())
+        .manage(processes::ProcessMonitor::default())
+        .setup(|app| {
+            usage::start(app.handle().clone());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             get_hardware_info, 
-            get_system_usage,
+            usage::get_system_usage,
+            usage::get_sampling_interval,
+            usage::get_sampler_error,
+            usage::set_sampling_interval,
+            usage::list_metrics,
+            usage::query_metric,
+            usage::list_events,
+            usage::export_metrics_csv,
+            usage::list_alert_rules,
+            usage::save_alert_rule,
+            usage::delete_alert_rule,
+            usage::get_alert_history,
+            usage::clear_alert_history,
+            processes::get_process_list,
+            processes::get_process_tree,
+            processes::end_process,
+            processes::set_process_priority,
+            processes::get_process_affinity,
+            processes::set_process_affinity,
             get_boot_time,
             get_uptime,
             get_firewall_status,
@@ -912,12 +992,27 @@ fn main() {
             install_product_key,
             attempt_activation,
             set_win11_bypass,
+            win11::check_win11_readiness,
+            requirements::get_game_requirements,
+            requirements::check_game_requirements,
+            psu::estimate_psu,
+            benchmark::run_cpu_benchmark,
+            benchmark::run_memory_benchmark,
+            benchmark::run_memory_test,
+            benchmark::get_benchmark_volumes,
+            benchmark::run_disk_benchmark,
+            benchmark::run_stress_test,
+            benchmark::cancel_benchmark,
+            benchmark::list_benchmark_runs,
+            benchmark::get_benchmark_run,
+            benchmark::delete_benchmark_run,
+            benchmark::compare_benchmark_runs,
+            benchmark::compare_benchmark_reference,
             set_classic_context_menu,
             set_show_extensions,
             set_show_hidden_files,
             restart_explorer,
             run_memory_diagnostic,
-            quick_memory_check,
             open_color_cpl,
             set_autostart,
             check_autostart,
@@ -928,14 +1023,21 @@ fn main() {
             enable_large_system_cache,
             // Individual Hardware Commands
             get_motherboard_info_command,
+            get_chassis_info_command,
             get_cpu_info_command,
             get_gpu_info_command,
             get_ram_info_command,
             get_disk_info_command,
+            get_system_score_command,
+            get_upgrade_advice,
+            scoring::profiles::get_scoring_profiles,
             get_sound_info_command,
             get_monitor_info_command,
             get_network_info_command,
             get_peripherals_info_command,
+            get_virtualization_info_command,
+            get_benchmark_ranking,
+            model_name::normalize_model_name,
             // Optimization
             optimization::apply_optimization,
             optimization::disable_telemetry,
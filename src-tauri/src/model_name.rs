@@ -0,0 +1,405 @@
+use serde::Serialize;
+
+// Hardware names arrive in many spellings:
+//   sysinfo / WMI   Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz
+//                   AMD Ryzen 5 3600 6-Core Processor
+//                   NVIDIA GeForce RTX 3060 Laptop GPU
+//   databases       Intel Core i7-10700K, NVIDIA GeForce RTX 3060 12GB
+// ModelName splits them into vendor / series / model / suffixes so they can be compared,
+// and builds a canonical key that is identical for every spelling of the same part.
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum Vendor {
+    Intel,
+    Amd,
+    Nvidia,
+    Qualcomm,
+    Unknown,
+}
+
+impl Vendor {
+    // Also accepts WMI manufacturer strings such as "GenuineIntel", "AuthenticAMD" or "Advanced Micro Devices, Inc."
+    pub fn from_text(text: &str) -> Vendor {
+        let lower = text.to_lowercase();
+        if lower.contains("intel") {
+            Vendor::Intel
+        } else if lower.contains("amd") || lower.contains("advanced micro devices") || lower.contains("ati technologies") {
+            Vendor::Amd
+        } else if lower.contains("nvidia") {
+            Vendor::Nvidia
+        } else if lower.contains("qualcomm") {
+            Vendor::Qualcomm
+        } else {
+            Vendor::Unknown
+        }
+    }
+
+    fn from_family_word(word: &str) -> Option<Vendor> {
+        match word {
+            "core" | "celeron" | "pentium" | "xeon" | "atom" | "arc" | "uhd" | "iris" => Some(Vendor::Intel),
+            "ryzen" | "threadripper" | "athlon" | "epyc" | "radeon" | "fx" => Some(Vendor::Amd),
+            "geforce" | "quadro" | "gtx" | "tesla" => Some(Vendor::Nvidia),
+            "snapdragon" | "adreno" => Some(Vendor::Qualcomm),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModelName {
+    pub raw: String,
+    pub vendor: Vendor,
+    // Product line including the tier, e.g. "Core i7", "Ryzen 7", "GeForce RTX", "Radeon RX"
+    pub series: String,
+    // Model number without suffix letters: "10700", "3060", "Q6600"
+    pub model: Option<String>,
+    // Canonical suffixes: K, KF, X3D, G, H, Ti, Super, XT, XTX, Laptop, Max-Q ...
+    pub suffixes: Vec<String>,
+    pub memory_gb: Option<u32>,
+    pub mobile: bool,
+    pub key: String,
+}
+
+// Words that vendors and WMI sprinkle around the model name
+const NOISE_WORDS: [&str; 7] = ["cpu", "gpu", "processor", "graphics", "series", "edition", "apu"];
+const VENDOR_WORDS: [&str; 5] = ["intel", "amd", "nvidia", "qualcomm", "ati"];
+// Free standing suffix words, (spelling in the name, canonical form)
+const DETACHED_SUFFIXES: [(&str, &str); 8] = [
+    ("ti", "Ti"),
+    ("super", "Super"),
+    ("xt", "XT"),
+    ("xtx", "XTX"),
+    ("laptop", "Laptop"),
+    ("mobile", "Mobile"),
+    ("notebook", "Laptop"),
+    ("maxq", "Max-Q"),
+];
+const MOBILE_SUFFIXES: [&str; 3] = ["Laptop", "Mobile", "Max-Q"];
+// Letters after a CPU model number that mark a mobile part (Intel/AMD), plus "M" for Radeon mobile GPUs
+const MOBILE_CPU_SUFFIXES: [&str; 7] = ["U", "H", "HS", "HX", "P", "Y", "M"];
+// Canonical spelling of series words, anything else is capitalized
+const SERIES_WORDS: [(&str, &str); 12] = [
+    ("geforce", "GeForce"),
+    ("rtx", "RTX"),
+    ("gtx", "GTX"),
+    ("gt", "GT"),
+    ("rx", "RX"),
+    ("uhd", "UHD"),
+    ("fx", "FX"),
+    ("hd", "HD"),
+    ("pro", "PRO"),
+    ("wx", "WX"),
+    ("xe", "Xe"),
+    ("vii", "VII"),
+];
+
+fn clean(raw: &str) -> String {
+    let mut text = raw.to_lowercase();
+    for mark in ["(r)", "(tm)", "®", "™"] {
+        text = text.replace(mark, " ");
+    }
+    // "... CPU @ 3.80GHz", "Ryzen 5 5600G with Radeon Graphics"
+    for cut in ["@", " with ", " w/ "] {
+        if let Some(pos) = text.find(cut) {
+            text.truncate(pos);
+        }
+    }
+    text
+}
+
+fn tokenize(raw: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in clean(raw).split_whitespace() {
+        if word == "max-q" {
+            tokens.push("maxq".to_string());
+            continue;
+        }
+        // "6-Core" in AMD names
+        if let Some(count) = word.strip_suffix("-core") {
+            if count.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+        }
+        for part in word.split(['-', '_', ',', '(', ')']) {
+            if !part.is_empty() && !NOISE_WORDS.contains(&part) {
+                tokens.push(part.to_string());
+            }
+        }
+    }
+    tokens
+}
+
+fn parse_memory_gb(token: &str) -> Option<u32> {
+    token.strip_suffix("gb").filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))?.parse().ok()
+}
+
+fn has_digit_run(token: &str, len: usize) -> bool {
+    let mut run = 0;
+    for c in token.chars() {
+        run = if c.is_ascii_digit() { run + 1 } else { 0 };
+        if run >= len {
+            return true;
+        }
+    }
+    false
+}
+
+// "i7", "i5", or the single tier digit in "Ryzen 7" / "Core Ultra 7"
+fn is_tier_word(word: &str) -> bool {
+    let digits = word.strip_prefix('i').unwrap_or(word);
+    digits.len() == 1 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn canonical_series_word(word: &str) -> String {
+    if let Some((_, canonical)) = SERIES_WORDS.iter().find(|(w, _)| *w == word) {
+        return canonical.to_string();
+    }
+    if is_tier_word(word) {
+        return word.to_string();
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl ModelName {
+    pub fn parse(raw: &str) -> ModelName {
+        let tokens = tokenize(raw);
+        let mut vendor = Vendor::Unknown;
+        let mut series_words = Vec::new();
+        let mut model = None;
+        let mut suffixes = Vec::new();
+        let mut memory_gb = None;
+
+        // The model number is the last token with three or more digits in a row
+        let model_index = tokens.iter().rposition(|t| has_digit_run(t, 3) && parse_memory_gb(t).is_none());
+
+        for (i, token) in tokens.iter().enumerate() {
+            if VENDOR_WORDS.contains(&token.as_str()) {
+                if vendor == Vendor::Unknown {
+                    vendor = Vendor::from_text(token);
+                }
+                continue;
+            }
+            if let Some(gb) = parse_memory_gb(token) {
+                memory_gb = Some(gb);
+                continue;
+            }
+            if Some(i) == model_index {
+                if token.starts_with(|c: char| c.is_ascii_digit()) {
+                    // "10700k" -> 10700 + K, "5800x3d" -> 5800 + X3D
+                    let digits: String = token.chars().take_while(|c| c.is_ascii_digit()).collect();
+                    let suffix = &token[digits.len()..];
+                    if !suffix.is_empty() {
+                        suffixes.push(suffix.to_uppercase());
+                    }
+                    model = Some(digits);
+                } else {
+                    // Letter prefixed models keep their letters: Q6600, N4020, A4000
+                    model = Some(token.to_uppercase());
+                }
+                continue;
+            }
+            if let Some((_, canonical)) = DETACHED_SUFFIXES.iter().find(|(w, _)| *w == token) {
+                // "XT" right after "Radeon RX" without a number is a series word, after the model it is a suffix
+                if model.is_some() || model_index.is_none() {
+                    suffixes.push(canonical.to_string());
+                    continue;
+                }
+            }
+            if model.is_some() {
+                // Anything after the model number that is not a suffix is noise ("Gen", stray words)
+                continue;
+            }
+            if vendor == Vendor::Unknown {
+                if let Some(v) = Vendor::from_family_word(token) {
+                    vendor = v;
+                }
+            }
+            series_words.push(canonical_series_word(token));
+        }
+
+        // "RTX" alone could be a workstation card from either NVIDIA line
+        if vendor == Vendor::Unknown && series_words.iter().any(|w| w == "RTX") {
+            vendor = Vendor::Nvidia;
+        }
+
+        let mobile = suffixes.iter().any(|s| {
+            MOBILE_SUFFIXES.contains(&s.as_str())
+                || MOBILE_CPU_SUFFIXES.contains(&s.as_str())
+                // Intel Ice Lake "1065G7"
+                || (s.len() == 2 && s.starts_with('G') && s[1..].chars().all(|c| c.is_ascii_digit()))
+        });
+
+        let series = series_words.join(" ");
+        let mut key_parts: Vec<String> = Vec::new();
+        if vendor != Vendor::Unknown {
+            key_parts.push(format!("{:?}", vendor));
+        }
+        key_parts.extend(series_words.iter().cloned());
+        key_parts.extend(model.iter().cloned());
+        key_parts.extend(suffixes.iter().cloned());
+        let key = key_parts.join(" ").to_lowercase();
+
+        ModelName {
+            raw: raw.trim().to_string(),
+            vendor,
+            series,
+            model,
+            suffixes,
+            memory_gb,
+            mobile,
+            key,
+        }
+    }
+
+    // Suffixes that change performance; mobile markers are compared separately
+    fn performance_suffixes(&self) -> Vec<&str> {
+        let mut suffixes: Vec<&str> = self.suffixes.iter()
+            .map(|s| s.as_str())
+            .filter(|s| !MOBILE_SUFFIXES.contains(s))
+            .collect();
+        suffixes.sort_unstable();
+        suffixes
+    }
+
+    fn tier(&self) -> Option<&str> {
+        self.series.split_whitespace().find(|w| is_tier_word(w))
+    }
+
+    // 1.0 for the same part, lower for close relatives, 0.0 for different parts.
+    // The model number, tier and performance suffixes must agree; only the words around them may differ.
+    pub fn similarity(&self, other: &ModelName) -> f32 {
+        // Memory size is not part of the key: "RTX 3080 10GB" and "RTX 3080 12GB" share one
+        let memory_factor = match (self.memory_gb, other.memory_gb) {
+            (Some(m1), Some(m2)) if m1 != m2 => 0.95,
+            _ => 1.0,
+        };
+        if self.key == other.key {
+            return memory_factor;
+        }
+        let (Some(a), Some(b)) = (&self.model, &other.model) else {
+            return 0.0;
+        };
+        if a != b {
+            return 0.0;
+        }
+        if self.vendor != Vendor::Unknown && other.vendor != Vendor::Unknown && self.vendor != other.vendor {
+            return 0.0;
+        }
+        if let (Some(t1), Some(t2)) = (self.tier(), other.tier()) {
+            if t1 != t2 {
+                return 0.0;
+            }
+        }
+
+        let mut factor = memory_factor;
+        let (s1, s2) = (self.performance_suffixes(), other.performance_suffixes());
+        if s1 != s2 {
+            // Intel F parts are the same silicon with the iGPU fused off: 12400F ~ 12400, 13600KF ~ 13600K
+            let without_f = |suffixes: &[&str]| -> Vec<String> {
+                suffixes.iter()
+                    .filter(|s| **s != "F")
+                    .map(|s| s.strip_suffix('F').filter(|b| !b.is_empty()).unwrap_or(s).to_string())
+                    .collect()
+            };
+            if without_f(&s1) != without_f(&s2) {
+                return 0.0;
+            }
+            factor *= 0.95;
+        }
+        if self.mobile != other.mobile {
+            factor *= 0.8;
+        }
+
+        // Dice coefficient over the series words, so "GeForce RTX" ~ "RTX" still counts
+        let w1: Vec<String> = self.series.to_lowercase().split_whitespace().map(String::from).collect();
+        let w2: Vec<String> = other.series.to_lowercase().split_whitespace().map(String::from).collect();
+        let dice = if w1.is_empty() && w2.is_empty() {
+            1.0
+        } else {
+            2.0 * w1.iter().filter(|w| w2.contains(w)).count() as f32 / (w1.len() + w2.len()) as f32
+        };
+
+        (0.5 + 0.5 * dice) * factor
+    }
+}
+
+#[tauri::command]
+pub fn normalize_model_name(name: String) -> ModelName {
+    ModelName::parse(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similarity(a: &str, b: &str) -> f32 {
+        ModelName::parse(a).similarity(&ModelName::parse(b))
+    }
+
+    #[test]
+    fn intel_wmi_name() {
+        let name = ModelName::parse("Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz");
+        assert_eq!(name.vendor, Vendor::Intel);
+        assert_eq!(name.series, "Core i7");
+        assert_eq!(name.model.as_deref(), Some("10700"));
+        assert_eq!(name.suffixes, ["K"]);
+        assert!(!name.mobile);
+        // Same key as the database spelling
+        assert_eq!(name.key, ModelName::parse("Intel Core i7-10700K").key);
+    }
+
+    #[test]
+    fn amd_core_count_is_noise() {
+        let name = ModelName::parse("AMD Ryzen 7 5800X3D 8-Core Processor");
+        assert_eq!(name.vendor, Vendor::Amd);
+        assert_eq!(name.series, "Ryzen 7");
+        assert_eq!(name.model.as_deref(), Some("5800"));
+        assert_eq!(name.suffixes, ["X3D"]);
+        assert_eq!(name.key, "amd ryzen 7 5800 x3d");
+        // X3D and X are different parts
+        assert_eq!(similarity("AMD Ryzen 7 5800X3D 8-Core Processor", "AMD Ryzen 7 5800X"), 0.0);
+    }
+
+    #[test]
+    fn laptop_gpu_is_mobile() {
+        let name = ModelName::parse("NVIDIA GeForce RTX 3080 Laptop GPU");
+        assert_eq!(name.vendor, Vendor::Nvidia);
+        assert_eq!(name.series, "GeForce RTX");
+        assert_eq!(name.model.as_deref(), Some("3080"));
+        assert!(name.mobile);
+        let desktop = similarity("NVIDIA GeForce RTX 3080 Laptop GPU", "NVIDIA GeForce RTX 3080");
+        assert!(desktop > 0.0 && desktop < 1.0);
+    }
+
+    #[test]
+    fn memory_size_lowers_similarity_only_slightly() {
+        let (small, large) = (ModelName::parse("GTX 1060 3GB"), ModelName::parse("GTX 1060 6GB"));
+        assert_eq!(small.memory_gb, Some(3));
+        assert_eq!(large.memory_gb, Some(6));
+        assert_eq!(small.key, large.key);
+        assert!(small.similarity(&large) < 1.0);
+        assert!(small.similarity(&large) > 0.9);
+        assert_eq!(small.similarity(&small), 1.0);
+    }
+
+    #[test]
+    fn xt_suffix_is_a_different_card() {
+        let xt = ModelName::parse("Radeon RX 6700 XT");
+        assert_eq!(xt.vendor, Vendor::Amd);
+        assert_eq!(xt.series, "Radeon RX");
+        assert_eq!(xt.suffixes, ["XT"]);
+        assert_eq!(similarity("Radeon RX 6700 XT", "RX 6700"), 0.0);
+        assert!(similarity("Radeon RX 6700 XT", "AMD Radeon RX 6700 XT") > 0.0);
+    }
+
+    #[test]
+    fn intel_f_parts_match_loosely() {
+        assert_eq!(similarity("Intel Core i5-12400F", "Intel Core i5-12400"), 0.95);
+        assert_eq!(similarity("Intel Core i7-12400", "Intel Core i5-12400"), 0.0);
+    }
+}
@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Instant;
+use sysinfo::{Pid, ProcessRefreshKind, System, UpdateKind, Users};
+use winapi::shared::minwindef::DWORD;
+use winapi::um::handleapi::{CloseHandle, INVALID_HANDLE_VALUE};
+use winapi::um::processthreadsapi::{GetPriorityClass, OpenProcess, SetPriorityClass};
+use winapi::um::tlhelp32::{CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS};
+use winapi::um::winbase::{
+    GetProcessAffinityMask, SetProcessAffinityMask, ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS,
+    HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS, REALTIME_PRIORITY_CLASS,
+};
+use winapi::um::winnt::{HANDLE, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION};
+
+// Per-process monitor. CPU and disk figures are deltas between two listings, so the first
+// call after startup reports zeros; the frontend polls and gets real numbers from the second one on.
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Priority {
+    Idle,
+    BelowNormal,
+    Normal,
+    AboveNormal,
+    High,
+    // Without admin rights Windows quietly uses High instead
+    Realtime,
+}
+
+impl Priority {
+    fn class(self) -> DWORD {
+        match self {
+            Priority::Idle => IDLE_PRIORITY_CLASS,
+            Priority::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+            Priority::Normal => NORMAL_PRIORITY_CLASS,
+            Priority::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            Priority::High => HIGH_PRIORITY_CLASS,
+            Priority::Realtime => REALTIME_PRIORITY_CLASS,
+        }
+    }
+
+    fn from_class(class: DWORD) -> Option<Self> {
+        [Priority::Idle, Priority::BelowNormal, Priority::Normal, Priority::AboveNormal, Priority::High, Priority::Realtime]
+            .into_iter()
+            .find(|p| p.class() == class)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    pub path: Option<String>,
+    pub user: Option<String>,
+    // Share of the whole machine, 0-100
+    pub cpu_usage: f32,
+    // Working set, bytes
+    pub memory: u64,
+    // Bytes per second since the previous listing
+    pub disk_read_bps: f64,
+    pub disk_write_bps: f64,
+    pub threads: Option<u32>,
+    // Unix seconds
+    pub start_time: u64,
+    // None when the process cannot be opened (protected or other users' processes without admin)
+    pub priority: Option<Priority>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ProcessNode {
+    #[serde(flatten)]
+    pub process: ProcessInfo,
+    pub children: Vec<ProcessNode>,
+}
+
+struct MonitorState {
+    sys: System,
+    users: Users,
+    last_refresh: Option<Instant>,
+}
+
+pub struct ProcessMonitor {
+    state: Mutex<MonitorState>,
+}
+
+impl Default for ProcessMonitor {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(MonitorState { sys: System::new(), users: Users::new_with_refreshed_list(), last_refresh: None }),
+        }
+    }
+}
+
+// Closes the handle on drop
+struct ProcessHandle(HANDLE);
+
+impl ProcessHandle {
+    fn open(pid: u32, access: DWORD) -> Result<Self, String> {
+        let handle = unsafe { OpenProcess(access, 0, pid) };
+        if handle.is_null() {
+            return Err(format!("Cannot open process {}: {}", pid, std::io::Error::last_os_error()));
+        }
+        Ok(ProcessHandle(handle))
+    }
+}
+
+impl Drop for ProcessHandle {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+fn priority_of(pid: u32) -> Option<Priority> {
+    let handle = ProcessHandle::open(pid, PROCESS_QUERY_LIMITED_INFORMATION).ok()?;
+    Priority::from_class(unsafe { GetPriorityClass(handle.0) })
+}
+
+// Thread count per PID from one Toolhelp snapshot; sysinfo does not report it on Windows
+fn thread_counts() -> HashMap<u32, u32> {
+    let mut counts = HashMap::new();
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0);
+        if snapshot == INVALID_HANDLE_VALUE {
+            return counts;
+        }
+        let mut entry: PROCESSENTRY32W = std::mem::zeroed();
+        entry.dwSize = std::mem::size_of::<PROCESSENTRY32W>() as u32;
+        let mut more = Process32FirstW(snapshot, &mut entry) != 0;
+        while more {
+            counts.insert(entry.th32ProcessID, entry.cntThreads);
+            more = Process32NextW(snapshot, &mut entry) != 0;
+        }
+        CloseHandle(snapshot);
+    }
+    counts
+}
+
+impl ProcessMonitor {
+    fn list(&self) -> Vec<ProcessInfo> {
+        let mut state = self.state.lock().unwrap();
+        state.sys.refresh_processes_specifics(
+            ProcessRefreshKind::new()
+                .with_cpu()
+                .with_memory()
+                .with_disk_usage()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_user(UpdateKind::OnlyIfNotSet),
+        );
+        let secs = state.last_refresh.map(|t| t.elapsed().as_secs_f64()).filter(|s| *s > 0.0);
+        state.last_refresh = Some(Instant::now());
+        let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f32;
+        let threads = thread_counts();
+
+        let mut processes: Vec<ProcessInfo> = state.sys.processes().values()
+            .map(|p| {
+                let pid = p.pid().as_u32();
+                let disk = p.disk_usage();
+                let rate = |bytes: u64| secs.map(|s| (bytes as f64 / s).round()).unwrap_or(0.0);
+                ProcessInfo {
+                    pid,
+                    parent: p.parent().map(|parent| parent.as_u32()),
+                    name: p.name().to_string(),
+                    path: p.exe().map(|e| e.to_string_lossy().to_string()),
+                    user: p.user_id().and_then(|uid| state.users.get_user_by_id(uid)).map(|u| u.name().to_string()),
+                    cpu_usage: p.cpu_usage() / cores,
+                    memory: p.memory(),
+                    disk_read_bps: rate(disk.read_bytes),
+                    disk_write_bps: rate(disk.written_bytes),
+                    threads: threads.get(&pid).copied(),
+                    start_time: p.start_time(),
+                    priority: priority_of(pid),
+                }
+            })
+            .collect();
+        processes.sort_by_key(|p| p.pid);
+        processes
+    }
+}
+
+// Children under their parents. A parent that started after its child is a different process
+// that reused the PID, so the child becomes a root.
+fn build_tree(processes: Vec<ProcessInfo>) -> Vec<ProcessNode> {
+    let start_times: HashMap<u32, u64> = processes.iter().map(|p| (p.pid, p.start_time)).collect();
+    let mut children: HashMap<u32, Vec<ProcessInfo>> = HashMap::new();
+    let mut roots = Vec::new();
+    for process in processes {
+        let parent = process.parent
+            .filter(|parent| *parent != process.pid)
+            .filter(|parent| start_times.get(parent).is_some_and(|started| *started <= process.start_time));
+        match parent {
+            Some(parent) => children.entry(parent).or_default().push(process),
+            None => roots.push(process),
+        }
+    }
+    fn attach(process: ProcessInfo, children: &mut HashMap<u32, Vec<ProcessInfo>>, seen: &mut HashSet<u32>) -> ProcessNode {
+        seen.insert(process.pid);
+        let mut nodes = Vec::new();
+        for kid in children.remove(&process.pid).unwrap_or_default() {
+            if !seen.contains(&kid.pid) {
+                nodes.push(attach(kid, children, seen));
+            }
+        }
+        ProcessNode { process, children: nodes }
+    }
+    let mut seen = HashSet::new();
+    let mut tree: Vec<ProcessNode> = roots.into_iter().map(|r| attach(r, &mut children, &mut seen)).collect();
+    // Whatever is left hangs off a cycle of equal start times; show it flat rather than lose it
+    let rest: Vec<ProcessInfo> = children.into_values().flatten().filter(|p| !seen.contains(&p.pid)).collect();
+    tree.extend(rest.into_iter().map(|p| ProcessNode { process: p, children: Vec::new() }));
+    tree
+}
+
+// System Idle Process, System and this app itself are off limits
+fn check_target(pid: u32) -> Result<(), String> {
+    if pid == 0 || pid == 4 {
+        return Err("System processes cannot be changed".to_string());
+    }
+    if pid == std::process::id() {
+        return Err("This is the hardware viewer itself".to_string());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_process_list(monitor: tauri::State<'_, ProcessMonitor>) -> Vec<ProcessInfo> {
+    monitor.list()
+}
+
+#[tauri::command]
+pub fn get_process_tree(monitor: tauri::State<'_, ProcessMonitor>) -> Vec<ProcessNode> {
+    build_tree(monitor.list())
+}
+
+#[tauri::command]
+pub fn end_process(monitor: tauri::State<'_, ProcessMonitor>, pid: u32) -> Result<(), String> {
+    check_target(pid)?;
+    let state = monitor.state.lock().unwrap();
+    let process = state.sys.process(Pid::from_u32(pid)).ok_or_else(|| format!("Process {} not found", pid))?;
+    if !process.kill() {
+        return Err(format!("Could not end {} ({}), it may need administrator rights", process.name(), pid));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_process_priority(pid: u32, priority: Priority) -> Result<(), String> {
+    check_target(pid)?;
+    let handle = ProcessHandle::open(pid, PROCESS_SET_INFORMATION)?;
+    if unsafe { SetPriorityClass(handle.0, priority.class()) } == 0 {
+        return Err(format!("Could not change the priority: {}", std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+// Logical processors the process may run on, as indexes
+#[tauri::command]
+pub fn get_process_affinity(pid: u32) -> Result<Vec<u32>, String> {
+    let handle = ProcessHandle::open(pid, PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let (mut process_mask, mut system_mask) = (0usize, 0usize);
+    if unsafe { GetProcessAffinityMask(handle.0, &mut process_mask, &mut system_mask) } == 0 {
+        return Err(format!("Could not read the affinity: {}", std::io::Error::last_os_error()));
+    }
+    Ok((0..usize::BITS).filter(|i| process_mask & (1 << i) != 0).collect())
+}
+
+// `cores` are logical processor indexes; only the first 64 (one processor group) can be set
+#[tauri::command]
+pub fn set_process_affinity(pid: u32, cores: Vec<u32>) -> Result<(), String> {
+    check_target(pid)?;
+    let mask = cores.iter()
+        .filter(|c| **c < usize::BITS)
+        .fold(0usize, |mask, c| mask | (1 << c));
+    if mask == 0 {
+        return Err("Select at least one processor".to_string());
+    }
+    let handle = ProcessHandle::open(pid, PROCESS_SET_INFORMATION | PROCESS_QUERY_LIMITED_INFORMATION)?;
+    let (mut process_mask, mut system_mask) = (0usize, 0usize);
+    unsafe { GetProcessAffinityMask(handle.0, &mut process_mask, &mut system_mask) };
+    if system_mask != 0 && mask & !system_mask != 0 {
+        return Err("Some of the selected processors do not exist".to_string());
+    }
+    if unsafe { SetProcessAffinityMask(handle.0, mask) } == 0 {
+        return Err(format!("Could not set the affinity: {}", std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
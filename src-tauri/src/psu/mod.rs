@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::motherboard::FormFactor;
+
+pub mod power;
+
+use power::{PowerMatch, PowerSource};
+
+// Power supply sizing: adds up the worst-case draw of every component in the scanned machine
+// and, optionally, the same machine with a different CPU/GPU or extra parts, then rounds the
+// total plus headroom up to a common PSU rating.
+
+// Typical draw of the smaller parts
+const RAM_MODULE_WATTS: u32 = 4;
+const NVME_WATTS: u32 = 8;
+const SSD_WATTS: u32 = 4;
+// Spinning up, idle is about half of that
+const HDD_WATTS: u32 = 10;
+const FAN_WATTS: u32 = 3;
+// 5 V at 500 mA, what a USB 2.0 port may hand out
+const USB_DEVICE_WATTS: u32 = 3;
+// Chipset, VRM losses, onboard audio and network
+const DESKTOP_BOARD_WATTS: u32 = 50;
+const PORTABLE_BOARD_WATTS: u32 = 20;
+// Firmware that exposes no fans still has some
+const DEFAULT_DESKTOP_FANS: u32 = 3;
+const DEFAULT_PORTABLE_FANS: u32 = 1;
+
+// Covers graphics card transients and keeps the unit near its most efficient load (~50-70%)
+const HEADROOM: f64 = 1.4;
+// Cards above this spike well over their rating for a few milliseconds
+const TRANSIENT_GPU_WATTS: u32 = 300;
+const PSU_RATINGS: [u32; 10] = [400, 450, 550, 650, 750, 850, 1000, 1200, 1350, 1600];
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum PsuVerdict {
+    // Rated at or above the recommendation
+    Enough,
+    // Covers the load but not the headroom
+    Tight,
+    TooSmall,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PowerItem {
+    pub component: String,
+    pub name: String,
+    pub count: u32,
+    // Total for all `count` parts
+    pub watts: u32,
+    pub source: PowerSource,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PowerEstimate {
+    pub items: Vec<PowerItem>,
+    pub load_watts: u32,
+    pub recommended_watts: u32,
+    // Only when the installed PSU rating was given
+    pub verdict: Option<PsuVerdict>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PsuReport {
+    pub psu_watts: Option<u32>,
+    pub current: PowerEstimate,
+    pub upgraded: Option<PowerEstimate>,
+    pub notes: Vec<String>,
+}
+
+// The hypothetical machine: parts to swap in and parts to add
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct PsuUpgrade {
+    // Replaces the installed CPU
+    pub cpu: Option<String>,
+    // Replaces every graphics card, or is added when there only is integrated graphics
+    pub gpu: Option<String>,
+    pub extra_ram_modules: u32,
+    pub extra_nvme: u32,
+    pub extra_ssd: u32,
+    pub extra_hdd: u32,
+    pub extra_fans: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PowerConfig {
+    pub cpus: Vec<PowerMatch>,
+    // Graphics cards only, integrated graphics are part of the CPU figure
+    pub gpus: Vec<PowerMatch>,
+    pub ram_modules: u32,
+    pub nvme: u32,
+    pub ssd: u32,
+    pub hdd: u32,
+    pub fans: u32,
+    pub usb_devices: u32,
+    pub portable: bool,
+}
+
+impl PowerConfig {
+    pub fn with_upgrade(&self, upgrade: &PsuUpgrade) -> PowerConfig {
+        let mut config = self.clone();
+        if let Some(cpu) = &upgrade.cpu {
+            config.cpus = vec![power::cpu_power(cpu)];
+        }
+        if let Some(gpu) = &upgrade.gpu {
+            config.gpus = vec![power::gpu_power(gpu, None)];
+        }
+        config.ram_modules += upgrade.extra_ram_modules;
+        config.nvme += upgrade.extra_nvme;
+        config.ssd += upgrade.extra_ssd;
+        config.hdd += upgrade.extra_hdd;
+        config.fans += upgrade.extra_fans;
+        config
+    }
+}
+
+fn item(component: &str, name: &str, count: u32, each: u32) -> PowerItem {
+    PowerItem { component: component.to_string(), name: name.to_string(), count, watts: count * each, source: PowerSource::Estimated }
+}
+
+fn part_item(component: &str, part: &PowerMatch) -> PowerItem {
+    PowerItem { component: component.to_string(), name: part.name.clone(), count: 1, watts: part.watts, source: part.source }
+}
+
+pub fn recommended_rating(load_watts: u32) -> u32 {
+    let target = (load_watts as f64 * HEADROOM).ceil() as u32;
+    PSU_RATINGS.iter().copied().find(|r| *r >= target)
+        // Beyond consumer units, round up to the next 100 W
+        .unwrap_or(target.div_ceil(100) * 100)
+}
+
+pub fn verdict(psu_watts: u32, load_watts: u32, recommended_watts: u32) -> PsuVerdict {
+    if psu_watts >= recommended_watts {
+        PsuVerdict::Enough
+    } else if psu_watts >= load_watts {
+        PsuVerdict::Tight
+    } else {
+        PsuVerdict::TooSmall
+    }
+}
+
+pub fn estimate(config: &PowerConfig, psu_watts: Option<u32>) -> PowerEstimate {
+    let mut items: Vec<PowerItem> = Vec::new();
+    items.extend(config.cpus.iter().map(|c| part_item("cpu", c)));
+    items.extend(config.gpus.iter().map(|g| part_item("gpu", g)));
+    if config.portable {
+        items.push(item("board", "Mainboard", 1, PORTABLE_BOARD_WATTS));
+    } else {
+        items.push(item("board", "Motherboard", 1, DESKTOP_BOARD_WATTS));
+    }
+    for (component, name, count, each) in [
+        ("ram", "Memory module", config.ram_modules, RAM_MODULE_WATTS),
+        ("storage", "NVMe SSD", config.nvme, NVME_WATTS),
+        ("storage", "SATA SSD", config.ssd, SSD_WATTS),
+        ("storage", "Hard drive", config.hdd, HDD_WATTS),
+        ("fans", "Fan", config.fans, FAN_WATTS),
+        ("usb", "USB device", config.usb_devices, USB_DEVICE_WATTS),
+    ] {
+        if count > 0 {
+            items.push(item(component, name, count, each));
+        }
+    }
+
+    let load_watts = items.iter().map(|i| i.watts).sum();
+    let recommended_watts = recommended_rating(load_watts);
+    PowerEstimate {
+        items,
+        load_watts,
+        recommended_watts,
+        verdict: psu_watts.map(|p| verdict(p, load_watts, recommended_watts)),
+    }
+}
+
+fn notes_for(config: &PowerConfig, estimates: &[&PowerEstimate]) -> Vec<String> {
+    let mut notes = Vec::new();
+    if config.portable {
+        notes.push("Portable systems run from their own power adapter, the figures only compare configurations".to_string());
+    }
+    let mut estimated: Vec<&str> = Vec::new();
+    for item in estimates.iter().flat_map(|e| e.items.iter()) {
+        let is_part = matches!(item.component.as_str(), "cpu" | "gpu");
+        if is_part && item.source == PowerSource::Estimated && item.watts > 0 && !estimated.contains(&item.name.as_str()) {
+            estimated.push(&item.name);
+            notes.push(format!("{} is not in the power database, a typical figure was used", item.name));
+        }
+    }
+    let peak_gpu = estimates.iter().flat_map(|e| e.items.iter()).filter(|i| i.component == "gpu").map(|i| i.watts).max();
+    if peak_gpu.is_some_and(|w| w >= TRANSIENT_GPU_WATTS) {
+        notes.push("Cards of this class draw short spikes far above their rating, an ATX 3.x unit handles them best".to_string());
+    }
+    notes
+}
+
+pub fn report(config: &PowerConfig, upgrade: Option<&PsuUpgrade>, psu_watts: Option<u32>) -> PsuReport {
+    let current = estimate(config, psu_watts);
+    let upgraded = upgrade.map(|u| estimate(&config.with_upgrade(u), psu_watts));
+    let mut estimates = vec![&current];
+    estimates.extend(upgraded.as_ref());
+    let notes = notes_for(config, &estimates);
+    PsuReport { psu_watts, current, upgraded, notes }
+}
+
+pub fn collect_config() -> anyhow::Result<PowerConfig> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi()?;
+
+    let portable = hardware::motherboard::get_chassis_info(&ctx)
+        .map(|c| c.form_factor.is_portable() || c.form_factor == FormFactor::AllInOne)
+        .unwrap_or(false);
+    let cpus = hardware::cpu::get_cpu_info(&mut ctx).unwrap_or_default().iter()
+        .map(|c| power::cpu_power(&c.name))
+        .collect();
+    let gpus = hardware::gpu::get_gpu_info(&ctx).unwrap_or_default().iter()
+        .filter(|g| !power::is_integrated_gpu(&g.name))
+        .map(|g| power::gpu_power(&g.name, g.adapter_ram))
+        .collect();
+    let ram_modules = hardware::memory::get_memory_info(&mut ctx).map(|m| m.len() as u32).unwrap_or(0);
+
+    let (mut nvme, mut ssd, mut hdd) = (0, 0, 0);
+    for disk in hardware::disk::get_disk_info(&ctx).unwrap_or_default() {
+        // USB enclosures are powered by the port, already counted below
+        if disk.bus_type.to_lowercase().contains("usb") {
+            continue;
+        }
+        if disk.bus_type.to_lowercase().contains("nvme") {
+            nvme += 1;
+        } else if disk.media_type.is_solid_state() {
+            ssd += 1;
+        } else {
+            hdd += 1;
+        }
+    }
+
+    let fans = match hardware::motherboard::get_fan_count(&ctx) {
+        Ok(count) if count > 0 => count as u32,
+        _ if portable => DEFAULT_PORTABLE_FANS,
+        _ => DEFAULT_DESKTOP_FANS,
+    };
+    // Hubs and host controllers show up in the USB class too
+    let usb_devices = hardware::peripherals::get_usb_devices(&ctx).unwrap_or_default().iter()
+        .filter(|d| {
+            let name = d.name.to_lowercase();
+            !["hub", "controller", "composite"].iter().any(|w| name.contains(w))
+        })
+        .count() as u32;
+
+    Ok(PowerConfig { cpus, gpus, ram_modules, nvme, ssd, hdd, fans, usb_devices, portable })
+}
+
+// `upgrade` describes the machine after the planned change, `psu_watts` is the installed unit's rating
+#[tauri::command]
+pub fn estimate_psu(upgrade: Option<PsuUpgrade>, psu_watts: Option<u32>) -> Result<PsuReport, String> {
+    let config = collect_config().map_err(|e| e.to_string())?;
+    Ok(report(&config, upgrade.as_ref(), psu_watts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommended_rating_adds_headroom() {
+        // 300 W * 1.4 = 420 W
+        assert_eq!(recommended_rating(300), 450);
+        // 500 W * 1.4 = 700 W
+        assert_eq!(recommended_rating(500), 750);
+        assert_eq!(recommended_rating(0), 400);
+        // 1200 W * 1.4 = 1680 W, past the largest common unit
+        assert_eq!(recommended_rating(1200), 1700);
+    }
+
+    #[test]
+    fn verdict_thresholds() {
+        assert_eq!(verdict(750, 500, 750), PsuVerdict::Enough);
+        assert_eq!(verdict(650, 500, 750), PsuVerdict::Tight);
+        assert_eq!(verdict(500, 500, 750), PsuVerdict::Tight);
+        assert_eq!(verdict(450, 500, 750), PsuVerdict::TooSmall);
+    }
+}
@@ -0,0 +1,341 @@
+use serde::Serialize;
+use crate::model_name::{ModelName, Vendor};
+
+// Worst-case sustained draw in watts: Intel PL2 (maximum turbo power), AMD PPT, graphics card board power.
+// Names follow the benchmark tables so the same spellings match; F/KF variants draw the same as the
+// full part and are found through the fuzzy match.
+
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct PowerEntry {
+    pub name: &'static str,
+    pub watts: u32,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum PowerSource {
+    Database,
+    Estimated,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PowerMatch {
+    pub name: String,
+    pub watts: u32,
+    pub source: PowerSource,
+}
+
+const fn part(name: &'static str, watts: u32) -> PowerEntry {
+    PowerEntry { name, watts }
+}
+
+const MIN_SIMILARITY: f32 = 0.5;
+
+// Fallbacks when the part is not in the tables
+const DESKTOP_CPU_WATTS: u32 = 125;
+const MOBILE_CPU_WATTS: u32 = 45;
+const DESKTOP_GPU_WATTS: u32 = 200;
+const MOBILE_GPU_WATTS: u32 = 100;
+
+fn find_best(table: &[PowerEntry], query: &ModelName) -> Option<PowerMatch> {
+    let mut best: Option<(f32, &PowerEntry)> = None;
+    for entry in table {
+        let sim = query.similarity(&ModelName::parse(entry.name));
+        if sim >= MIN_SIMILARITY && best.is_none_or(|(s, _)| sim > s) {
+            best = Some((sim, entry));
+        }
+    }
+    best.map(|(_, entry)| PowerMatch { name: entry.name.to_string(), watts: entry.watts, source: PowerSource::Database })
+}
+
+fn estimated(name: &str, watts: u32) -> PowerMatch {
+    PowerMatch { name: name.to_string(), watts, source: PowerSource::Estimated }
+}
+
+pub fn cpu_power(name: &str) -> PowerMatch {
+    let query = ModelName::parse(name);
+    // Laptop parts share model numbers with desktop chips but run at a fraction of the power
+    if query.mobile {
+        return estimated(name, MOBILE_CPU_WATTS);
+    }
+    find_best(CPU_POWER, &query).unwrap_or_else(|| estimated(name, DESKTOP_CPU_WATTS))
+}
+
+// Integrated graphics draw from the CPU package budget and add nothing of their own. Only the
+// names CPU graphics actually use count, so discrete cards are never dropped from the total:
+//   Intel    UHD / HD / Iris Graphics, "Arc Graphics" without a model number
+//   AMD      "Radeon Graphics", "Radeon Vega 8 Graphics", "Radeon 780M" (6x0M-8x0M, no RX)
+//   Qualcomm Adreno
+pub fn is_integrated_gpu(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    if lower.contains("basic display") || lower.contains("virtual") || lower.contains("remote display") {
+        return true;
+    }
+    let query = ModelName::parse(name);
+    let words: Vec<&str> = query.series.split_whitespace().collect();
+    match query.vendor {
+        Vendor::Intel => {
+            words.iter().any(|w| ["UHD", "HD", "Iris"].contains(w)) || (words.contains(&"Arc") && query.model.is_none())
+        }
+        Vendor::Amd => {
+            if words.first() != Some(&"Radeon") || words.iter().any(|w| ["RX", "PRO", "WX", "VII"].contains(w)) {
+                return false;
+            }
+            let vega_number = words.iter()
+                .position(|w| *w == "Vega")
+                .and_then(|i| words.get(i + 1))
+                .is_some_and(|n| n.parse::<u32>().is_ok());
+            let apu_model = query.model.as_deref().is_some_and(|m| {
+                m.len() == 3 && m.ends_with('0') && matches!(m.as_bytes()[0], b'6'..=b'8')
+            }) && query.suffixes == ["M"];
+            (words.len() == 1 && query.model.is_none()) || vega_number || apu_model
+        }
+        Vendor::Qualcomm => true,
+        _ => false,
+    }
+}
+
+pub fn gpu_power(name: &str, vram_bytes: Option<u64>) -> PowerMatch {
+    if is_integrated_gpu(name) {
+        return estimated(name, 0);
+    }
+    let mut query = ModelName::parse(name);
+    if query.mobile {
+        return estimated(name, MOBILE_GPU_WATTS);
+    }
+    // "RTX 3080 12GB" and "RTX 3080 10GB" differ in board power as well
+    if query.memory_gb.is_none() {
+        query.memory_gb = vram_bytes.map(|b| (b as f64 / (1024.0 * 1024.0 * 1024.0)).round() as u32).filter(|gb| *gb > 0);
+    }
+    find_best(GPU_POWER, &query).unwrap_or_else(|| estimated(name, DESKTOP_GPU_WATTS))
+}
+
+pub static CPU_POWER: &[PowerEntry] = &[
+    // AMD Ryzen 9000 Series
+    part("AMD Ryzen 9 9950X", 230),
+    part("AMD Ryzen 9 9900X", 162),
+    part("AMD Ryzen 7 9700X", 88),
+    part("AMD Ryzen 5 9600X", 88),
+
+    // Intel 14th Gen
+    part("Intel Core i9-14900KS", 253),
+    part("Intel Core i9-14900K", 253),
+    part("Intel Core i9-14900", 219),
+    part("Intel Core i7-14700K", 253),
+    part("Intel Core i7-14700", 219),
+    part("Intel Core i5-14600K", 181),
+    part("Intel Core i5-14500", 154),
+    part("Intel Core i5-14400", 148),
+    part("Intel Core i3-14100", 110),
+
+    // AMD Ryzen 7000 Series
+    part("AMD Ryzen 9 7950X3D", 162),
+    part("AMD Ryzen 9 7950X", 230),
+    part("AMD Ryzen 9 7900X3D", 162),
+    part("AMD Ryzen 9 7900X", 230),
+    part("AMD Ryzen 9 7900", 88),
+    part("AMD Ryzen 7 7800X3D", 162),
+    part("AMD Ryzen 7 7700X", 142),
+    part("AMD Ryzen 7 7700", 88),
+    part("AMD Ryzen 5 7600X", 142),
+    part("AMD Ryzen 5 7600", 88),
+    part("AMD Ryzen 5 7500F", 88),
+
+    // Intel 13th Gen
+    part("Intel Core i9-13900KS", 253),
+    part("Intel Core i9-13900K", 253),
+    part("Intel Core i9-13900", 219),
+    part("Intel Core i7-13700K", 253),
+    part("Intel Core i7-13700", 219),
+    part("Intel Core i5-13600K", 181),
+    part("Intel Core i5-13500", 154),
+    part("Intel Core i5-13490F", 148),
+    part("Intel Core i5-13400", 148),
+    part("Intel Core i3-13100", 89),
+
+    // AMD Ryzen 5000 Series
+    part("AMD Ryzen 9 5950X", 142),
+    part("AMD Ryzen 9 5900X", 142),
+    part("AMD Ryzen 7 5800X3D", 142),
+    part("AMD Ryzen 7 5800X", 142),
+    part("AMD Ryzen 7 5700X", 88),
+    part("AMD Ryzen 7 5700G", 88),
+    part("AMD Ryzen 5 5600X", 88),
+    part("AMD Ryzen 5 5600", 88),
+    part("AMD Ryzen 5 5600G", 88),
+    part("AMD Ryzen 5 5500", 88),
+
+    // Intel 12th Gen
+    part("Intel Core i9-12900KS", 241),
+    part("Intel Core i9-12900K", 241),
+    part("Intel Core i9-12900", 202),
+    part("Intel Core i7-12700K", 190),
+    part("Intel Core i7-12700", 180),
+    part("Intel Core i5-12600K", 150),
+    part("Intel Core i5-12490F", 117),
+    part("Intel Core i5-12400", 117),
+    part("Intel Core i3-12100", 89),
+
+    // Intel 11th / 10th Gen
+    part("Intel Core i9-11900K", 251),
+    part("Intel Core i7-11700K", 251),
+    part("Intel Core i5-11600K", 182),
+    part("Intel Core i5-11400", 154),
+    part("Intel Core i9-10900K", 250),
+    part("Intel Core i7-10700K", 229),
+    part("Intel Core i5-10600K", 182),
+    part("Intel Core i5-10400", 134),
+    part("Intel Core i3-10105F", 90),
+    part("Intel Core i3-10100F", 90),
+
+    // AMD Ryzen 3000 / 2000 / 1000 Series
+    part("AMD Ryzen 9 3950X", 142),
+    part("AMD Ryzen 9 3900X", 142),
+    part("AMD Ryzen 7 3800X", 142),
+    part("AMD Ryzen 7 3700X", 88),
+    part("AMD Ryzen 5 3600X", 128),
+    part("AMD Ryzen 5 3600", 88),
+    part("AMD Ryzen 5 3500X", 88),
+    part("AMD Ryzen 3 3300X", 88),
+    part("AMD Ryzen 3 3100", 88),
+    part("AMD Ryzen 5 3400G", 88),
+    part("AMD Ryzen 3 3200G", 88),
+    part("AMD Ryzen 7 2700X", 142),
+    part("AMD Ryzen 5 2600", 88),
+    part("AMD Ryzen 7 1800X", 128),
+
+    // Older parts, boards of that era mostly ran them at TDP
+    part("Intel Core i9-9900K", 160),
+    part("Intel Core i7-9700K", 150),
+    part("Intel Core i5-9600KF", 130),
+    part("Intel Core i5-9400F", 90),
+    part("Intel Core i3-9100F", 65),
+    part("Intel Core i7-8700K", 130),
+    part("Intel Core i5-8400", 90),
+    part("Intel Core i7-7700K", 91),
+    part("Intel Core i7-6700K", 91),
+    part("Intel Core i7-4790K", 88),
+    part("Intel Core i7-3770K", 77),
+    part("Intel Core i7-2600K", 95),
+    part("Intel Core i5-2500K", 95),
+    part("Intel Core 2 Quad Q6600", 105),
+    part("AMD FX-8350", 125),
+];
+
+pub static GPU_POWER: &[PowerEntry] = &[
+    part("NVIDIA GeForce RTX 4090", 450),
+    part("NVIDIA GeForce RTX 4080 Super", 320),
+    part("NVIDIA GeForce RTX 4080", 320),
+    part("NVIDIA GeForce RTX 4070 Ti Super", 285),
+    part("NVIDIA GeForce RTX 4070 Ti", 285),
+    part("NVIDIA GeForce RTX 4070 Super", 220),
+    part("NVIDIA GeForce RTX 4070", 200),
+    part("NVIDIA GeForce RTX 4060 Ti", 165),
+    part("NVIDIA GeForce RTX 4060", 115),
+    part("NVIDIA GeForce RTX 3090 Ti", 450),
+    part("NVIDIA GeForce RTX 3090", 350),
+    part("NVIDIA GeForce RTX 3080 Ti", 350),
+    part("NVIDIA GeForce RTX 3080 12GB", 350),
+    part("NVIDIA GeForce RTX 3080 10GB", 320),
+    part("NVIDIA GeForce RTX 3070 Ti", 290),
+    part("NVIDIA GeForce RTX 3070", 220),
+    part("NVIDIA GeForce RTX 3060 Ti", 200),
+    part("NVIDIA GeForce RTX 3060 12GB", 170),
+    part("NVIDIA GeForce RTX 3050", 130),
+    part("NVIDIA GeForce RTX 2080 Ti", 250),
+    part("NVIDIA GeForce RTX 2080 Super", 250),
+    part("NVIDIA GeForce RTX 2070 Super", 215),
+    part("NVIDIA GeForce RTX 2060 Super", 175),
+    part("NVIDIA GeForce RTX 2060", 160),
+    part("NVIDIA GeForce GTX 1660 Super", 125),
+    part("NVIDIA GeForce GTX 1660 Ti", 120),
+    part("NVIDIA GeForce GTX 1650", 75),
+    part("NVIDIA GeForce GTX 1080 Ti", 250),
+    part("NVIDIA GeForce GTX 1080", 180),
+    part("NVIDIA GeForce GTX 1070 Ti", 180),
+    part("NVIDIA GeForce GTX 1070", 150),
+    part("NVIDIA GeForce GTX 1060 6GB", 120),
+    part("NVIDIA GeForce GTX 1050 Ti", 75),
+    part("NVIDIA GeForce GTX 980 Ti", 250),
+    part("NVIDIA GeForce GTX 980", 165),
+    part("NVIDIA GeForce GTX 970", 145),
+    part("NVIDIA GeForce GTX 960", 120),
+    part("NVIDIA GeForce GTX 750 Ti", 60),
+    part("NVIDIA GeForce GTX 660", 140),
+    part("NVIDIA GeForce GT 1030", 30),
+    part("AMD Radeon RX 7900 XTX", 355),
+    part("AMD Radeon RX 7900 XT", 315),
+    part("AMD Radeon RX 7800 XT", 263),
+    part("AMD Radeon RX 6950 XT", 335),
+    part("AMD Radeon RX 6900 XT", 300),
+    part("AMD Radeon RX 6800 XT", 300),
+    part("AMD Radeon RX 6800", 250),
+    part("AMD Radeon RX 6700 XT", 230),
+    part("AMD Radeon RX 6600 XT", 160),
+    part("AMD Radeon RX 5700 XT", 225),
+    part("AMD Radeon RX 5600 XT", 150),
+    part("AMD Radeon RX 580", 185),
+    part("AMD Radeon VII", 300),
+    part("AMD Radeon RX Vega 64", 295),
+    part("AMD Radeon RX Vega 56", 210),
+    // Some drivers report both Vega cards by this name, so it takes the larger one
+    part("AMD Radeon RX Vega", 295),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integrated_graphics_by_cpu_naming() {
+        for name in [
+            "Intel(R) UHD Graphics 630",
+            "Intel(R) HD Graphics 620",
+            "Intel(R) Iris(R) Xe Graphics",
+            "Intel(R) Arc(TM) Graphics",
+            "AMD Radeon(TM) Graphics",
+            "AMD Radeon(TM) Vega 8 Graphics",
+            "AMD Radeon 780M Graphics",
+            "AMD Radeon(TM) 610M",
+            "Microsoft Basic Display Adapter",
+        ] {
+            assert!(is_integrated_gpu(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn graphics_cards_are_not_integrated() {
+        for name in [
+            "AMD Radeon RX Vega",
+            "Radeon RX Vega 56",
+            "AMD Radeon RX Vega 64",
+            "AMD Radeon VII",
+            "AMD Radeon HD 7970",
+            "AMD Radeon RX 6800M",
+            "AMD Radeon Pro 5500M",
+            "Intel(R) Arc(TM) A770 Graphics",
+            "NVIDIA GeForce RTX 3060",
+        ] {
+            assert!(!is_integrated_gpu(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn gpu_power_from_database() {
+        assert_eq!(gpu_power("AMD Radeon RX Vega 64", None).watts, 295);
+        assert_eq!(gpu_power("Radeon RX Vega 56", None).watts, 210);
+        assert_eq!(gpu_power("AMD Radeon VII", None).watts, 300);
+        assert_eq!(gpu_power("NVIDIA GeForce RTX 4090", None).source, PowerSource::Database);
+        // VRAM size picks between the two RTX 3080 boards
+        assert_eq!(gpu_power("NVIDIA GeForce RTX 3080", Some(12 * 1024 * 1024 * 1024)).watts, 350);
+        assert_eq!(gpu_power("NVIDIA GeForce RTX 3080", Some(10 * 1024 * 1024 * 1024)).watts, 320);
+    }
+
+    #[test]
+    fn gpu_power_fallbacks() {
+        assert_eq!(gpu_power("AMD Radeon 780M Graphics", None).watts, 0);
+        assert_eq!(gpu_power("NVIDIA GeForce RTX 3060 Laptop GPU", None).watts, MOBILE_GPU_WATTS);
+        let unknown = gpu_power("NVIDIA GeForce RTX 9999", None);
+        assert_eq!(unknown.watts, DESKTOP_GPU_WATTS);
+        assert_eq!(unknown.source, PowerSource::Estimated);
+    }
+}
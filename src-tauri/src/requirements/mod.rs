@@ -0,0 +1,361 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::gpu::{GpuInfo, VramSource, WMI_VRAM_CAP};
+use crate::hardware::os::OsInfo;
+use crate::model_name::ModelName;
+use crate::scoring::benchmarks::{self, BenchmarkMatch};
+use crate::win11::{self, CheckStatus};
+
+// "Can my PC run X": published minimum/recommended specs compared against the scanned hardware.
+// The definitions ship in games.json and can be overridden (same id) or extended in
+// neko233-hardware-viewer.requirements.json:
+//   { "games": [ { "id": "my-game", "name": "My Game", "minimum": { ... }, "recommended": { ... } } ] }
+
+pub const REQUIREMENTS_FILE: &str = "neko233-hardware-viewer.requirements.json";
+const BUNDLED_REQUIREMENTS: &str = include_str!("games.json");
+
+const GB: u64 = 1024 * 1024 * 1024;
+
+// Minimum build of each Windows release a requirement can name, most specific first
+const WINDOWS_BUILDS: [(&str, u32); 5] = [
+    ("WINDOWS 11", 22000),
+    ("WINDOWS 10", 10240),
+    ("WINDOWS 8.1", 9600),
+    ("WINDOWS 8", 9200),
+    ("WINDOWS 7", 7600),
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Requirements {
+    // Alternatives ("i5-8400 / Ryzen 5 2600"), any one of them is enough. They are
+    // compared by benchmark score, so the installed part does not need to be listed.
+    #[serde(default)]
+    pub cpu: Vec<String>,
+    #[serde(default)]
+    pub gpu: Vec<String>,
+    pub ram_gb: Option<u64>,
+    pub vram_gb: Option<u64>,
+    // Free space on one drive
+    pub storage_gb: Option<u64>,
+    #[serde(default)]
+    pub ssd: bool,
+    pub os: Option<String>,
+    pub directx: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GameRequirements {
+    pub id: String,
+    pub name: String,
+    pub minimum: Requirements,
+    pub recommended: Option<Requirements>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequirementsFile {
+    #[serde(default)]
+    pub games: Vec<GameRequirements>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TierCheck {
+    pub status: CheckStatus,
+    pub required: String,
+    pub reason: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ComponentCheck {
+    pub id: String,
+    pub name: String,
+    pub found: String,
+    // None when the tier does not list this component
+    pub minimum: Option<TierCheck>,
+    pub recommended: Option<TierCheck>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct GameReport {
+    pub id: String,
+    pub name: String,
+    pub minimum: CheckStatus,
+    pub recommended: Option<CheckStatus>,
+    pub components: Vec<ComponentCheck>,
+}
+
+// Everything the checks look at, collected once and shared by every game
+#[derive(Debug, Clone, Default)]
+pub struct RequirementsSnapshot {
+    pub cpu_name: Option<String>,
+    pub gpus: Vec<GpuInfo>,
+    pub ram_total_bytes: Option<u64>,
+    pub largest_free_bytes: Option<u64>,
+    pub has_ssd: Option<bool>,
+    pub os: Option<OsInfo>,
+}
+
+// Bundled definitions merged with the user file; a broken file is reported, not silently ignored
+pub fn load_requirements() -> Result<Vec<GameRequirements>, String> {
+    let bundled: RequirementsFile = serde_json::from_str(BUNDLED_REQUIREMENTS)
+        .map_err(|e| format!("Invalid bundled requirements: {}", e))?;
+    let mut games = bundled.games;
+
+    if let Ok(content) = fs::read_to_string(REQUIREMENTS_FILE) {
+        let file: RequirementsFile = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid {}: {}", REQUIREMENTS_FILE, e))?;
+        for game in file.games {
+            match games.iter_mut().find(|g| g.id == game.id) {
+                Some(existing) => *existing = game,
+                None => games.push(game),
+            }
+        }
+    }
+
+    Ok(games)
+}
+
+fn tier_check(status: CheckStatus, required: String, reason: String) -> TierCheck {
+    TierCheck { status, required, reason }
+}
+
+fn format_gb(bytes: u64) -> String {
+    format!("{:.1} GB", bytes as f64 / GB as f64)
+}
+
+// Whole GB as sold: drivers report 12 GB cards as 12282 MB, visible memory is a bit under installed
+fn rounded_gb(bytes: u64) -> u64 {
+    (bytes as f64 / GB as f64).round() as u64
+}
+
+fn component(id: &str, name: &str, found: String, game: &GameRequirements, check: impl Fn(&Requirements) -> Option<TierCheck>) -> Option<ComponentCheck> {
+    let minimum = check(&game.minimum);
+    let recommended = game.recommended.as_ref().and_then(&check);
+    if minimum.is_none() && recommended.is_none() {
+        return None;
+    }
+    Some(ComponentCheck { id: id.to_string(), name: name.to_string(), found, minimum, recommended })
+}
+
+// The weakest listed alternative sets the bar
+fn check_benchmark(parts: &[String], installed: Option<&BenchmarkMatch>, lookup: impl Fn(&str) -> Option<BenchmarkMatch>) -> Option<TierCheck> {
+    if parts.is_empty() {
+        return None;
+    }
+    let required = parts.join(" / ");
+    let needed = match parts.iter().filter_map(|p| lookup(p)).min_by_key(|m| m.score) {
+        Some(m) => m,
+        None => return Some(tier_check(CheckStatus::Unknown, required, "None of the listed parts are in the benchmark database".to_string())),
+    };
+    Some(match installed {
+        None => tier_check(CheckStatus::Unknown, required, "Installed part is not in the benchmark database, compare manually".to_string()),
+        Some(m) if m.score >= needed.score => tier_check(CheckStatus::Pass, required, format!("{} points, {} needs {}", m.score, needed.name, needed.score)),
+        Some(m) => tier_check(CheckStatus::Fail, required, format!("{} points, {} needs {}", m.score, needed.name, needed.score)),
+    })
+}
+
+fn check_ram(required_gb: Option<u64>, total: Option<u64>) -> Option<TierCheck> {
+    let gb = required_gb?;
+    let required = format!("{} GB", gb);
+    Some(match total {
+        Some(bytes) if rounded_gb(bytes) >= gb => tier_check(CheckStatus::Pass, required, "Enough memory installed".to_string()),
+        Some(bytes) => tier_check(CheckStatus::Fail, required, format!("{} installed, {} GB required", format_gb(bytes), gb)),
+        None => tier_check(CheckStatus::Unknown, required, "Installed memory could not be read".to_string()),
+    })
+}
+
+// WMI clips AdapterRAM at 4 GB, a size in the model name ("GTX 1060 6GB") is better than that
+fn effective_vram(gpu: &GpuInfo, benchmark: Option<&BenchmarkMatch>) -> Option<u64> {
+    let bytes = gpu.adapter_ram?;
+    if gpu.vram_source == VramSource::Wmi && bytes >= WMI_VRAM_CAP {
+        let named = ModelName::parse(&gpu.name).memory_gb
+            .or_else(|| benchmark.and_then(|m| ModelName::parse(&m.name).memory_gb));
+        if let Some(gb) = named {
+            return Some(bytes.max(gb as u64 * GB));
+        }
+    }
+    Some(bytes)
+}
+
+fn check_vram(required_gb: Option<u64>, gpu: Option<&GpuInfo>, vram: Option<u64>) -> Option<TierCheck> {
+    let gb = required_gb?;
+    let required = format!("{} GB", gb);
+    let (gpu, bytes) = match gpu.zip(vram) {
+        Some(found) => found,
+        None => return Some(tier_check(CheckStatus::Unknown, required, "Video memory size could not be read".to_string())),
+    };
+    Some(if rounded_gb(bytes) >= gb {
+        tier_check(CheckStatus::Pass, required, "Enough video memory".to_string())
+    } else if gpu.vram_source == VramSource::Wmi && (WMI_VRAM_CAP..=4 * GB).contains(&bytes) {
+        tier_check(CheckStatus::Unknown, required, "WMI reports at most 4 GB of video memory, the card may have more".to_string())
+    } else {
+        tier_check(CheckStatus::Fail, required, format!("{} of video memory, {} GB required", format_gb(bytes), gb))
+    })
+}
+
+fn check_storage(req: &Requirements, free: Option<u64>, has_ssd: Option<bool>) -> Option<TierCheck> {
+    if req.storage_gb.is_none() && !req.ssd {
+        return None;
+    }
+    let required = match (req.storage_gb, req.ssd) {
+        (Some(gb), true) => format!("{} GB free, SSD", gb),
+        (Some(gb), false) => format!("{} GB free", gb),
+        (None, _) => "SSD".to_string(),
+    };
+    if let Some(gb) = req.storage_gb {
+        match free {
+            Some(bytes) if bytes < gb * GB => return Some(tier_check(CheckStatus::Fail, required, format!("Largest drive has only {} free", format_gb(bytes)))),
+            None => return Some(tier_check(CheckStatus::Unknown, required, "Free space could not be read".to_string())),
+            _ => {}
+        }
+    }
+    Some(match (req.ssd, has_ssd) {
+        (true, Some(false)) => tier_check(CheckStatus::Fail, required, "No SSD installed, loading will be slow or the game may stutter".to_string()),
+        (true, None) => tier_check(CheckStatus::Unknown, required, "Drive types could not be determined".to_string()),
+        _ => tier_check(CheckStatus::Pass, required, "Enough free space".to_string()),
+    })
+}
+
+fn windows_build(text: &str) -> Option<u32> {
+    let upper = text.to_uppercase();
+    WINDOWS_BUILDS.iter().find(|(name, _)| upper.contains(name)).map(|(_, build)| *build)
+}
+
+fn check_os(required: Option<&String>, os: Option<&OsInfo>) -> Option<TierCheck> {
+    let required = required?.clone();
+    let needed = match windows_build(&required) {
+        Some(build) => build,
+        None => return Some(tier_check(CheckStatus::Unknown, required, "Operating system requirement not recognized".to_string())),
+    };
+    let (os, build) = match os.and_then(|o| o.build().map(|b| (o, b))) {
+        Some(found) => found,
+        None => return Some(tier_check(CheckStatus::Unknown, required, "Windows version could not be read".to_string())),
+    };
+    Some(if build < needed {
+        tier_check(CheckStatus::Fail, required.clone(), format!("{} is older than {}", os.caption.trim(), required))
+    } else if required.contains("64") && os.is_64bit() == Some(false) {
+        tier_check(CheckStatus::Fail, required, "A 64-bit Windows is required".to_string())
+    } else {
+        tier_check(CheckStatus::Pass, required, "Windows version is new enough".to_string())
+    })
+}
+
+// The driver model caps the DirectX runtime; the GPU's own feature level can still be lower
+fn directx_level(wddm: (u32, u32)) -> u32 {
+    match wddm {
+        (major, _) if major >= 2 => 12,
+        (1, minor) if minor >= 1 => 11,
+        _ => 10,
+    }
+}
+
+fn check_directx(required: Option<u32>, wddm: Option<(u32, u32)>) -> Option<TierCheck> {
+    let level = required?;
+    let required = format!("DirectX {}", level);
+    Some(match wddm.map(directx_level) {
+        Some(found) if found >= level => tier_check(CheckStatus::Pass, required, format!("Display driver supports DirectX {}", found)),
+        Some(found) => tier_check(CheckStatus::Fail, required, format!("Display driver only supports DirectX {}, update the driver or GPU", found)),
+        None => tier_check(CheckStatus::Unknown, required, "Display driver model could not be determined".to_string()),
+    })
+}
+
+fn overall<'a>(checks: impl Iterator<Item = &'a TierCheck>) -> CheckStatus {
+    let statuses: Vec<CheckStatus> = checks.map(|c| c.status).collect();
+    if statuses.contains(&CheckStatus::Fail) {
+        CheckStatus::Fail
+    } else if statuses.contains(&CheckStatus::Unknown) {
+        CheckStatus::Unknown
+    } else {
+        CheckStatus::Pass
+    }
+}
+
+pub fn evaluate(game: &GameRequirements, snapshot: &RequirementsSnapshot) -> GameReport {
+    let cpu = snapshot.cpu_name.as_deref().and_then(benchmarks::match_cpu);
+    // The fastest adapter runs the game, integrated graphics only matter when it is the only one
+    let gpu = snapshot.gpus.iter()
+        .filter_map(|g| benchmarks::match_gpu(&g.name, g.adapter_ram).map(|m| (g, m)))
+        .max_by_key(|(_, m)| m.score);
+    let vram_gpu = gpu.as_ref().map(|(g, _)| *g)
+        .or_else(|| snapshot.gpus.iter().max_by_key(|g| g.adapter_ram.unwrap_or_default()));
+    let vram = vram_gpu.and_then(|g| effective_vram(g, gpu.as_ref().map(|(_, m)| m)));
+    let wddm = snapshot.gpus.iter().filter_map(|g| win11::wddm_version_from_driver(&g.driver_version)).max();
+
+    let cpu_found = match (&snapshot.cpu_name, &cpu) {
+        (Some(name), Some(m)) => format!("{} ({} points)", name.trim(), m.score),
+        (Some(name), None) => name.trim().to_string(),
+        _ => "Unknown".to_string(),
+    };
+    let gpu_found = match &gpu {
+        Some((g, m)) => format!("{} ({} points)", g.name.trim(), m.score),
+        None => snapshot.gpus.first().map(|g| g.name.trim().to_string()).unwrap_or("Unknown".to_string()),
+    };
+    let os_found = snapshot.os.as_ref()
+        .map(|o| format!("{} (build {})", o.caption.trim(), o.build_number.trim()))
+        .unwrap_or("Unknown".to_string());
+    let storage_found = match (snapshot.largest_free_bytes, snapshot.has_ssd) {
+        (Some(free), Some(true)) => format!("{} free, SSD installed", format_gb(free)),
+        (Some(free), _) => format!("{} free", format_gb(free)),
+        (None, _) => "Unknown".to_string(),
+    };
+
+    let components: Vec<ComponentCheck> = [
+        component("cpu", "Processor", cpu_found, game, |r| check_benchmark(&r.cpu, cpu.as_ref(), benchmarks::match_cpu)),
+        component("gpu", "Graphics", gpu_found, game, |r| check_benchmark(&r.gpu, gpu.as_ref().map(|(_, m)| m), |name| benchmarks::match_gpu(name, None))),
+        component("ram", "Memory", snapshot.ram_total_bytes.map(format_gb).unwrap_or("Unknown".to_string()), game,
+            |r| check_ram(r.ram_gb, snapshot.ram_total_bytes)),
+        component("vram", "Video memory", vram.map(format_gb).unwrap_or("Unknown".to_string()), game,
+            |r| check_vram(r.vram_gb, vram_gpu, vram)),
+        component("storage", "Storage", storage_found, game, |r| check_storage(r, snapshot.largest_free_bytes, snapshot.has_ssd)),
+        component("os", "Operating system", os_found, game, |r| check_os(r.os.as_ref(), snapshot.os.as_ref())),
+        component("directx", "DirectX", wddm.map(|(major, minor)| format!("DirectX {} (WDDM {}.{})", directx_level((major, minor)), major, minor)).unwrap_or("Unknown".to_string()), game,
+            |r| check_directx(r.directx, wddm)),
+    ].into_iter().flatten().collect();
+
+    GameReport {
+        id: game.id.clone(),
+        name: game.name.clone(),
+        minimum: overall(components.iter().filter_map(|c| c.minimum.as_ref())),
+        recommended: game.recommended.as_ref().map(|_| overall(components.iter().filter_map(|c| c.recommended.as_ref()))),
+        components,
+    }
+}
+
+pub fn collect_snapshot() -> anyhow::Result<RequirementsSnapshot> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi()?;
+
+    let cpu_name = hardware::cpu::get_cpu_info(&mut ctx).ok().and_then(|c| c.into_iter().next()).map(|c| c.name);
+    let ram_total_bytes = hardware::memory::get_installed_memory_bytes(&mut ctx);
+    let gpus = hardware::gpu::get_gpu_info(&ctx).unwrap_or_default();
+    let largest_free_bytes = hardware::disk::get_largest_free_space(&ctx).unwrap_or(None);
+    let has_ssd = hardware::disk::get_disk_info(&ctx).ok()
+        .map(|disks| disks.iter().any(|d| d.media_type.is_solid_state() || d.bus_type.to_lowercase().contains("nvme")));
+    let os = hardware::os::get_os_info(&ctx).ok();
+
+    Ok(RequirementsSnapshot {
+        cpu_name,
+        gpus,
+        ram_total_bytes,
+        largest_free_bytes,
+        has_ssd,
+        os,
+    })
+}
+
+#[tauri::command]
+pub fn get_game_requirements() -> Result<Vec<GameRequirements>, String> {
+    load_requirements()
+}
+
+// One hardware scan for all requested games; every game when `game_id` is not given
+#[tauri::command]
+pub fn check_game_requirements(game_id: Option<String>) -> Result<Vec<GameReport>, String> {
+    let games = load_requirements()?;
+    let selected: Vec<&GameRequirements> = match &game_id {
+        Some(id) => vec![games.iter().find(|g| &g.id == id).ok_or_else(|| format!("Unknown game: {}", id))?],
+        None => games.iter().collect(),
+    };
+    let snapshot = collect_snapshot().map_err(|e| e.to_string())?;
+    Ok(selected.into_iter().map(|g| evaluate(g, &snapshot)).collect())
+}
@@ -0,0 +1,265 @@
+use serde::Serialize;
+use crate::model_name::ModelName;
+
+// Multi-thread benchmark scores for desktop parts (same numbers the ranking ladder shows).
+// Hardware names are matched fuzzily, so "Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz" finds "Intel Core i7-10700K".
+
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct BenchmarkEntry {
+    pub name: &'static str,
+    pub score: u32,
+    #[serde(rename = "hasIGPU", skip_serializing_if = "Option::is_none")]
+    pub has_igpu: Option<bool>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct BenchmarkMatch {
+    pub name: String,
+    pub score: u32,
+    // 1.0 is an exact model match, lower values mean the entry is a close relative (F variant, laptop part)
+    pub similarity: f32,
+}
+
+const fn cpu(name: &'static str, score: u32, has_igpu: bool) -> BenchmarkEntry {
+    BenchmarkEntry { name, score, has_igpu: Some(has_igpu) }
+}
+
+const fn gpu(name: &'static str, score: u32) -> BenchmarkEntry {
+    BenchmarkEntry { name, score, has_igpu: None }
+}
+
+const MIN_SIMILARITY: f32 = 0.5;
+
+fn find_best(table: &[BenchmarkEntry], query: &ModelName) -> Option<BenchmarkMatch> {
+    let mut best: Option<(f32, &BenchmarkEntry)> = None;
+    for entry in table {
+        let sim = query.similarity(&ModelName::parse(entry.name));
+        if sim >= MIN_SIMILARITY && best.is_none_or(|(s, _)| sim > s) {
+            best = Some((sim, entry));
+        }
+    }
+    best.map(|(sim, entry)| BenchmarkMatch {
+        name: entry.name.to_string(),
+        score: entry.score,
+        similarity: (sim * 100.0).round() / 100.0,
+    })
+}
+
+pub fn match_cpu(name: &str) -> Option<BenchmarkMatch> {
+    find_best(CPU_BENCHMARKS, &ModelName::parse(name))
+}
+
+pub fn match_gpu(name: &str, vram_bytes: Option<u64>) -> Option<BenchmarkMatch> {
+    let mut query = ModelName::parse(name);
+    // Entries like "RTX 3080 12GB": the card's memory size picks the right one.
+    // Rounded to whole GB, drivers report 12 GB cards as 12282 MB and similar.
+    if query.memory_gb.is_none() {
+        query.memory_gb = vram_bytes.map(|b| (b as f64 / (1024.0 * 1024.0 * 1024.0)).round() as u32).filter(|gb| *gb > 0);
+    }
+    find_best(GPU_BENCHMARKS, &query)
+}
+
+pub static CPU_BENCHMARKS: &[BenchmarkEntry] = &[
+    // AMD Ryzen 9000 Series
+    cpu("AMD Ryzen 9 9950X", 67000, true),
+    cpu("AMD Ryzen 9 9900X", 58000, true),
+    cpu("AMD Ryzen 7 9700X", 48000, true),
+    cpu("AMD Ryzen 5 9600X", 38000, true),
+
+    // Intel 14th Gen
+    cpu("Intel Core i9-14900KS", 65000, true),
+    cpu("Intel Core i9-14900K", 62000, true),
+    cpu("Intel Core i9-14900KF", 62000, false),
+    cpu("Intel Core i9-14900F", 59000, false),
+    cpu("Intel Core i9-14900", 59000, true),
+    cpu("Intel Core i7-14700K", 51000, true),
+    cpu("Intel Core i7-14700KF", 51000, false),
+    cpu("Intel Core i7-14700F", 48000, false),
+    cpu("Intel Core i7-14700", 48000, true),
+    cpu("Intel Core i5-14600K", 39000, true),
+    cpu("Intel Core i5-14600KF", 39000, false),
+    cpu("Intel Core i5-14500", 34000, true),
+    cpu("Intel Core i5-14400F", 28000, false),
+    cpu("Intel Core i5-14400", 28000, true),
+    cpu("Intel Core i3-14100F", 16000, false),
+    cpu("Intel Core i3-14100", 16000, true),
+
+    // AMD Ryzen 7000 Series
+    cpu("AMD Ryzen 9 7950X3D", 63500, true),
+    cpu("AMD Ryzen 9 7950X", 62500, true),
+    cpu("AMD Ryzen 9 7900X3D", 54000, true),
+    cpu("AMD Ryzen 9 7900X", 52000, true),
+    cpu("AMD Ryzen 9 7900", 48000, true),
+    cpu("AMD Ryzen 7 7800X3D", 36000, true),
+    cpu("AMD Ryzen 7 7700X", 36000, true),
+    cpu("AMD Ryzen 7 7700", 34000, true),
+    cpu("AMD Ryzen 5 7600X", 29000, true),
+    cpu("AMD Ryzen 5 7600", 28000, true),
+    cpu("AMD Ryzen 5 7500F", 27000, false),
+
+    // Intel 13th Gen
+    cpu("Intel Core i9-13900KS", 63000, true),
+    cpu("Intel Core i9-13900K", 60000, true),
+    cpu("Intel Core i9-13900KF", 60000, false),
+    cpu("Intel Core i9-13900F", 56000, false),
+    cpu("Intel Core i9-13900", 56000, true),
+    cpu("Intel Core i7-13700K", 48000, true),
+    cpu("Intel Core i7-13700KF", 48000, false),
+    cpu("Intel Core i7-13700F", 45000, false),
+    cpu("Intel Core i7-13700", 45000, true),
+    cpu("Intel Core i5-13600K", 38000, true),
+    cpu("Intel Core i5-13600KF", 38000, false),
+    cpu("Intel Core i5-13500", 33000, true),
+    cpu("Intel Core i5-13490F", 28500, false),
+    cpu("Intel Core i5-13400F", 27000, false),
+    cpu("Intel Core i5-13400", 27000, true),
+    cpu("Intel Core i3-13100F", 15000, false),
+    cpu("Intel Core i3-13100", 15000, true),
+
+    // AMD Ryzen 5000 Series
+    cpu("AMD Ryzen 9 5950X", 46000, false),
+    cpu("AMD Ryzen 9 5900X", 39000, false),
+    cpu("AMD Ryzen 7 5800X3D", 29000, false),
+    cpu("AMD Ryzen 7 5800X", 28000, false),
+    cpu("AMD Ryzen 7 5700X", 27000, false),
+    cpu("AMD Ryzen 7 5700G", 26000, true),
+    cpu("AMD Ryzen 5 5600X", 22000, false),
+    cpu("AMD Ryzen 5 5600", 21500, false),
+    cpu("AMD Ryzen 5 5600G", 20000, true),
+    cpu("AMD Ryzen 5 5500", 19000, false),
+
+    // Intel 12th Gen
+    cpu("Intel Core i9-12900KS", 43000, true),
+    cpu("Intel Core i9-12900K", 41000, true),
+    cpu("Intel Core i9-12900KF", 41000, false),
+    cpu("Intel Core i9-12900F", 39000, false),
+    cpu("Intel Core i9-12900", 39000, true),
+    cpu("Intel Core i7-12700K", 35000, true),
+    cpu("Intel Core i7-12700KF", 35000, false),
+    cpu("Intel Core i7-12700F", 32000, false),
+    cpu("Intel Core i7-12700", 32000, true),
+    cpu("Intel Core i5-12600K", 27000, true),
+    cpu("Intel Core i5-12600KF", 27000, false),
+    cpu("Intel Core i5-12490F", 20500, false),
+    cpu("Intel Core i5-12400F", 19500, false),
+    cpu("Intel Core i5-12400", 19500, true),
+    cpu("Intel Core i3-12100F", 13000, false),
+    cpu("Intel Core i3-12100", 13000, true),
+
+    // Intel 11th Gen
+    cpu("Intel Core i9-11900K", 25000, true),
+    cpu("Intel Core i9-11900KF", 25000, false),
+    cpu("Intel Core i7-11700K", 23000, true),
+    cpu("Intel Core i7-11700KF", 23000, false),
+    cpu("Intel Core i5-11600K", 19000, true),
+    cpu("Intel Core i5-11600KF", 19000, false),
+    cpu("Intel Core i5-11400F", 16000, false),
+    cpu("Intel Core i5-11400", 16000, true),
+
+    // Intel 10th Gen
+    cpu("Intel Core i9-10900K", 24000, true),
+    cpu("Intel Core i9-10900KF", 24000, false),
+    cpu("Intel Core i7-10700K", 19500, true),
+    cpu("Intel Core i7-10700KF", 19500, false),
+    cpu("Intel Core i5-10600K", 14500, true),
+    cpu("Intel Core i5-10600KF", 14500, false),
+    cpu("Intel Core i5-10400F", 12500, false),
+    cpu("Intel Core i5-10400", 12500, true),
+    cpu("Intel Core i3-10105F", 9000, false),
+    cpu("Intel Core i3-10100F", 8800, false),
+
+    // AMD Ryzen 3000 Series
+    cpu("AMD Ryzen 9 3950X", 39000, false),
+    cpu("AMD Ryzen 9 3900X", 32000, false),
+    cpu("AMD Ryzen 7 3800X", 24000, false),
+    cpu("AMD Ryzen 7 3700X", 22000, false),
+    cpu("AMD Ryzen 5 3600X", 18500, false),
+    cpu("AMD Ryzen 5 3600", 17800, false),
+    cpu("AMD Ryzen 5 3500X", 15000, false),
+    cpu("AMD Ryzen 3 3300X", 13000, false),
+    cpu("AMD Ryzen 3 3100", 11000, false),
+    cpu("AMD Ryzen 5 3400G", 9500, true),
+    cpu("AMD Ryzen 3 3200G", 7500, true),
+
+    // Older Intel
+    cpu("Intel Core i9-9900K", 18500, true),
+    cpu("Intel Core i9-9900KF", 18500, false),
+    cpu("Intel Core i7-9700K", 14500, true),
+    cpu("Intel Core i7-9700KF", 14500, false),
+    cpu("Intel Core i5-9600KF", 11000, false),
+    cpu("Intel Core i5-9400F", 9500, false),
+    cpu("Intel Core i3-9100F", 6500, false),
+    cpu("Intel Core i7-8700K", 13800, true),
+    cpu("Intel Core i5-8400", 9000, true),
+    cpu("Intel Core i7-7700K", 9800, true),
+    cpu("Intel Core i7-6700K", 8900, true),
+    cpu("Intel Core i7-4790K", 8000, true),
+    cpu("Intel Core i7-3770K", 6500, true),
+    cpu("Intel Core i7-2600K", 5500, true),
+    cpu("Intel Core i5-2500K", 4000, true),
+    cpu("Intel Core 2 Quad Q6600", 1800, false),
+
+    // Older AMD
+    cpu("AMD Ryzen 7 2700X", 17500, false),
+    cpu("AMD Ryzen 5 2600", 13000, false),
+    cpu("AMD Ryzen 7 1800X", 16000, false),
+    cpu("AMD FX-8350", 5800, false),
+];
+
+pub static GPU_BENCHMARKS: &[BenchmarkEntry] = &[
+    gpu("NVIDIA GeForce RTX 4090", 39000),
+    gpu("NVIDIA GeForce RTX 4080 Super", 31000),
+    gpu("NVIDIA GeForce RTX 4080", 28000),
+    gpu("AMD Radeon RX 7900 XTX", 29000),
+    gpu("NVIDIA GeForce RTX 4070 Ti Super", 25000),
+    gpu("NVIDIA GeForce RTX 3090 Ti", 26000),
+    gpu("AMD Radeon RX 7900 XT", 26000),
+    gpu("NVIDIA GeForce RTX 3090", 24000),
+    gpu("NVIDIA GeForce RTX 4070 Ti", 23000),
+    gpu("NVIDIA GeForce RTX 3080 Ti", 23500),
+    gpu("AMD Radeon RX 6950 XT", 24000),
+    gpu("NVIDIA GeForce RTX 3080 12GB", 22000),
+    gpu("NVIDIA GeForce RTX 4070 Super", 21000),
+    gpu("AMD Radeon RX 6900 XT", 21000),
+    gpu("NVIDIA GeForce RTX 3080 10GB", 20500),
+    gpu("NVIDIA GeForce RTX 4070", 18000),
+    gpu("AMD Radeon RX 7800 XT", 19000),
+    gpu("NVIDIA GeForce RTX 3070 Ti", 17000),
+    gpu("AMD Radeon RX 6800 XT", 19000),
+    gpu("NVIDIA GeForce RTX 3070", 16000),
+    gpu("NVIDIA GeForce RTX 2080 Ti", 16500),
+    gpu("AMD Radeon RX 6800", 16000),
+    gpu("NVIDIA GeForce RTX 4060 Ti", 13500),
+    gpu("NVIDIA GeForce RTX 3060 Ti", 14000),
+    gpu("AMD Radeon RX 6700 XT", 13000),
+    gpu("NVIDIA GeForce RTX 2080 Super", 13500),
+    gpu("NVIDIA GeForce RTX 3060 12GB", 11000),
+    gpu("NVIDIA GeForce RTX 2070 Super", 11500),
+    gpu("AMD Radeon RX 5700 XT", 11000),
+    gpu("NVIDIA GeForce RTX 2060 Super", 10000),
+    gpu("NVIDIA GeForce GTX 1080 Ti", 11500),
+    gpu("NVIDIA GeForce RTX 4060", 10500),
+    gpu("AMD Radeon RX 7600", 10000),
+    gpu("AMD Radeon RX 6600 XT", 10000),
+    gpu("NVIDIA GeForce RTX 3050", 7000),
+    gpu("NVIDIA GeForce GTX 1080", 9000),
+    gpu("NVIDIA GeForce RTX 2060", 8500),
+    gpu("AMD Radeon RX 5600 XT", 8000),
+    gpu("NVIDIA GeForce GTX 1070 Ti", 8200),
+    gpu("NVIDIA GeForce GTX 1660 Super", 6800),
+    gpu("NVIDIA GeForce GTX 1070", 7500),
+    gpu("NVIDIA GeForce GTX 980 Ti", 7000),
+    gpu("NVIDIA GeForce GTX 1660 Ti", 7000),
+    gpu("AMD Radeon RX 580", 4500),
+    gpu("NVIDIA GeForce GTX 1060 6GB", 4800),
+    gpu("AMD Radeon RX 6500 XT", 4200),
+    gpu("NVIDIA GeForce GTX 980", 5500),
+    gpu("NVIDIA GeForce GTX 970", 4500),
+    gpu("NVIDIA GeForce GTX 1650", 3500),
+    gpu("AMD Radeon RX 6400", 3300),
+    gpu("NVIDIA GeForce GTX 1050 Ti", 2800),
+    gpu("NVIDIA GeForce GTX 960", 2500),
+    gpu("NVIDIA GeForce GTX 750 Ti", 1500),
+    gpu("NVIDIA GeForce GTX 660", 1800),
+    gpu("NVIDIA GeForce GT 1030", 1200),
+];
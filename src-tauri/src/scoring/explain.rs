@@ -0,0 +1,85 @@
+use serde::Serialize;
+use std::fmt;
+use super::Score;
+use super::profiles::Tiers;
+
+// Why a component got its score: the inputs that went in, the tier rule that matched,
+// what the next tier needs, and how much the input data can be trusted.
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ScoreInput {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Explanation {
+    pub inputs: Vec<ScoreInput>,
+    pub rule: String,
+    pub next_tier: Option<String>,
+    pub confidence: Confidence,
+    pub caveats: Vec<String>,
+}
+
+impl Explanation {
+    pub fn new(confidence: Confidence) -> Self {
+        Explanation {
+            inputs: Vec::new(),
+            rule: String::new(),
+            next_tier: None,
+            confidence,
+            caveats: Vec::new(),
+        }
+    }
+
+    pub fn input(mut self, name: &str, value: impl ToString) -> Self {
+        self.inputs.push(ScoreInput { name: name.to_string(), value: value.to_string() });
+        self
+    }
+
+    // Lowers the confidence (never raises it) and records the reason
+    pub fn caveat(mut self, confidence: Confidence, reason: impl Into<String>) -> Self {
+        if confidence < self.confidence {
+            self.confidence = confidence;
+        }
+        self.caveats.push(reason.into());
+        self
+    }
+
+    pub fn tiers<T>(mut self, tiers: &Tiers<T>, score: &Score, describe: impl Fn(&T) -> String) -> Self {
+        let (rule, next_tier) = match score {
+            Score::Excellent => (format!("Excellent: {}", describe(&tiers.excellent)), None),
+            Score::Good => (format!("Good: {}", describe(&tiers.good)), Some(format!("Excellent needs {}", describe(&tiers.excellent)))),
+            Score::Average => (format!("Average: {}", describe(&tiers.average)), Some(format!("Good needs {}", describe(&tiers.good)))),
+            Score::Poor => (format!("Poor: below {}", describe(&tiers.average)), Some(format!("Average needs {}", describe(&tiers.average)))),
+            Score::Unknown => ("Not enough data to score".to_string(), None),
+        };
+        self.rule = rule;
+        self.next_tier = next_tier;
+        self
+    }
+}
+
+// Plain text form for reports and logs
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let inputs: Vec<String> = self.inputs.iter().map(|i| format!("{} = {}", i.name, i.value)).collect();
+        writeln!(f, "Inputs: {}", inputs.join(", "))?;
+        writeln!(f, "Rule: {}", self.rule)?;
+        if let Some(next) = &self.next_tier {
+            writeln!(f, "Next tier: {}", next)?;
+        }
+        write!(f, "Confidence: {:?}", self.confidence)?;
+        for caveat in &self.caveats {
+            write!(f, "\n  - {}", caveat)?;
+        }
+        Ok(())
+    }
+}
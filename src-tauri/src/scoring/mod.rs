@@ -0,0 +1,321 @@
+use colored::*;
+use serde::Serialize;
+
+pub mod benchmarks;
+pub mod system;
+pub mod profiles;
+pub mod explain;
+pub mod upgrade;
+
+use benchmarks::BenchmarkMatch;
+use profiles::{ScoringProfile, Tiers};
+use explain::{Confidence, Explanation};
+use crate::hardware::codes::MediaType;
+use crate::hardware::gpu::{VramSource, WMI_VRAM_CAP};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Score {
+    Excellent,
+    Good,
+    Average,
+    Poor,
+    Unknown,
+}
+
+impl Score {
+    pub fn to_colored_string(&self) -> ColoredString {
+        match self {
+            Score::Excellent => "Excellent".green().bold(),
+            Score::Good => "Good".green(),
+            Score::Average => "Average".yellow(),
+            Score::Poor => "Poor".red(),
+            Score::Unknown => "Unknown".white(),
+        }
+    }
+
+    // Inverse of the `{:?}` label stored in the Scored* results
+    pub fn from_label(label: &str) -> Score {
+        match label {
+            "Excellent" => Score::Excellent,
+            "Good" => Score::Good,
+            "Average" => Score::Average,
+            "Poor" => Score::Poor,
+            _ => Score::Unknown,
+        }
+    }
+}
+
+fn tier<T>(tiers: &Tiers<T>, meets: impl Fn(&T) -> bool) -> Score {
+    if meets(&tiers.excellent) {
+        Score::Excellent
+    } else if meets(&tiers.good) {
+        Score::Good
+    } else if meets(&tiers.average) {
+        Score::Average
+    } else {
+        Score::Poor
+    }
+}
+
+pub fn score_cpu(profile: &ScoringProfile, cores: u32, clock_mhz: u32) -> Score {
+    tier(&profile.cpu, |rule| cores >= rule.cores && clock_mhz >= rule.clock_mhz)
+}
+
+// Tiers on the benchmark scale (see benchmarks::CPU_BENCHMARKS)
+pub fn score_cpu_benchmark(profile: &ScoringProfile, score: u32) -> Score {
+    tier(&profile.cpu_benchmark, |min| score >= *min)
+}
+
+pub fn score_ram(profile: &ScoringProfile, total_capacity_gb: u64, avg_speed_mhz: u32) -> Score {
+    tier(&profile.ram, |rule| total_capacity_gb >= rule.gb && avg_speed_mhz >= rule.speed_mhz)
+}
+
+pub fn score_gpu(profile: &ScoringProfile, vram_bytes: u64) -> Score {
+    let vram_gb = vram_bytes / 1024 / 1024 / 1024;
+    tier(&profile.gpu_vram_gb, |min| vram_gb >= *min)
+}
+
+pub fn score_gpu_benchmark(profile: &ScoringProfile, score: u32) -> Score {
+    tier(&profile.gpu_benchmark, |min| score >= *min)
+}
+
+pub fn score_disk(profile: &ScoringProfile, is_ssd: bool, is_nvme: bool, size_bytes: u64) -> Score {
+    let size_gb = size_bytes / 1024 / 1024 / 1024;
+    tier(&profile.disk, |rule| (is_ssd || !rule.ssd) && (is_nvme || !rule.nvme) && size_gb >= rule.size_gb)
+}
+
+// Weighted 0-100 system index
+pub fn score_system(profile: &ScoringProfile, index: u32) -> Score {
+    tier(&profile.system, |min| index >= *min)
+}
+
+pub fn calculate_cpu_score_num(cores: u32, clock_mhz: u32) -> u32 {
+    // On the benchmark database scale, so unknown models sit next to matched ones
+    // Formula: Cores * Clock (MHz)
+    // Example: 6 cores, 2500MHz (i5-12400F base clock) -> 15000, database 19500
+    // Example: 8 cores, 3800MHz (Ryzen 7 5800X) -> 30400, database 28000
+    let score = cores as f32 * clock_mhz as f32;
+    score as u32
+}
+
+pub fn calculate_ram_score_num(total_gb: u64, avg_speed_mhz: u32) -> u32 {
+    // Formula: (GB * 1) + (Speed / 40)
+    // Example: 16GB, 3200MHz -> 16 + 80 = 96
+    // Example: 8GB, 2133MHz -> 8 + 53 = 61
+    let score = (total_gb as f32 * 1.0) + (avg_speed_mhz as f32 / 40.0);
+    score as u32
+}
+
+pub fn calculate_gpu_score_num(vram_bytes: u64) -> u32 {
+    let vram_gb = vram_bytes / 1024 / 1024 / 1024;
+    // On the benchmark database scale, so unknown models sit next to matched ones
+    // Formula: VRAM * 1300
+    // Example: 8GB -> 10400 (RTX 4060: 10500)
+    // Example: 12GB -> 15600 (RTX 3060 12GB: 11000, RX 6700 XT: 13000)
+    let score = vram_gb as f32 * 1300.0;
+    score as u32
+}
+
+pub fn calculate_disk_score_num(is_ssd: bool, is_nvme: bool, size_bytes: u64) -> u32 {
+    let size_gb = size_bytes / 1024 / 1024 / 1024;
+    // Base score from size
+    let mut score = size_gb as f32 / 10.0;
+    
+    // Bonus for SSD and NVMe
+    if is_ssd {
+        score += 20.0;
+    }
+    if is_nvme {
+        score += 30.0;
+    }
+    
+    score as u32
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum ScoreSource {
+    Benchmark,
+    Heuristic,
+    // Built-in benchmark run on this machine
+    Measured,
+}
+
+pub struct Rating {
+    pub score: Score,
+    pub score_num: u32,
+    pub source: ScoreSource,
+    pub benchmark: Option<BenchmarkMatch>,
+    pub explanation: Explanation,
+}
+
+fn points(min: &u32) -> String {
+    format!("{} benchmark points", min)
+}
+
+// Benchmark matches below 1.0 are a relative of the installed part (F variant, laptop chip)
+fn benchmark_explanation(m: &BenchmarkMatch) -> Explanation {
+    let explanation = Explanation::new(Confidence::High)
+        .input("Benchmark entry", &m.name)
+        .input("Benchmark score", m.score);
+    if m.similarity < 1.0 {
+        explanation.caveat(Confidence::Medium, format!("Closest database entry is {} (similarity {:.2}), not an exact model match", m.name, m.similarity))
+    } else {
+        explanation
+    }
+}
+
+// Benchmark database first, the core/clock formula only for models we do not know
+pub fn rate_cpu(profile: &ScoringProfile, name: &str, cores: u32, clock_mhz: u32) -> Rating {
+    match benchmarks::match_cpu(name) {
+        Some(m) => {
+            let score = score_cpu_benchmark(profile, m.score);
+            let explanation = benchmark_explanation(&m).tiers(&profile.cpu_benchmark, &score, points);
+            Rating { score, score_num: m.score, source: ScoreSource::Benchmark, benchmark: Some(m), explanation }
+        }
+        None => {
+            let score = score_cpu(profile, cores, clock_mhz);
+            let mut explanation = Explanation::new(Confidence::Medium)
+                .input("Cores", cores)
+                .input("Max clock", format!("{} MHz", clock_mhz))
+                .caveat(Confidence::Medium, "Model not in the benchmark database, estimated from core count and clock")
+                .tiers(&profile.cpu, &score, |rule| format!("{}+ cores at {}+ MHz", rule.cores, rule.clock_mhz));
+            if cores == 0 || clock_mhz == 0 {
+                explanation = explanation.caveat(Confidence::Low, "Core count or clock speed was not reported");
+            }
+            Rating {
+                score,
+                score_num: calculate_cpu_score_num(cores, clock_mhz),
+                source: ScoreSource::Heuristic,
+                benchmark: None,
+                explanation,
+            }
+        }
+    }
+}
+
+// Built-in benchmark result (benchmark::cpu), already on the database scale. Beats any spec-based
+// guess; a large gap to the database entry usually means thermal or power limits.
+pub fn rate_cpu_measured(profile: &ScoringProfile, database_points: u32, database: Option<&BenchmarkMatch>) -> Rating {
+    let score = score_cpu_benchmark(profile, database_points);
+    let mut explanation = Explanation::new(Confidence::High)
+        .input("Measured score", database_points);
+    if let Some(m) = database {
+        let ratio = database_points as f64 / m.score.max(1) as f64;
+        explanation = explanation.input("Database score", format!("{} ({})", m.score, m.name));
+        if ratio < 0.8 {
+            explanation = explanation.caveat(Confidence::Medium, format!("Measured {:.0}% of the database score, check cooling, power limits and background load", ratio * 100.0));
+        }
+    }
+    let explanation = explanation.tiers(&profile.cpu_benchmark, &score, points);
+    Rating { score, score_num: database_points, source: ScoreSource::Measured, benchmark: database.cloned(), explanation }
+}
+
+pub fn rate_gpu(profile: &ScoringProfile, name: &str, vram_bytes: Option<u64>, vram_source: VramSource) -> Rating {
+    if let Some(m) = benchmarks::match_gpu(name, vram_bytes) {
+        let score = score_gpu_benchmark(profile, m.score);
+        let explanation = benchmark_explanation(&m).tiers(&profile.gpu_benchmark, &score, points);
+        return Rating { score, score_num: m.score, source: ScoreSource::Benchmark, benchmark: Some(m), explanation };
+    }
+    let vram_text = vram_bytes.map(|b| format!("{:.1} GB", b as f64 / 1024.0 / 1024.0 / 1024.0)).unwrap_or("Unknown".to_string());
+    let mut explanation = Explanation::new(Confidence::Medium)
+        .input("VRAM", vram_text)
+        .input("VRAM source", format!("{:?}", vram_source))
+        .caveat(Confidence::Medium, "Model not in the benchmark database, estimated from video memory size");
+    explanation = match vram_source {
+        VramSource::GuessedFromName => explanation.caveat(Confidence::Low, "VRAM was not reported by the driver and was guessed from the model name"),
+        VramSource::Wmi if vram_bytes.is_some_and(|b| b >= WMI_VRAM_CAP) => explanation.caveat(Confidence::Low, "WMI caps video memory at 4 GB, the card may have more"),
+        _ => explanation,
+    };
+    match vram_bytes {
+        Some(vram) => {
+            let score = score_gpu(profile, vram);
+            Rating {
+                explanation: explanation.tiers(&profile.gpu_vram_gb, &score, |min| format!("{}+ GB VRAM", min)),
+                score,
+                score_num: calculate_gpu_score_num(vram),
+                source: ScoreSource::Heuristic,
+                benchmark: None,
+            }
+        }
+        None => Rating {
+            score: Score::Unknown,
+            score_num: 0,
+            source: ScoreSource::Heuristic,
+            benchmark: None,
+            explanation: explanation
+                .caveat(Confidence::Low, "Video memory size unknown")
+                .tiers(&profile.gpu_vram_gb, &Score::Unknown, |min| format!("{}+ GB VRAM", min)),
+        },
+    }
+}
+
+pub fn rate_ram(profile: &ScoringProfile, total_gb: u64, avg_speed_mhz: u32, modules: usize) -> Rating {
+    let score = score_ram(profile, total_gb, avg_speed_mhz);
+    let mut explanation = Explanation::new(Confidence::High)
+        .input("Capacity", format!("{} GB", total_gb))
+        .input("Average speed", format!("{} MHz", avg_speed_mhz))
+        .input("Modules", modules);
+    if avg_speed_mhz == 0 {
+        explanation = explanation.caveat(Confidence::Low, "Memory speed was not reported, only capacity could be rated");
+    }
+    if modules == 0 {
+        explanation = explanation.caveat(Confidence::Low, "No memory modules were reported by WMI");
+    }
+    Rating {
+        explanation: explanation.tiers(&profile.ram, &score, |rule| format!("{}+ GB at {}+ MHz", rule.gb, rule.speed_mhz)),
+        score,
+        score_num: calculate_ram_score_num(total_gb, avg_speed_mhz),
+        source: ScoreSource::Heuristic,
+        benchmark: None,
+    }
+}
+
+pub fn rate_disk(profile: &ScoringProfile, media_type: MediaType, is_nvme: bool, size_bytes: u64) -> Rating {
+    let is_ssd = media_type.is_solid_state();
+    let score = score_disk(profile, is_ssd, is_nvme, size_bytes);
+    let mut explanation = Explanation::new(Confidence::High)
+        .input("Media type", media_type.label())
+        .input("NVMe", is_nvme)
+        .input("Size", format!("{} GB", size_bytes / 1024 / 1024 / 1024));
+    if media_type == MediaType::Unknown && !is_nvme {
+        explanation = explanation.caveat(Confidence::Low, "Drive type could not be determined and was rated as a hard disk");
+    }
+    Rating {
+        explanation: explanation.tiers(&profile.disk, &score, |rule| {
+            let kind = if rule.nvme { "NVMe SSD" } else if rule.ssd { "SSD" } else { "any drive" };
+            format!("{} of {}+ GB", kind, rule.size_gb)
+        }),
+        score,
+        score_num: calculate_disk_score_num(is_ssd, is_nvme, size_bytes),
+        source: ScoreSource::Heuristic,
+        benchmark: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gaming() -> ScoringProfile {
+        profiles::builtin_profiles().remove(0)
+    }
+
+    // Spec estimates have to be comparable to database points, they share the "Score" line
+    #[test]
+    fn estimates_are_on_the_benchmark_scale() {
+        let profile = gaming();
+        let matched = rate_cpu(&profile, "Intel Core i5-12400F", 6, 2500);
+        let estimated = rate_cpu(&profile, "Example CPU 9000", 6, 2500);
+        assert_eq!(matched.source, ScoreSource::Benchmark);
+        assert_eq!(estimated.source, ScoreSource::Heuristic);
+        let ratio = estimated.score_num as f32 / matched.score_num as f32;
+        assert!((0.5..2.0).contains(&ratio), "{} vs {}", estimated.score_num, matched.score_num);
+
+        let eight_gb = 8 * 1024 * 1024 * 1024;
+        let matched = rate_gpu(&profile, "NVIDIA GeForce RTX 4060", Some(eight_gb), VramSource::Wmi);
+        let estimated = rate_gpu(&profile, "Example GPU 9000", Some(eight_gb), VramSource::Wmi);
+        assert_eq!(estimated.source, ScoreSource::Heuristic);
+        let ratio = estimated.score_num as f32 / matched.score_num as f32;
+        assert!((0.5..2.0).contains(&ratio), "{} vs {}", estimated.score_num, matched.score_num);
+    }
+}
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use super::system::Workload;
+
+// Scoring thresholds and weights per use case. The built-in profiles can be overridden
+// (same id) or extended with new ones in neko233-hardware-viewer.scoring.json:
+//   { "default_profile": "office", "profiles": [ { "id": "office", ... } ] }
+
+pub const PROFILES_FILE: &str = "neko233-hardware-viewer.scoring.json";
+
+// Minimum value needed for each tier, anything below `average` is Poor
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Tiers<T> {
+    pub excellent: T,
+    pub good: T,
+    pub average: T,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CpuRule {
+    pub cores: u32,
+    pub clock_mhz: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RamRule {
+    pub gb: u64,
+    pub speed_mhz: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiskRule {
+    pub ssd: bool,
+    pub nvme: bool,
+    pub size_gb: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Weights {
+    pub cpu: f32,
+    pub gpu: f32,
+    pub ram: f32,
+    pub disk: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScoringProfile {
+    pub id: String,
+    pub name: String,
+    pub workload: Workload,
+    pub weights: Weights,
+    // Heuristic rules, used when the model is not in the benchmark database
+    pub cpu: Tiers<CpuRule>,
+    pub ram: Tiers<RamRule>,
+    pub gpu_vram_gb: Tiers<u64>,
+    pub disk: Tiers<DiskRule>,
+    // Benchmark database scores
+    pub cpu_benchmark: Tiers<u32>,
+    pub gpu_benchmark: Tiers<u32>,
+    // Weighted 0-100 system index
+    pub system: Tiers<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProfilesFile {
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: Vec<ScoringProfile>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ProfileSet {
+    pub default_profile: String,
+    pub profiles: Vec<ScoringProfile>,
+}
+
+fn tiers<T>(excellent: T, good: T, average: T) -> Tiers<T> {
+    Tiers { excellent, good, average }
+}
+
+fn cpu_rule(cores: u32, clock_mhz: u32) -> CpuRule {
+    CpuRule { cores, clock_mhz }
+}
+
+fn ram_rule(gb: u64, speed_mhz: u32) -> RamRule {
+    RamRule { gb, speed_mhz }
+}
+
+fn disk_rule(ssd: bool, nvme: bool, size_gb: u64) -> DiskRule {
+    DiskRule { ssd, nvme, size_gb }
+}
+
+pub fn builtin_profiles() -> Vec<ScoringProfile> {
+    vec![
+        ScoringProfile {
+            id: "gaming".to_string(),
+            name: "Gaming".to_string(),
+            workload: Workload::Gaming,
+            weights: Weights { cpu: 0.30, gpu: 0.45, ram: 0.15, disk: 0.10 },
+            cpu: tiers(cpu_rule(8, 3500), cpu_rule(6, 3000), cpu_rule(4, 0)),
+            ram: tiers(ram_rule(32, 3200), ram_rule(16, 2666), ram_rule(8, 0)),
+            gpu_vram_gb: tiers(8, 4, 2),
+            disk: tiers(disk_rule(true, true, 1000), disk_rule(true, false, 500), disk_rule(true, false, 250)),
+            cpu_benchmark: tiers(40000, 20000, 10000),
+            gpu_benchmark: tiers(18000, 10000, 5000),
+            system: tiers(80, 60, 40),
+        },
+        // Mail, documents, browser and video calls: the GPU barely matters, any SSD is fine
+        ScoringProfile {
+            id: "office".to_string(),
+            name: "Office".to_string(),
+            workload: Workload::Office,
+            weights: Weights { cpu: 0.40, gpu: 0.05, ram: 0.30, disk: 0.25 },
+            cpu: tiers(cpu_rule(6, 3000), cpu_rule(4, 2500), cpu_rule(2, 0)),
+            ram: tiers(ram_rule(16, 2666), ram_rule(8, 0), ram_rule(4, 0)),
+            gpu_vram_gb: tiers(2, 1, 0),
+            disk: tiers(disk_rule(true, false, 500), disk_rule(true, false, 240), disk_rule(false, false, 120)),
+            cpu_benchmark: tiers(15000, 8000, 4000),
+            gpu_benchmark: tiers(3000, 1000, 0),
+            system: tiers(65, 45, 25),
+        },
+        // CAD, compiling, rendering: cores and memory first
+        ScoringProfile {
+            id: "workstation".to_string(),
+            name: "Workstation".to_string(),
+            workload: Workload::Workstation,
+            weights: Weights { cpu: 0.45, gpu: 0.20, ram: 0.25, disk: 0.10 },
+            cpu: tiers(cpu_rule(16, 3500), cpu_rule(8, 3000), cpu_rule(6, 0)),
+            ram: tiers(ram_rule(64, 3200), ram_rule(32, 2666), ram_rule(16, 0)),
+            gpu_vram_gb: tiers(12, 8, 4),
+            disk: tiers(disk_rule(true, true, 2000), disk_rule(true, true, 1000), disk_rule(true, false, 500)),
+            cpu_benchmark: tiers(55000, 35000, 20000),
+            gpu_benchmark: tiers(20000, 12000, 6000),
+            system: tiers(85, 65, 45),
+        },
+    ]
+}
+
+// Built-in profiles merged with the user file; a broken file is reported, not silently ignored
+pub fn load_profiles() -> Result<ProfileSet, String> {
+    let mut profiles = builtin_profiles();
+    let mut default_profile = "gaming".to_string();
+
+    if let Ok(content) = fs::read_to_string(PROFILES_FILE) {
+        let file: ProfilesFile = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid {}: {}", PROFILES_FILE, e))?;
+        for profile in file.profiles {
+            match profiles.iter_mut().find(|p| p.id == profile.id) {
+                Some(existing) => *existing = profile,
+                None => profiles.push(profile),
+            }
+        }
+        if let Some(id) = file.default_profile {
+            if !profiles.iter().any(|p| p.id == id) {
+                return Err(format!("Default scoring profile '{}' is not defined", id));
+            }
+            default_profile = id;
+        }
+    }
+
+    Ok(ProfileSet { default_profile, profiles })
+}
+
+impl ProfileSet {
+    pub fn get(&self, id: Option<&str>) -> Result<ScoringProfile, String> {
+        let id = id.unwrap_or(&self.default_profile);
+        self.profiles.iter()
+            .find(|p| p.id == id)
+            .cloned()
+            .ok_or_else(|| format!("Unknown scoring profile: {}", id))
+    }
+}
+
+// The profile for one scan: the requested one, or the configured default
+pub fn resolve(id: Option<&str>) -> Result<ScoringProfile, String> {
+    load_profiles()?.get(id)
+}
+
+#[tauri::command]
+pub fn get_scoring_profiles() -> Result<ProfileSet, String> {
+    load_profiles()
+}
@@ -0,0 +1,333 @@
+use serde::{Deserialize, Serialize};
+use super::Score;
+use super::profiles::{ScoringProfile, Weights};
+
+// Whole-system verdict. Each component is mapped to a 0-100 index, the indexes are
+// combined with per-workload weights, and the gaps between them become findings.
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Workload {
+    Gaming,
+    Office,
+    Workstation,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum Component {
+    Cpu,
+    Gpu,
+    Ram,
+    Disk,
+    // The machine as a whole rather than one part of it
+    System,
+}
+
+impl Component {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Component::Cpu => "CPU",
+            Component::Gpu => "GPU",
+            Component::Ram => "RAM",
+            Component::Disk => "Storage",
+            Component::System => "System",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Finding {
+    pub severity: Severity,
+    pub component: Component,
+    pub message: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WorkloadScore {
+    pub profile: String,
+    pub name: String,
+    pub workload: Workload,
+    pub score: String,
+    pub score_num: u32,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ComponentIndexes {
+    pub cpu: Option<u32>,
+    pub gpu: Option<u32>,
+    pub ram: Option<u32>,
+    pub disk: Option<u32>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SystemScore {
+    // Verdict for the active profile, every other profile is in `workloads`
+    pub profile: String,
+    pub workload: Workload,
+    pub score: String,
+    pub score_num: u32,
+    pub components: ComponentIndexes,
+    pub workloads: Vec<WorkloadScore>,
+    pub bottleneck: Option<Component>,
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Clone)]
+pub struct ComponentInput {
+    pub tier: Score,
+    pub benchmark: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct CpuInput {
+    pub rating: ComponentInput,
+    pub cores: u32,
+}
+
+#[derive(Clone)]
+pub struct DiskInput {
+    pub is_ssd: bool,
+    pub is_nvme: bool,
+}
+
+#[derive(Clone, Default)]
+pub struct SystemInputs {
+    pub cpu: Option<CpuInput>,
+    // Best GPU in the machine, integrated graphics only count when nothing else is there
+    pub gpu: Option<ComponentInput>,
+    pub ram_gb: u64,
+    pub ram_speed_mhz: u32,
+    pub ram_modules: usize,
+    pub system_disk: Option<DiskInput>,
+    pub virtual_machine: bool,
+}
+
+// Benchmark scores that count as 100 (roughly a Core i7-14700K and an RTX 4080)
+pub const CPU_REFERENCE: u32 = 50000;
+pub const GPU_REFERENCE: u32 = 30000;
+const RAM_REFERENCE_GB: u64 = 32;
+const RAM_REFERENCE_MHZ: u32 = 6000;
+
+// A component this far below the average of the others holds the system back
+const BOTTLENECK_GAP: u32 = 25;
+
+fn tier_index(tier: &Score) -> Option<u32> {
+    match tier {
+        Score::Excellent => Some(90),
+        Score::Good => Some(70),
+        Score::Average => Some(45),
+        Score::Poor => Some(20),
+        Score::Unknown => None,
+    }
+}
+
+fn component_index(input: &ComponentInput, reference: u32) -> Option<u32> {
+    match input.benchmark {
+        Some(score) => Some((score as u64 * 100 / reference as u64).min(100) as u32),
+        None => tier_index(&input.tier),
+    }
+}
+
+fn ram_index(gb: u64, speed_mhz: u32) -> Option<u32> {
+    if gb == 0 {
+        return None;
+    }
+    let capacity = (gb * 100 / RAM_REFERENCE_GB).min(100) as f32;
+    let speed = (speed_mhz * 100 / RAM_REFERENCE_MHZ).min(100) as f32;
+    Some((capacity * 0.8 + speed * 0.2).round() as u32)
+}
+
+fn disk_index(disk: &DiskInput) -> u32 {
+    if disk.is_nvme {
+        100
+    } else if disk.is_ssd {
+        75
+    } else {
+        25
+    }
+}
+
+fn indexes_of(components: &ComponentIndexes) -> [Option<u32>; 4] {
+    [components.cpu, components.gpu, components.ram, components.disk]
+}
+
+const COMPONENTS: [Component; 4] = [Component::Cpu, Component::Gpu, Component::Ram, Component::Disk];
+
+fn weights_of(weights: &Weights) -> [f32; 4] {
+    [weights.cpu, weights.gpu, weights.ram, weights.disk]
+}
+
+// Weighted average over the components we could measure, weights of missing ones are redistributed
+fn weighted_score(components: &ComponentIndexes, weights: &[f32; 4]) -> Option<u32> {
+    let mut total = 0.0;
+    let mut weight_sum = 0.0;
+    for (index, weight) in indexes_of(components).iter().zip(weights) {
+        if let Some(index) = index {
+            total += *index as f32 * weight;
+            weight_sum += weight;
+        }
+    }
+    if weight_sum == 0.0 {
+        None
+    } else {
+        Some((total / weight_sum).round() as u32)
+    }
+}
+
+fn find_bottleneck(components: &ComponentIndexes, weights: &[f32; 4]) -> Option<Component> {
+    let measured: Vec<(Component, u32)> = COMPONENTS.iter()
+        .zip(indexes_of(components))
+        .zip(weights)
+        // Components the workload barely uses cannot bottleneck it
+        .filter(|((_, index), weight)| index.is_some() && **weight >= 0.10)
+        .map(|((component, index), _)| (*component, index.unwrap_or_default()))
+        .collect();
+    if measured.len() < 2 {
+        return None;
+    }
+    let (component, lowest) = *measured.iter().min_by_key(|(_, index)| *index)?;
+    let others: Vec<u32> = measured.iter().filter(|(c, _)| *c != component).map(|(_, i)| *i).collect();
+    let others_avg = others.iter().sum::<u32>() / others.len() as u32;
+    if others_avg >= lowest + BOTTLENECK_GAP {
+        Some(component)
+    } else {
+        None
+    }
+}
+
+fn finding(findings: &mut Vec<Finding>, severity: Severity, component: Component, message: String) {
+    findings.push(Finding { severity, component, message });
+}
+
+fn collect_findings(inputs: &SystemInputs, components: &ComponentIndexes, workload: Workload) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // CPU/GPU balance only matters when the GPU does real work
+    let balance = if workload == Workload::Office { None } else { inputs.cpu.as_ref().zip(components.cpu).zip(components.gpu) };
+    if let Some(((cpu, cpu_idx), gpu_idx)) = balance {
+        if gpu_idx >= 50 && cpu.cores > 0 && cpu.cores <= 4 {
+            finding(&mut findings, Severity::Warning, Component::Cpu, format!(
+                "High-end GPU paired with a {}-core CPU, games will be CPU bound", cpu.cores));
+        } else if gpu_idx >= cpu_idx + 35 {
+            finding(&mut findings, Severity::Warning, Component::Cpu,
+                "The CPU is much weaker than the GPU and will limit frame rates".to_string());
+        } else if cpu_idx >= gpu_idx + 40 {
+            finding(&mut findings, Severity::Info, Component::Gpu,
+                "The GPU is much weaker than the CPU, a graphics upgrade gives the biggest gaming gain".to_string());
+        }
+    }
+    if inputs.gpu.is_none() {
+        finding(&mut findings, Severity::Info, Component::Gpu, "No graphics adapter could be scored".to_string());
+    }
+
+    let (recommended_gb, tight_for) = match workload {
+        Workload::Office => (8, "office work"),
+        Workload::Gaming => (16, "current games and heavy browser use"),
+        Workload::Workstation => (32, "large projects, renders and virtual machines"),
+    };
+    match inputs.ram_gb {
+        0 => {}
+        gb if gb < 8 => finding(&mut findings, Severity::Critical, Component::Ram, format!(
+            "Only {} GB of RAM, Windows alone uses about 4 GB", gb)),
+        gb if gb < recommended_gb => finding(&mut findings, Severity::Warning, Component::Ram, format!(
+            "{} GB of RAM is tight for {}, {} GB is recommended", gb, tight_for, recommended_gb)),
+        _ => {}
+    }
+    if inputs.ram_modules == 1 {
+        finding(&mut findings, Severity::Info, Component::Ram,
+            "A single memory module runs in single-channel mode, a second matching module doubles bandwidth".to_string());
+    }
+
+    match &inputs.system_disk {
+        Some(disk) if !disk.is_ssd && !disk.is_nvme => finding(&mut findings, Severity::Warning, Component::Disk,
+            "Windows is installed on a hard disk, boot and load times will be slow. An SSD is the most noticeable upgrade".to_string()),
+        None => finding(&mut findings, Severity::Info, Component::Disk, "The system drive could not be identified".to_string()),
+        _ => {}
+    }
+
+    if inputs.virtual_machine {
+        finding(&mut findings, Severity::Info, Component::System,
+            "Running in a virtual machine, the scores describe the resources assigned by the host".to_string());
+    }
+
+    // Most severe first
+    findings.sort_by(|a, b| b.severity.partial_cmp(&a.severity).unwrap_or(std::cmp::Ordering::Equal));
+    findings
+}
+
+pub fn evaluate(inputs: &SystemInputs, profile: &ScoringProfile, all_profiles: &[ScoringProfile]) -> SystemScore {
+    let components = ComponentIndexes {
+        cpu: inputs.cpu.as_ref().and_then(|c| component_index(&c.rating, CPU_REFERENCE)),
+        gpu: inputs.gpu.as_ref().and_then(|g| component_index(g, GPU_REFERENCE)),
+        ram: ram_index(inputs.ram_gb, inputs.ram_speed_mhz),
+        disk: inputs.system_disk.as_ref().map(disk_index),
+    };
+
+    let score_for = |p: &ScoringProfile| {
+        let score_num = weighted_score(&components, &weights_of(&p.weights));
+        WorkloadScore {
+            profile: p.id.clone(),
+            name: p.name.clone(),
+            workload: p.workload,
+            score: format!("{:?}", score_num.map(|n| super::score_system(p, n)).unwrap_or(Score::Unknown)),
+            score_num: score_num.unwrap_or(0),
+        }
+    };
+    let primary = score_for(profile);
+    let workloads: Vec<WorkloadScore> = all_profiles.iter().map(score_for).collect();
+
+    let mut findings = collect_findings(inputs, &components, profile.workload);
+    let bottleneck = find_bottleneck(&components, &weights_of(&profile.weights));
+    if let Some(component) = bottleneck {
+        finding(&mut findings, Severity::Info, component, format!("{} is the bottleneck for {}", component.label(), profile.name));
+    }
+
+    SystemScore {
+        profile: profile.id.clone(),
+        workload: profile.workload,
+        score: primary.score,
+        score_num: primary.score_num,
+        components,
+        workloads,
+        bottleneck,
+        findings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn findings_for(inputs: &SystemInputs, workload: Workload) -> Vec<Finding> {
+        collect_findings(inputs, &ComponentIndexes { cpu: None, gpu: None, ram: None, disk: None }, workload)
+    }
+
+    #[test]
+    fn virtual_machine_is_a_system_finding() {
+        let inputs = SystemInputs { virtual_machine: true, ..Default::default() };
+        let findings = findings_for(&inputs, Workload::Gaming);
+        let vm = findings.iter().find(|f| f.message.contains("virtual machine")).unwrap();
+        assert_eq!(vm.component, Component::System);
+    }
+
+    #[test]
+    fn ram_advice_follows_the_workload() {
+        let inputs = SystemInputs { ram_gb: 16, ..Default::default() };
+        assert!(!findings_for(&inputs, Workload::Gaming).iter().any(|f| f.component == Component::Ram));
+        let workstation = findings_for(&inputs, Workload::Workstation);
+        let ram = workstation.iter().find(|f| f.component == Component::Ram).unwrap();
+        assert_eq!(ram.severity, Severity::Warning);
+        assert!(ram.message.contains("32 GB is recommended") && !ram.message.contains("games"));
+
+        let inputs = SystemInputs { ram_gb: 12, ..Default::default() };
+        let gaming = findings_for(&inputs, Workload::Gaming);
+        assert!(gaming.iter().any(|f| f.component == Component::Ram && f.message.contains("games")));
+        assert!(!findings_for(&inputs, Workload::Office).iter().any(|f| f.component == Component::Ram));
+    }
+}
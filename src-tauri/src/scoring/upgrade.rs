@@ -0,0 +1,415 @@
+use serde::Serialize;
+use std::ops::RangeInclusive;
+use crate::hardware::motherboard::{FormFactor, MotherboardInfo};
+use crate::model_name::{ModelName, Vendor};
+use super::benchmarks::{BenchmarkEntry, CPU_BENCHMARKS, GPU_BENCHMARKS};
+use super::profiles::ScoringProfile;
+use super::system::{self, Component, ComponentInput, DiskInput, SystemInputs, SystemScore, GPU_REFERENCE};
+
+// Upgrade advisor. Candidate parts come from the benchmark database, filtered by what the
+// board can take (socket/chipset, free slots, max RAM, PCIe generation), and every candidate
+// is valued by re-running the system score with that part swapped in.
+
+struct Platform {
+    chipsets: &'static [&'static str],
+    socket: &'static str,
+    vendor: Vendor,
+    // CPU generations the socket accepts, and those that work without a BIOS update
+    generations: RangeInclusive<u32>,
+    native: RangeInclusive<u32>,
+    gpu_pcie: u32,
+    m2_pcie: u32,
+}
+
+// Same chipset names as motherboard::get_motherboard_info. Intel Core Ultra 200S is generation 15.
+const PLATFORMS: &[Platform] = &[
+    Platform { chipsets: &["X870E", "X870", "X670E", "B650E"], socket: "AM5", vendor: Vendor::Amd, generations: 7..=9, native: 7..=9, gpu_pcie: 5, m2_pcie: 5 },
+    Platform { chipsets: &["X670", "B650"], socket: "AM5", vendor: Vendor::Amd, generations: 7..=9, native: 7..=9, gpu_pcie: 4, m2_pcie: 5 },
+    Platform { chipsets: &["A620"], socket: "AM5", vendor: Vendor::Amd, generations: 7..=9, native: 7..=9, gpu_pcie: 4, m2_pcie: 4 },
+    Platform { chipsets: &["X570", "B550"], socket: "AM4", vendor: Vendor::Amd, generations: 3..=5, native: 3..=3, gpu_pcie: 4, m2_pcie: 4 },
+    Platform { chipsets: &["A520"], socket: "AM4", vendor: Vendor::Amd, generations: 3..=5, native: 3..=5, gpu_pcie: 3, m2_pcie: 3 },
+    Platform { chipsets: &["X470", "B450", "X370", "B350"], socket: "AM4", vendor: Vendor::Amd, generations: 1..=5, native: 1..=2, gpu_pcie: 3, m2_pcie: 3 },
+    Platform { chipsets: &["Z890", "B860"], socket: "LGA1851", vendor: Vendor::Intel, generations: 15..=15, native: 15..=15, gpu_pcie: 5, m2_pcie: 5 },
+    Platform { chipsets: &["Z790"], socket: "LGA1700", vendor: Vendor::Intel, generations: 12..=14, native: 12..=13, gpu_pcie: 5, m2_pcie: 4 },
+    Platform { chipsets: &["B760", "H770"], socket: "LGA1700", vendor: Vendor::Intel, generations: 12..=14, native: 12..=13, gpu_pcie: 4, m2_pcie: 4 },
+    Platform { chipsets: &["Z690"], socket: "LGA1700", vendor: Vendor::Intel, generations: 12..=14, native: 12..=12, gpu_pcie: 5, m2_pcie: 4 },
+    Platform { chipsets: &["H710", "B660", "H670", "H610"], socket: "LGA1700", vendor: Vendor::Intel, generations: 12..=14, native: 12..=12, gpu_pcie: 4, m2_pcie: 4 },
+    Platform { chipsets: &["Z590", "B560", "H570", "H510"], socket: "LGA1200", vendor: Vendor::Intel, generations: 10..=11, native: 10..=11, gpu_pcie: 4, m2_pcie: 4 },
+    Platform { chipsets: &["Z490", "H470"], socket: "LGA1200", vendor: Vendor::Intel, generations: 10..=11, native: 10..=10, gpu_pcie: 3, m2_pcie: 3 },
+    Platform { chipsets: &["B460", "H410"], socket: "LGA1200", vendor: Vendor::Intel, generations: 10..=10, native: 10..=10, gpu_pcie: 3, m2_pcie: 3 },
+];
+
+// Only cards still sold new are suggested, older ones are in the database for scoring
+const CURRENT_GPU_SERIES: [&str; 4] = ["RTX 40", "RTX 30", "RX 7", "RX 6"];
+// Cards with a narrow PCIe link lose bandwidth on PCIe 3.0 boards
+const X4_GPUS: [&str; 2] = ["RX 6400", "RX 6500"];
+const X8_GPUS: [&str; 4] = ["RX 6600", "RX 7600", "RTX 4060", "RTX 3050"];
+
+// A "step up" part has to be at least this much faster to be worth the money
+const STEP_GAIN: f32 = 1.3;
+// A GPU more than this many index points above the CPU mostly waits on it
+const GPU_BALANCE_GAP: u32 = 35;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PlatformInfo {
+    pub chipset: String,
+    pub socket: Option<String>,
+    pub gpu_pcie_gen: Option<u32>,
+    pub m2_pcie_gen: Option<u32>,
+    pub ram_slots_total: u32,
+    pub ram_slots_free: u32,
+    pub max_ram_gb: Option<u64>,
+    pub m2_slots_free: u32,
+    pub gpu_slots_free: u32,
+    pub form_factor: FormFactor,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Upgrade {
+    pub component: Component,
+    pub title: String,
+    pub reason: String,
+    // Things to check before buying
+    pub constraints: Vec<String>,
+    pub new_score: u32,
+    pub score_gain: i32,
+    pub addresses_bottleneck: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UpgradeAdvice {
+    pub profile: String,
+    pub current_score: u32,
+    pub weakest: Option<Component>,
+    pub platform: PlatformInfo,
+    // Highest expected gain first
+    pub upgrades: Vec<Upgrade>,
+    pub notes: Vec<String>,
+}
+
+pub struct UpgradeInputs {
+    pub system: SystemInputs,
+    pub cpu_name: Option<String>,
+    pub ram_modules_gb: Vec<u64>,
+    pub board: Option<MotherboardInfo>,
+    pub form_factor: FormFactor,
+}
+
+fn platform_for(chipset: &str) -> Option<&'static Platform> {
+    PLATFORMS.iter().find(|p| p.chipsets.contains(&chipset))
+}
+
+// Intel Core generation (i5-12400 -> 12, Core Ultra 7 265K -> 15) or Ryzen series (5600X -> 5)
+fn cpu_generation(model: &ModelName) -> Option<u32> {
+    let number = model.model.as_deref()?;
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    match model.vendor {
+        Vendor::Intel if model.series.starts_with("Core Ultra") => (number.len() == 3 && number.starts_with('2')).then_some(15),
+        Vendor::Intel if model.series.starts_with("Core i") => match number.len() {
+            5 => number[..2].parse().ok(),
+            4 => number[..1].parse().ok(),
+            _ => None,
+        },
+        Vendor::Amd if model.series.starts_with("Ryzen") && number.len() == 4 => number[..1].parse().ok(),
+        _ => None,
+    }
+}
+
+// Up to two options: the smallest worthwhile step, and the strongest one under `cap`
+fn pick(current: u32, mut candidates: Vec<(&'static BenchmarkEntry, u32)>, cap: Option<u32>) -> Vec<(&'static BenchmarkEntry, u32)> {
+    candidates.retain(|(_, score)| *score > current && cap.is_none_or(|c| *score <= c));
+    candidates.sort_by_key(|(_, score)| *score);
+    let step = candidates.iter().find(|(_, score)| *score as f32 >= current as f32 * STEP_GAIN).copied();
+    let top = candidates.last().copied();
+    let mut picked: Vec<(&'static BenchmarkEntry, u32)> = step.into_iter().collect();
+    if let Some(top) = top {
+        if picked.iter().all(|(e, _)| e.name != top.0.name) {
+            picked.push(top);
+        }
+    }
+    picked
+}
+
+// Integrated graphics are not in the benchmark database
+fn has_graphics_card(inputs: &SystemInputs) -> bool {
+    inputs.gpu.as_ref().is_some_and(|g| g.benchmark.is_some())
+}
+
+fn score_of(inputs: &SystemInputs, profile: &ScoringProfile) -> u32 {
+    system::evaluate(inputs, profile, &[]).score_num
+}
+
+struct Advisor<'a> {
+    inputs: &'a UpgradeInputs,
+    profile: &'a ScoringProfile,
+    current: &'a SystemScore,
+    weakest: Option<Component>,
+    upgrades: Vec<Upgrade>,
+    notes: Vec<String>,
+}
+
+impl Advisor<'_> {
+    fn add(&mut self, component: Component, title: String, reason: String, constraints: Vec<String>, upgraded: &SystemInputs) {
+        let new_score = score_of(upgraded, self.profile);
+        let score_gain = new_score as i32 - self.current.score_num as i32;
+        if score_gain <= 0 {
+            return;
+        }
+        self.upgrades.push(Upgrade {
+            component,
+            title,
+            reason,
+            constraints,
+            new_score,
+            score_gain,
+            addresses_bottleneck: self.weakest == Some(component),
+        });
+    }
+}
+
+fn advise_cpu(advisor: &mut Advisor, platform: Option<&Platform>) {
+    let inputs = advisor.inputs;
+    if !inputs.form_factor.supports_cpu_upgrade() {
+        return;
+    }
+    let Some(cpu) = inputs.system.cpu.as_ref() else { return };
+    let Some(current) = cpu.rating.benchmark else {
+        advisor.notes.push("The installed CPU is not in the benchmark database, CPU upgrades cannot be valued".to_string());
+        return;
+    };
+    let Some(platform) = platform else {
+        advisor.notes.push("Chipset not recognized, CPU socket compatibility cannot be checked".to_string());
+        return;
+    };
+    // The chipset is guessed from the board name, make sure the installed CPU agrees with it
+    let installed = inputs.cpu_name.as_deref().map(ModelName::parse);
+    if installed.as_ref().is_some_and(|m| m.vendor != platform.vendor || cpu_generation(m).is_some_and(|g| !platform.generations.contains(&g))) {
+        advisor.notes.push(format!("The installed CPU does not fit the detected {} platform, CPU upgrades were skipped", platform.socket));
+        return;
+    }
+
+    let candidates: Vec<(&'static BenchmarkEntry, u32)> = CPU_BENCHMARKS.iter()
+        .filter(|entry| {
+            let model = ModelName::parse(entry.name);
+            model.vendor == platform.vendor && !model.mobile
+                && cpu_generation(&model).is_some_and(|g| platform.generations.contains(&g))
+        })
+        .map(|entry| (entry, entry.score))
+        .collect();
+
+    for (entry, score) in pick(current, candidates, None) {
+        let mut constraints = vec![format!("{} socket, {} chipset", platform.socket, platform.chipsets.join("/"))];
+        let generation = cpu_generation(&ModelName::parse(entry.name)).unwrap_or_default();
+        if !platform.native.contains(&generation) {
+            constraints.push("Needs a BIOS update before the swap, flash it with the old CPU still installed".to_string());
+        }
+        if entry.has_igpu == Some(false) && !has_graphics_card(&inputs.system) {
+            constraints.push("Has no integrated graphics, a graphics card is required".to_string());
+        }
+        let mut upgraded = inputs.system.clone();
+        if let Some(c) = upgraded.cpu.as_mut() {
+            c.rating.benchmark = Some(score);
+        }
+        advisor.add(Component::Cpu, format!("Upgrade the CPU to {}", entry.name),
+            format!("{} benchmark points, {} now", score, current), constraints, &upgraded);
+    }
+}
+
+fn pcie_penalty(name: &str, gpu_pcie: u32) -> Option<(f32, String)> {
+    if gpu_pcie > 3 {
+        return None;
+    }
+    let upper = name.to_uppercase();
+    if X4_GPUS.iter().any(|g| upper.contains(g)) {
+        Some((0.85, "Only a PCIe x4 card, on this PCIe 3.0 board it loses about 15% performance".to_string()))
+    } else if X8_GPUS.iter().any(|g| upper.contains(g)) {
+        Some((0.95, "PCIe x8 card, on this PCIe 3.0 board it loses a few percent".to_string()))
+    } else {
+        None
+    }
+}
+
+fn advise_gpu(advisor: &mut Advisor, platform: Option<&Platform>, board: Option<&MotherboardInfo>) {
+    let inputs = advisor.inputs;
+    if !inputs.form_factor.supports_gpu_upgrade() {
+        return;
+    }
+    // Boards that do not list their slots report 0, assume the usual single x16 slot
+    let slots_full = board.is_some_and(|b| b.gpu_slots.total > 0 && b.gpu_slots.used >= b.gpu_slots.total);
+    if slots_full && !has_graphics_card(&inputs.system) {
+        return;
+    }
+    let current = inputs.system.gpu.as_ref().and_then(|g| g.benchmark).unwrap_or(0);
+    let gpu_pcie = platform.map(|p| p.gpu_pcie).unwrap_or(4);
+    // Beyond this the CPU holds the card back
+    let cap = advisor.current.components.cpu.map(|cpu_idx| (cpu_idx + GPU_BALANCE_GAP).min(100) * GPU_REFERENCE / 100);
+
+    let candidates: Vec<(&'static BenchmarkEntry, u32)> = GPU_BENCHMARKS.iter()
+        .filter(|entry| CURRENT_GPU_SERIES.iter().any(|s| entry.name.contains(s)))
+        .map(|entry| {
+            let factor = pcie_penalty(entry.name, gpu_pcie).map(|(f, _)| f).unwrap_or(1.0);
+            (entry, (entry.score as f32 * factor) as u32)
+        })
+        .collect();
+
+    for (entry, score) in pick(current, candidates, cap) {
+        let mut constraints = Vec::new();
+        if let Some((_, warning)) = pcie_penalty(entry.name, gpu_pcie) {
+            constraints.push(warning);
+        }
+        if current == 0 {
+            constraints.push("Uses the free PCIe x16 slot, check case length and power supply connectors".to_string());
+        } else {
+            constraints.push("Check case length and power supply connectors".to_string());
+        }
+        let mut upgraded = inputs.system.clone();
+        upgraded.gpu = Some(ComponentInput { tier: super::Score::Unknown, benchmark: Some(score) });
+        advisor.add(Component::Gpu, if current == 0 { format!("Add a {} graphics card", entry.name) } else { format!("Upgrade the graphics card to {}", entry.name) },
+            format!("{} benchmark points, {} now", score, current), constraints, &upgraded);
+    }
+}
+
+fn advise_ram(advisor: &mut Advisor, board: Option<&MotherboardInfo>) {
+    let inputs = advisor.inputs;
+    if !inputs.form_factor.supports_memory_upgrade() {
+        advisor.notes.push("Memory is soldered on this device and cannot be upgraded".to_string());
+        return;
+    }
+    let total: u64 = inputs.ram_modules_gb.iter().sum();
+    let modules = inputs.ram_modules_gb.len() as u32;
+    let (slots, max_gb) = board.map(|b| (b.ram_slots.total, b.max_ram_gb)).unwrap_or((0, None));
+    let free = slots.saturating_sub(modules);
+    let target = advisor.profile.ram.excellent.gb.min(max_gb.unwrap_or(u64::MAX));
+    let mut constraints = vec![format!("Match the installed memory type and speed ({} MHz)", inputs.system.ram_speed_mhz)];
+    if let Some(max) = max_gb {
+        constraints.push(format!("The board accepts up to {} GB", max));
+    }
+
+    // One stick runs single channel, a matching second one is the cheapest fix
+    if modules == 1 && free >= 1 {
+        let mut upgraded = inputs.system.clone();
+        upgraded.ram_gb = total * 2;
+        upgraded.ram_modules = 2;
+        advisor.add(Component::Ram, format!("Add a matching {} GB module", total),
+            "Enables dual-channel mode and doubles capacity".to_string(), constraints.clone(), &upgraded);
+    }
+
+    if total >= target || modules == 0 {
+        return;
+    }
+    let module_gb = inputs.ram_modules_gb[0];
+    let uniform = inputs.ram_modules_gb.iter().all(|gb| *gb == module_gb);
+    let needed = (target - total).div_ceil(module_gb.max(1)) as u32;
+    let mut upgraded = inputs.system.clone();
+    upgraded.ram_gb = target;
+    if uniform && needed <= free && total + needed as u64 * module_gb <= max_gb.unwrap_or(u64::MAX) {
+        upgraded.ram_gb = total + needed as u64 * module_gb;
+        upgraded.ram_modules = (modules + needed) as usize;
+        advisor.add(Component::Ram, format!("Add {} x {} GB", needed, module_gb),
+            format!("{} GB total, uses {} of the {} free slots", upgraded.ram_gb, needed, free), constraints, &upgraded);
+    } else if slots >= 2 || slots == 0 {
+        upgraded.ram_modules = 2;
+        advisor.add(Component::Ram, format!("Replace with a 2 x {} GB kit", target / 2),
+            format!("{} GB total in dual channel", target), constraints, &upgraded);
+    }
+}
+
+fn advise_disk(advisor: &mut Advisor, platform: Option<&Platform>, board: Option<&MotherboardInfo>) {
+    let inputs = advisor.inputs;
+    let Some(disk) = inputs.system.system_disk.as_ref() else { return };
+    if disk.is_nvme {
+        return;
+    }
+    let m2_free = board.map(|b| b.ssd_slots.total.saturating_sub(b.ssd_slots.used)).unwrap_or(0);
+    let mut constraints = vec!["Clone the Windows drive to the new one, or reinstall".to_string()];
+    let mut upgraded = inputs.system.clone();
+    if m2_free > 0 {
+        let generation = platform.map(|p| p.m2_pcie).unwrap_or(3);
+        constraints.push(format!("Uses a free M.2 slot, PCIe {}.0 drives run at full speed", generation));
+        upgraded.system_disk = Some(DiskInput { is_ssd: true, is_nvme: true });
+        advisor.add(Component::Disk, "Move Windows to an NVMe SSD".to_string(),
+            if disk.is_ssd { "NVMe is several times faster than SATA".to_string() } else { "Boot and load times drop from minutes to seconds".to_string() },
+            constraints, &upgraded);
+    } else if !disk.is_ssd {
+        constraints.push("No free M.2 slot found, a 2.5\" SATA SSD fits any drive bay".to_string());
+        upgraded.system_disk = Some(DiskInput { is_ssd: true, is_nvme: false });
+        advisor.add(Component::Disk, "Move Windows to a SATA SSD".to_string(),
+            "Boot and load times drop from minutes to seconds".to_string(), constraints, &upgraded);
+    }
+}
+
+pub fn advise(inputs: &UpgradeInputs, profile: &ScoringProfile) -> UpgradeAdvice {
+    let current = system::evaluate(&inputs.system, profile, &[]);
+    let board = inputs.board.as_ref();
+    let platform = board.and_then(|b| platform_for(&b.chipset));
+    // Bottleneck when there is a clear one, otherwise simply the lowest index
+    let weakest = current.bottleneck.or_else(|| {
+        let c = &current.components;
+        [(Component::Cpu, c.cpu), (Component::Gpu, c.gpu), (Component::Ram, c.ram), (Component::Disk, c.disk)]
+            .into_iter()
+            .filter_map(|(component, index)| index.map(|i| (component, i)))
+            .min_by_key(|(_, i)| *i)
+            .map(|(component, _)| component)
+    });
+
+    let mut advisor = Advisor { inputs, profile, current: &current, weakest, upgrades: Vec::new(), notes: Vec::new() };
+    advise_cpu(&mut advisor, platform);
+    advise_gpu(&mut advisor, platform, board);
+    advise_ram(&mut advisor, board);
+    advise_disk(&mut advisor, platform, board);
+    let (mut upgrades, mut notes) = (advisor.upgrades, advisor.notes);
+    if !inputs.form_factor.supports_cpu_upgrade() {
+        notes.push("The processor cannot be replaced in this form factor".to_string());
+    }
+    if !inputs.form_factor.supports_gpu_upgrade() {
+        notes.push("The graphics card cannot be replaced in this form factor".to_string());
+    }
+    if inputs.system.virtual_machine {
+        notes.push("Running in a virtual machine, change the resources assigned by the host instead".to_string());
+    }
+
+    upgrades.sort_by(|a, b| b.score_gain.cmp(&a.score_gain).then(b.addresses_bottleneck.cmp(&a.addresses_bottleneck)));
+
+    let ram_slots_total = board.map(|b| b.ram_slots.total).unwrap_or(0);
+    UpgradeAdvice {
+        profile: profile.id.clone(),
+        current_score: current.score_num,
+        weakest,
+        platform: PlatformInfo {
+            chipset: board.map(|b| b.chipset.clone()).unwrap_or("Unknown".to_string()),
+            socket: platform.map(|p| p.socket.to_string()),
+            gpu_pcie_gen: platform.map(|p| p.gpu_pcie),
+            m2_pcie_gen: platform.map(|p| p.m2_pcie),
+            ram_slots_total,
+            ram_slots_free: ram_slots_total.saturating_sub(inputs.ram_modules_gb.len() as u32),
+            max_ram_gb: board.and_then(|b| b.max_ram_gb),
+            m2_slots_free: board.map(|b| b.ssd_slots.total.saturating_sub(b.ssd_slots.used)).unwrap_or(0),
+            gpu_slots_free: board.map(|b| b.gpu_slots.total.saturating_sub(b.gpu_slots.used)).unwrap_or(0),
+            form_factor: inputs.form_factor,
+        },
+        upgrades,
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrow_link_cards_are_in_the_database() {
+        for card in X4_GPUS.iter().chain(X8_GPUS.iter()) {
+            assert!(GPU_BENCHMARKS.iter().any(|entry| entry.name.to_uppercase().contains(card)), "{} is not in GPU_BENCHMARKS", card);
+        }
+    }
+
+    #[test]
+    fn narrow_link_cards_lose_bandwidth_on_pcie_3() {
+        assert_eq!(pcie_penalty("AMD Radeon RX 6500 XT", 3).map(|(f, _)| f), Some(0.85));
+        assert_eq!(pcie_penalty("AMD Radeon RX 7600", 3).map(|(f, _)| f), Some(0.95));
+        assert!(pcie_penalty("AMD Radeon RX 6500 XT", 4).is_none());
+        assert!(pcie_penalty("NVIDIA GeForce RTX 4070", 3).is_none());
+    }
+}
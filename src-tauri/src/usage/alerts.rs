@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+// User-defined threshold alerts checked against every sample. A rule fires once its condition
+// has held for `duration_secs`, stays active (without firing again) until the condition clears,
+// and will not fire again within `cooldown_secs` of the last time.
+
+pub const ALERT_EVENT: &str = "alert-fired";
+
+const ALERTS_FILE: &str = "neko233-hardware-viewer.alerts.json";
+// Oldest fired alerts are dropped past this
+const MAX_HISTORY: usize = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Above,
+    AtLeast,
+    Below,
+    AtMost,
+}
+
+impl Comparison {
+    fn holds(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Above => value > threshold,
+            Comparison::AtLeast => value >= threshold,
+            Comparison::Below => value < threshold,
+            Comparison::AtMost => value <= threshold,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Above => ">",
+            Comparison::AtLeast => ">=",
+            Comparison::Below => "<",
+            Comparison::AtMost => "<=",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AlertRule {
+    // Empty for a new rule, `save_alert_rule` assigns one
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    // Any name from `list_metrics`, e.g. "cpu_temp_c" or "volume_free_pct.C:"
+    pub metric: String,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    #[serde(default)]
+    pub duration_secs: u64,
+    #[serde(default)]
+    pub cooldown_secs: u64,
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FiredAlert {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub metric: String,
+    // Value of the sample that fired the rule
+    pub value: f64,
+    pub comparison: Comparison,
+    pub threshold: f64,
+    // Unix milliseconds
+    pub fired_at: u64,
+    // Set once the condition no longer holds
+    pub resolved_at: Option<u64>,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct AlertsFile {
+    rules: Vec<AlertRule>,
+    history: Vec<FiredAlert>,
+}
+
+#[derive(Debug, Default)]
+struct RuleState {
+    // Since when the condition holds without interruption
+    since: Option<u64>,
+    last_fired: Option<u64>,
+    active: bool,
+}
+
+pub struct AlertEngine {
+    file: PathBuf,
+    rules: Vec<AlertRule>,
+    history: Vec<FiredAlert>,
+    state: HashMap<String, RuleState>,
+}
+
+// Examples for a first start; both can be edited or deleted
+fn default_rules() -> Vec<AlertRule> {
+    let system_drive = std::env::var("SystemDrive").unwrap_or("C:".to_string());
+    vec![
+        AlertRule {
+            id: "cpu-temp".to_string(),
+            name: "CPU temperature".to_string(),
+            metric: "cpu_temp_c".to_string(),
+            comparison: Comparison::Above,
+            threshold: 90.0,
+            duration_secs: 30,
+            cooldown_secs: 600,
+            enabled: true,
+        },
+        AlertRule {
+            id: "system-free-space".to_string(),
+            name: format!("Free space on {}", system_drive),
+            metric: format!("volume_free_pct.{}", system_drive),
+            comparison: Comparison::Below,
+            threshold: 10.0,
+            duration_secs: 60,
+            cooldown_secs: 3600,
+            enabled: true,
+        },
+    ]
+}
+
+// Marks the rule's open alert as resolved at `t`; returns whether there was one
+fn resolve_open(history: &mut [FiredAlert], rule_id: &str, t: u64) -> bool {
+    match history.iter_mut().rev().find(|a| a.rule_id == rule_id) {
+        Some(alert) if alert.resolved_at.is_none() => {
+            alert.resolved_at = Some(t);
+            true
+        }
+        _ => false,
+    }
+}
+
+impl Default for AlertEngine {
+    fn default() -> Self {
+        let file = fs::read_to_string(ALERTS_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str::<AlertsFile>(&content).ok())
+            .unwrap_or_else(|| AlertsFile { rules: default_rules(), history: Vec::new() });
+        Self { file: PathBuf::from(ALERTS_FILE), rules: file.rules, history: file.history, state: HashMap::new() }
+    }
+}
+
+impl AlertEngine {
+    fn save(&self) -> Result<(), String> {
+        let file = AlertsFile { rules: self.rules.clone(), history: self.history.clone() };
+        let json = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+        fs::write(&self.file, json).map_err(|e| e.to_string())
+    }
+
+    pub fn rules(&self) -> Vec<AlertRule> {
+        self.rules.clone()
+    }
+
+    // Newest first
+    pub fn history(&self, limit: usize) -> Vec<FiredAlert> {
+        self.history.iter().rev().take(limit).cloned().collect()
+    }
+
+    pub fn clear_history(&mut self) -> Result<(), String> {
+        self.history.clear();
+        self.save()
+    }
+
+    // Adds the rule, or replaces the one with the same id; returns the stored rule
+    pub fn save_rule(&mut self, mut rule: AlertRule, now: u64) -> Result<AlertRule, String> {
+        rule.name = rule.name.trim().to_string();
+        rule.metric = rule.metric.trim().to_string();
+        if rule.metric.is_empty() {
+            return Err("The rule needs a metric".to_string());
+        }
+        if !rule.threshold.is_finite() {
+            return Err("The threshold must be a number".to_string());
+        }
+        if rule.name.is_empty() {
+            rule.name = format!("{} {} {}", rule.metric, rule.comparison.symbol(), rule.threshold);
+        }
+        if rule.id.is_empty() {
+            rule.id = format!("rule-{}", now);
+        }
+        // A changed condition starts over, and an alert of the old one will not resolve by itself
+        if self.state.remove(&rule.id).is_some_and(|s| s.active) {
+            resolve_open(&mut self.history, &rule.id, now);
+        }
+        match self.rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule.clone(),
+            None => self.rules.push(rule.clone()),
+        }
+        self.save()?;
+        Ok(rule)
+    }
+
+    pub fn delete_rule(&mut self, id: &str, now: u64) -> Result<(), String> {
+        let before = self.rules.len();
+        self.rules.retain(|r| r.id != id);
+        if self.rules.len() == before {
+            return Err(format!("Alert rule {} not found", id));
+        }
+        if self.state.remove(id).is_some_and(|s| s.active) {
+            resolve_open(&mut self.history, id, now);
+        }
+        self.save()
+    }
+
+    // Checks every enabled rule against one sample and returns the alerts it fired, plus the
+    // outcome of saving the history when it changed
+    pub fn evaluate(&mut self, t: u64, metrics: &[(String, f64)]) -> (Vec<FiredAlert>, Result<(), String>) {
+        let mut fired = Vec::new();
+        let mut changed = false;
+        for rule in &self.rules {
+            if !rule.enabled {
+                if self.state.remove(&rule.id).is_some_and(|s| s.active) {
+                    changed |= resolve_open(&mut self.history, &rule.id, t);
+                }
+                continue;
+            }
+            let state = self.state.entry(rule.id.clone()).or_default();
+            // A metric missing from the sample (sensor gone, drive removed) counts as not holding
+            let value = metrics.iter().find(|(name, _)| *name == rule.metric).map(|(_, v)| *v);
+            let Some(value) = value.filter(|v| rule.comparison.holds(*v, rule.threshold)) else {
+                state.since = None;
+                if state.active {
+                    state.active = false;
+                    changed |= resolve_open(&mut self.history, &rule.id, t);
+                }
+                continue;
+            };
+            let since = *state.since.get_or_insert(t);
+            let held = t.saturating_sub(since) >= rule.duration_secs * 1000;
+            let cooled = state.last_fired.is_none_or(|last| t.saturating_sub(last) >= rule.cooldown_secs * 1000);
+            if state.active || !held || !cooled {
+                continue;
+            }
+            state.active = true;
+            state.last_fired = Some(t);
+            let message = format!(
+                "{}: {} {} {} ({:.1}) for {} s",
+                rule.name, rule.metric, rule.comparison.symbol(), rule.threshold, value, rule.duration_secs
+            );
+            fired.push(FiredAlert {
+                rule_id: rule.id.clone(),
+                rule_name: rule.name.clone(),
+                metric: rule.metric.clone(),
+                value,
+                comparison: rule.comparison,
+                threshold: rule.threshold,
+                fired_at: t,
+                resolved_at: None,
+                message,
+            });
+        }
+        if fired.is_empty() && !changed {
+            return (fired, Ok(()));
+        }
+        self.history.extend(fired.iter().cloned());
+        let excess = self.history.len().saturating_sub(MAX_HISTORY);
+        self.history.drain(..excess);
+        let saved = self.save();
+        (fired, saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: u64 = 1000;
+
+    fn engine(name: &str) -> AlertEngine {
+        let rule = AlertRule {
+            id: "hot".to_string(),
+            name: "Hot".to_string(),
+            metric: "cpu_temp_c".to_string(),
+            comparison: Comparison::Above,
+            threshold: 90.0,
+            duration_secs: 30,
+            cooldown_secs: 600,
+            enabled: true,
+        };
+        let file = std::env::temp_dir().join(format!("neko233-hardware-viewer.alerts-test-{}-{}.json", name, std::process::id()));
+        AlertEngine { file, rules: vec![rule], history: Vec::new(), state: HashMap::new() }
+    }
+
+    // One sample per second from `from` to `to` (seconds, inclusive); returns when alerts fired
+    fn run(engine: &mut AlertEngine, from: u64, to: u64, temp: f64) -> Vec<u64> {
+        let mut fired = Vec::new();
+        for s in from..=to {
+            let (alerts, _) = engine.evaluate(s * SECOND, &[("cpu_temp_c".to_string(), temp)]);
+            fired.extend(alerts.iter().map(|a| a.fired_at / SECOND));
+        }
+        fired
+    }
+
+    #[test]
+    fn fires_only_after_the_duration() {
+        let mut e = engine("duration");
+        // 21 s above the threshold is not enough, and the dip starts the count over
+        assert!(run(&mut e, 0, 20, 95.0).is_empty());
+        assert!(run(&mut e, 21, 21, 50.0).is_empty());
+        assert_eq!(run(&mut e, 22, 70, 95.0), vec![52]);
+        assert_eq!(e.history.len(), 1);
+        assert_eq!(e.history[0].resolved_at, None);
+        let _ = fs::remove_file(&e.file);
+    }
+
+    #[test]
+    fn cooldown_holds_back_a_second_alert() {
+        let mut e = engine("cooldown");
+        assert_eq!(run(&mut e, 0, 30, 95.0), vec![30]);
+        run(&mut e, 31, 31, 50.0);
+        assert_eq!(e.history[0].resolved_at, Some(31 * SECOND));
+        // Held again from 32 s, but the cooldown only ends 600 s after the first alert
+        assert_eq!(run(&mut e, 32, 640, 95.0), vec![630]);
+        run(&mut e, 641, 641, 50.0);
+        // After the cooldown the duration alone decides
+        assert_eq!(run(&mut e, 1300, 1400, 95.0), vec![1330]);
+        assert_eq!(e.history.len(), 3);
+        let _ = fs::remove_file(&e.file);
+    }
+
+    #[test]
+    fn missing_metric_resolves_the_alert() {
+        let mut e = engine("missing");
+        run(&mut e, 0, 40, 95.0);
+        let (fired, _) = e.evaluate(41 * SECOND, &[]);
+        assert!(fired.is_empty());
+        assert_eq!(e.history[0].resolved_at, Some(41 * SECOND));
+        let _ = fs::remove_file(&e.file);
+    }
+
+    #[test]
+    fn disabling_the_rule_resolves_the_alert() {
+        let mut e = engine("disable");
+        run(&mut e, 0, 40, 95.0);
+        let mut rule = e.rules[0].clone();
+        rule.enabled = false;
+        e.save_rule(rule, 41 * SECOND).unwrap();
+        assert_eq!(e.history[0].resolved_at, Some(41 * SECOND));
+        assert!(run(&mut e, 42, 100, 95.0).is_empty());
+        let _ = fs::remove_file(&e.file);
+    }
+}
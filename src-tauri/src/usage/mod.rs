@@ -0,0 +1,436 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sysinfo::{Disks, Networks};
+use tauri::{Emitter, Manager};
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::disk::DiskIoCounters;
+
+pub mod alerts;
+pub mod series;
+pub mod store;
+
+// Background sampler. One thread started from `main` refreshes only CPU, memory, network and disk
+// counters at the configured interval, keeps the latest sample for `get_system_usage`, feeds every
+// metric into the time-series store and pushes each sample to the frontend as a `system-usage` event.
+// Finished minutes and events also go to the on-disk history, which refills the store at startup.
+// Alert rules are checked against every sample.
+
+pub const USAGE_EVENT: &str = "system-usage";
+
+const CONFIG_FILE: &str = "neko233-hardware-viewer.sampler.json";
+const DEFAULT_INTERVAL_MS: u64 = 1000;
+// sysinfo needs at least 200 ms between two CPU refreshes for a meaningful usage value
+const MIN_INTERVAL_MS: u64 = 250;
+const MAX_INTERVAL_MS: u64 = 60000;
+// How often the volume list is rebuilt; free space is read every sample
+const VOLUME_LIST_REFRESH: Duration = Duration::from_secs(60);
+
+#[derive(Serialize, Debug, Clone)]
+pub struct VolumeUsage {
+    // Drive letter, "C:"
+    pub volume: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+// Per-second rates of one network interface since the previous sample
+#[derive(Serialize, Debug, Clone)]
+pub struct InterfaceUsage {
+    // Interface alias, matches NetworkInfo.net_connection_id ("Ethernet", "Wi-Fi")
+    pub name: String,
+    pub rx_bps: f64,
+    pub tx_bps: f64,
+    pub rx_packets: f64,
+    pub tx_packets: f64,
+    pub rx_errors: f64,
+    pub tx_errors: f64,
+}
+
+// Per-second rates of one physical disk since the previous sample
+#[derive(Serialize, Debug, Clone)]
+pub struct DiskUsage {
+    // Performance counter instance, "0 C:"
+    pub name: String,
+    // Matches DiskInfo.index
+    pub index: Option<u32>,
+    pub read_bps: f64,
+    pub write_bps: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    // Share of the time the disk was not idle, 0-100
+    pub busy_pct: f64,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UsageSample {
+    // Unix milliseconds
+    pub timestamp: u64,
+    pub cpu_usage: f32,
+    pub cpu_cores: Vec<f32>,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    // Bytes per second across all physical disks, None while the counters are unavailable
+    pub disk_read_bps: Option<f64>,
+    pub disk_write_bps: Option<f64>,
+    // Bytes per second across all network interfaces
+    pub net_rx_bps: f64,
+    pub net_tx_bps: f64,
+    // Interfaces that carried any traffic since boot
+    pub interfaces: Vec<InterfaceUsage>,
+    // Empty while the disk counters are unavailable
+    pub disks: Vec<DiskUsage>,
+    // Hottest ACPI thermal zone, None on boards that expose none
+    pub cpu_temp_c: Option<f64>,
+    pub volumes: Vec<VolumeUsage>,
+}
+
+impl UsageSample {
+    // Flat metric names as the time-series store keys them
+    pub fn metrics(&self) -> Vec<(String, f64)> {
+        let mut metrics = vec![
+            ("cpu_usage".to_string(), self.cpu_usage as f64),
+            ("memory_used".to_string(), self.memory_used as f64),
+            ("memory_usage".to_string(), self.memory_used as f64 / self.memory_total.max(1) as f64 * 100.0),
+            ("swap_used".to_string(), self.swap_used as f64),
+            ("net_rx_bps".to_string(), self.net_rx_bps),
+            ("net_tx_bps".to_string(), self.net_tx_bps),
+        ];
+        metrics.extend(self.cpu_cores.iter().enumerate().map(|(i, usage)| (format!("cpu_core.{}", i), *usage as f64)));
+        metrics.extend(self.disk_read_bps.map(|v| ("disk_read_bps".to_string(), v)));
+        metrics.extend(self.disk_write_bps.map(|v| ("disk_write_bps".to_string(), v)));
+        metrics.extend(self.cpu_temp_c.map(|v| ("cpu_temp_c".to_string(), v)));
+        for i in &self.interfaces {
+            metrics.push((format!("net_rx_bps.{}", i.name), i.rx_bps));
+            metrics.push((format!("net_tx_bps.{}", i.name), i.tx_bps));
+            metrics.push((format!("net_rx_packets.{}", i.name), i.rx_packets));
+            metrics.push((format!("net_tx_packets.{}", i.name), i.tx_packets));
+            metrics.push((format!("net_rx_errors.{}", i.name), i.rx_errors));
+            metrics.push((format!("net_tx_errors.{}", i.name), i.tx_errors));
+        }
+        for d in &self.disks {
+            metrics.push((format!("disk_read_bps.{}", d.name), d.read_bps));
+            metrics.push((format!("disk_write_bps.{}", d.name), d.write_bps));
+            metrics.push((format!("disk_read_iops.{}", d.name), d.read_iops));
+            metrics.push((format!("disk_write_iops.{}", d.name), d.write_iops));
+            metrics.push((format!("disk_busy_pct.{}", d.name), d.busy_pct));
+        }
+        for v in self.volumes.iter().filter(|v| v.total_bytes > 0) {
+            metrics.push((format!("volume_free_pct.{}", v.volume), v.available_bytes as f64 / v.total_bytes as f64 * 100.0));
+            metrics.push((format!("volume_free_gb.{}", v.volume), v.available_bytes as f64 / 1024.0 / 1024.0 / 1024.0));
+        }
+        metrics
+    }
+}
+
+// A failed write of the on-disk history or the alert rules; the app has no console to print it to
+#[derive(Serialize, Debug, Clone)]
+pub struct SamplerError {
+    // Unix milliseconds
+    pub t: u64,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct SamplerConfig {
+    interval_ms: u64,
+}
+
+pub struct Sampler {
+    interval_ms: AtomicU64,
+    latest: Mutex<Option<UsageSample>>,
+    series: Mutex<series::SeriesStore>,
+    store: Mutex<store::MetricStore>,
+    alerts: Mutex<alerts::AlertEngine>,
+    last_error: Mutex<Option<SamplerError>>,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        let interval_ms = fs::read_to_string(CONFIG_FILE)
+            .ok()
+            .and_then(|content| serde_json::from_str::<SamplerConfig>(&content).ok())
+            .map(|config| config.interval_ms.clamp(MIN_INTERVAL_MS, MAX_INTERVAL_MS))
+            .unwrap_or(DEFAULT_INTERVAL_MS);
+        Self {
+            interval_ms: AtomicU64::new(interval_ms),
+            latest: Mutex::new(None),
+            series: Mutex::new(series::SeriesStore::default()),
+            store: Mutex::new(store::MetricStore::default()),
+            alerts: Mutex::new(alerts::AlertEngine::default()),
+            last_error: Mutex::new(None),
+        }
+    }
+}
+
+impl Sampler {
+    fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.load(Ordering::Relaxed))
+    }
+
+    // Adds an entry to the on-disk event log
+    pub fn log_event(&self, kind: &str, message: String) {
+        let event = store::StoredEvent { t: now_millis(), kind: kind.to_string(), message };
+        if let Err(e) = self.store.lock().unwrap().append_event(event) {
+            self.report_error(format!("Failed to store event: {}", e));
+        }
+    }
+
+    // Kept for `get_sampler_error`, only the latest one
+    fn report_error(&self, message: String) {
+        *self.last_error.lock().unwrap() = Some(SamplerError { t: now_millis(), message });
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+// Rates between two readings of the same counter instance, timed by the counters' own clock
+fn disk_usage(previous: &DiskIoCounters, current: &DiskIoCounters) -> Option<DiskUsage> {
+    let ticks = current.timestamp.checked_sub(previous.timestamp).filter(|t| *t > 0)? as f64;
+    let secs = ticks / 10_000_000.0;
+    // Counters restart when a disk is removed and re-added
+    let rate = |before: u64, now: u64| (now.saturating_sub(before) as f64 / secs).round();
+    let idle = current.percent_idle_time.saturating_sub(previous.percent_idle_time) as f64;
+    Some(DiskUsage {
+        name: current.name.clone(),
+        index: current.disk_index(),
+        read_bps: rate(previous.disk_read_bytes_persec, current.disk_read_bytes_persec),
+        write_bps: rate(previous.disk_write_bytes_persec, current.disk_write_bytes_persec),
+        read_iops: rate(previous.disk_reads_persec, current.disk_reads_persec),
+        write_iops: rate(previous.disk_writes_persec, current.disk_writes_persec),
+        busy_pct: ((1.0 - idle / ticks) * 1000.0).round().clamp(0.0, 1000.0) / 10.0,
+    })
+}
+
+fn disk_counters(ctx: &HardwareContext, wmi: bool) -> Vec<DiskIoCounters> {
+    if !wmi {
+        return Vec::new();
+    }
+    hardware::disk::get_disk_io_counters(ctx).unwrap_or_default()
+}
+
+// Runs for the lifetime of the app; the `Sampler` state must be managed before this is called
+pub fn start(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut ctx = HardwareContext::new();
+        let wmi = ctx.init_wmi().is_ok();
+        let mut networks = Networks::new_with_refreshed_list();
+        ctx.sys.refresh_cpu_usage();
+        let mut disks = disk_counters(&ctx, wmi);
+        let mut volumes = Disks::new_with_refreshed_list();
+        let mut volumes_listed = Instant::now();
+        // Boards without a thermal zone would pay for two failing WMI queries every sample
+        let thermal = wmi && hardware::sensors::get_cpu_temperature(&ctx).ok().flatten().is_some();
+        let mut last = Instant::now();
+        {
+            let sampler = app.state::<Sampler>();
+            sampler.store.lock().unwrap().load(&mut sampler.series.lock().unwrap(), now_millis());
+            sampler.log_event("started", format!("Sampling every {} ms", sampler.interval().as_millis()));
+        }
+
+        loop {
+            let sampler = app.state::<Sampler>();
+            std::thread::sleep(sampler.interval());
+            let secs = last.elapsed().as_secs_f64();
+            last = Instant::now();
+
+            ctx.sys.refresh_cpu_usage();
+            ctx.sys.refresh_memory();
+            // Also picks up adapters that appeared since the last sample
+            networks.refresh_list();
+            let (rx, tx) = networks.iter().fold((0, 0), |(rx, tx), (_, data)| (rx + data.received(), tx + data.transmitted()));
+            let per_sec = |count: u64| (count as f64 / secs).round();
+            // Skips the many pseudo interfaces Windows lists that never carry anything
+            let mut interfaces: Vec<InterfaceUsage> = networks.iter()
+                .filter(|(_, data)| data.total_received() + data.total_transmitted() > 0)
+                .map(|(name, data)| InterfaceUsage {
+                    name: name.clone(),
+                    rx_bps: per_sec(data.received()),
+                    tx_bps: per_sec(data.transmitted()),
+                    rx_packets: per_sec(data.packets_received()),
+                    tx_packets: per_sec(data.packets_transmitted()),
+                    rx_errors: per_sec(data.errors_on_received()),
+                    tx_errors: per_sec(data.errors_on_transmitted()),
+                })
+                .collect();
+            interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+            let current_disks = disk_counters(&ctx, wmi);
+            let mut disk_rates: Vec<DiskUsage> = current_disks.iter()
+                .filter_map(|c| disk_usage(disks.iter().find(|p| p.name == c.name)?, c))
+                .collect();
+            disks = current_disks;
+            let total = disk_rates.iter().position(|d| d.name == "_Total").map(|i| disk_rates.remove(i));
+            disk_rates.sort_by_key(|d| d.index);
+            if volumes_listed.elapsed() >= VOLUME_LIST_REFRESH {
+                volumes.refresh_list();
+                volumes_listed = Instant::now();
+            } else {
+                volumes.refresh();
+            }
+
+            let sample = UsageSample {
+                timestamp: now_millis(),
+                cpu_usage: ctx.sys.global_cpu_info().cpu_usage(),
+                cpu_cores: ctx.sys.cpus().iter().map(|c| c.cpu_usage()).collect(),
+                memory_used: ctx.sys.used_memory(),
+                memory_total: ctx.sys.total_memory(),
+                swap_used: ctx.sys.used_swap(),
+                swap_total: ctx.sys.total_swap(),
+                disk_read_bps: total.as_ref().map(|t| t.read_bps),
+                disk_write_bps: total.as_ref().map(|t| t.write_bps),
+                net_rx_bps: per_sec(rx),
+                net_tx_bps: per_sec(tx),
+                interfaces,
+                disks: disk_rates,
+                cpu_temp_c: if thermal { hardware::sensors::get_cpu_temperature(&ctx).unwrap_or(None) } else { None },
+                volumes: volumes.iter()
+                    .map(|d| VolumeUsage {
+                        volume: d.mount_point().to_string_lossy().trim_end_matches('\\').to_string(),
+                        total_bytes: d.total_space(),
+                        available_bytes: d.available_space(),
+                    })
+                    .collect(),
+            };
+            let metrics = sample.metrics();
+            let (fired, saved) = sampler.alerts.lock().unwrap().evaluate(sample.timestamp, &metrics);
+            if let Err(e) = saved {
+                sampler.report_error(format!("Failed to save alerts: {}", e));
+            }
+            for alert in fired {
+                sampler.log_event("alert", alert.message.clone());
+                app.emit(alerts::ALERT_EVENT, alert).unwrap_or(());
+            }
+            let closed = sampler.series.lock().unwrap().record(sample.timestamp, metrics);
+            if let Err(e) = sampler.store.lock().unwrap().append_metrics(closed) {
+                sampler.report_error(format!("Failed to store metrics: {}", e));
+            }
+            *sampler.latest.lock().unwrap() = Some(sample.clone());
+            app.emit(USAGE_EVENT, sample).unwrap_or(());
+        }
+    });
+}
+
+// Latest background sample; memory totals only until the first sample is in
+#[tauri::command]
+pub fn get_system_usage(sampler: tauri::State<'_, Sampler>) -> UsageSample {
+    if let Some(sample) = sampler.latest.lock().unwrap().clone() {
+        return sample;
+    }
+    let mut sys = sysinfo::System::new();
+    sys.refresh_memory();
+    UsageSample {
+        timestamp: now_millis(),
+        memory_used: sys.used_memory(),
+        memory_total: sys.total_memory(),
+        swap_used: sys.used_swap(),
+        swap_total: sys.total_swap(),
+        ..Default::default()
+    }
+}
+
+// Latest failed history write, None while every write succeeded
+#[tauri::command]
+pub fn get_sampler_error(sampler: tauri::State<'_, Sampler>) -> Option<SamplerError> {
+    sampler.last_error.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn get_sampling_interval(sampler: tauri::State<'_, Sampler>) -> u64 {
+    sampler.interval_ms.load(Ordering::Relaxed)
+}
+
+// Takes effect after the current wait; clamped to 250 ms - 60 s and kept across restarts
+#[tauri::command]
+pub fn set_sampling_interval(sampler: tauri::State<'_, Sampler>, interval_ms: u64) -> Result<u64, String> {
+    let interval_ms = interval_ms.clamp(MIN_INTERVAL_MS, MAX_INTERVAL_MS);
+    let json = serde_json::to_string_pretty(&SamplerConfig { interval_ms }).map_err(|e| e.to_string())?;
+    fs::write(CONFIG_FILE, json).map_err(|e| e.to_string())?;
+    sampler.interval_ms.store(interval_ms, Ordering::Relaxed);
+    Ok(interval_ms)
+}
+
+// Names accepted by `query_metric`
+#[tauri::command]
+pub fn list_metrics(sampler: tauri::State<'_, Sampler>) -> Vec<String> {
+    sampler.series.lock().unwrap().metrics()
+}
+
+// `from`/`to` in Unix milliseconds, the last 10 minutes by default. `resolution_ms` picks a tier
+// (1000, 60000 or 900000); without it the finest one covering `from` is used.
+#[tauri::command]
+pub fn query_metric(
+    sampler: tauri::State<'_, Sampler>,
+    metric: String,
+    from: Option<u64>,
+    to: Option<u64>,
+    resolution_ms: Option<u64>,
+) -> Result<series::SeriesQuery, String> {
+    let to = to.unwrap_or_else(now_millis);
+    let from = from.unwrap_or(to.saturating_sub(series::TIERS[0].0 * series::TIERS[0].1 as u64));
+    if from > to {
+        return Err("Range start is after its end".to_string());
+    }
+    sampler.series.lock().unwrap().query(&metric, from, to, resolution_ms)
+}
+
+// Events logged between `from` and `to` (Unix milliseconds), the last 24 hours by default
+#[tauri::command]
+pub fn list_events(sampler: tauri::State<'_, Sampler>, from: Option<u64>, to: Option<u64>) -> Vec<store::StoredEvent> {
+    let to = to.unwrap_or_else(now_millis);
+    let from = from.unwrap_or(to.saturating_sub(86_400_000));
+    sampler.store.lock().unwrap().events(from, to)
+}
+
+// Writes the one-minute history and events between `from` and `to` to a CSV file and returns its
+// full path. `metrics` limits the columns; `path` defaults to a file next to the app.
+#[tauri::command]
+pub fn export_metrics_csv(
+    sampler: tauri::State<'_, Sampler>,
+    from: u64,
+    to: u64,
+    metrics: Option<Vec<String>>,
+    path: Option<String>,
+) -> Result<String, String> {
+    if from > to {
+        return Err("Range start is after its end".to_string());
+    }
+    let path = std::path::PathBuf::from(path.unwrap_or_else(|| {
+        let day = |t: u64| crate::datetime::Timestamp::from_unix((t / 1000) as i64).to_date_string();
+        format!("neko233-hardware-viewer.metrics-{}-{}.csv", day(from), day(to))
+    }));
+    sampler.store.lock().unwrap().export_csv(from, to, metrics.as_deref(), &path)?;
+    Ok(fs::canonicalize(&path).unwrap_or(path).to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn list_alert_rules(sampler: tauri::State<'_, Sampler>) -> Vec<alerts::AlertRule> {
+    sampler.alerts.lock().unwrap().rules()
+}
+
+// Adds a rule (empty id) or replaces the rule with the same id
+#[tauri::command]
+pub fn save_alert_rule(sampler: tauri::State<'_, Sampler>, rule: alerts::AlertRule) -> Result<alerts::AlertRule, String> {
+    sampler.alerts.lock().unwrap().save_rule(rule, now_millis())
+}
+
+#[tauri::command]
+pub fn delete_alert_rule(sampler: tauri::State<'_, Sampler>, id: String) -> Result<(), String> {
+    sampler.alerts.lock().unwrap().delete_rule(&id, now_millis())
+}
+
+// Fired alerts, newest first
+#[tauri::command]
+pub fn get_alert_history(sampler: tauri::State<'_, Sampler>, limit: Option<usize>) -> Vec<alerts::FiredAlert> {
+    sampler.alerts.lock().unwrap().history(limit.unwrap_or(100))
+}
+
+#[tauri::command]
+pub fn clear_alert_history(sampler: tauri::State<'_, Sampler>) -> Result<(), String> {
+    sampler.alerts.lock().unwrap().clear_history()
+}
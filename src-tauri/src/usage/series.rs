@@ -0,0 +1,247 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+
+// In-memory time series of every sampled metric. Each metric keeps three ring buffers of
+// averaged buckets; every sample goes into all three, so the coarse tiers do not depend on the
+// fine ones still holding their data.
+
+// (bucket length in ms, buckets kept): 1 s for 10 minutes, 1 min for 24 hours, 15 min for 30 days
+pub const TIERS: [(u64, usize); 3] = [(1_000, 600), (60_000, 1_440), (900_000, 2_880)];
+// Tier whose finished buckets go to the on-disk history and come back from it at startup
+pub const PERSISTED_TIER: usize = 1;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    // Bucket start, Unix milliseconds
+    pub t: u64,
+    pub avg: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    start: u64,
+    sum: f64,
+    count: u32,
+    min: f64,
+    max: f64,
+}
+
+impl Point {
+    fn value(t: u64, value: f64) -> Self {
+        Point { t, avg: value, min: value, max: value }
+    }
+}
+
+impl Bucket {
+    fn new(start: u64, p: Point) -> Self {
+        Self { start, sum: p.avg, count: 1, min: p.min, max: p.max }
+    }
+
+    // Merged buckets count as one sample each, close enough for equally long buckets
+    fn add(&mut self, p: Point) {
+        self.sum += p.avg;
+        self.count += 1;
+        self.min = self.min.min(p.min);
+        self.max = self.max.max(p.max);
+    }
+
+    fn point(&self) -> Point {
+        Point { t: self.start, avg: self.sum / self.count as f64, min: self.min, max: self.max }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tier {
+    points: VecDeque<Point>,
+    // Bucket still collecting samples
+    open: Option<Bucket>,
+}
+
+impl Tier {
+    // Returns the bucket this point closed, if any
+    fn add(&mut self, (resolution, capacity): (u64, usize), p: Point) -> Option<Point> {
+        let start = p.t - p.t % resolution;
+        match &mut self.open {
+            // A clock set backwards keeps filling the open bucket instead of reordering the buffer
+            Some(bucket) if start <= bucket.start => {
+                bucket.add(p);
+                None
+            }
+            open => {
+                let done = open.replace(Bucket::new(start, p))?.point();
+                if self.points.len() == capacity {
+                    self.points.pop_front();
+                }
+                self.points.push_back(done);
+                Some(done)
+            }
+        }
+    }
+
+    // Buckets overlapping [from, to]
+    fn query(&self, resolution: u64, from: u64, to: u64) -> Vec<Point> {
+        self.points.iter()
+            .copied()
+            .chain(self.open.map(|b| b.point()))
+            .filter(|p| p.t + resolution > from && p.t <= to)
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Series {
+    tiers: [Tier; 3],
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SeriesQuery {
+    pub metric: String,
+    pub resolution_ms: u64,
+    pub points: Vec<Point>,
+}
+
+#[derive(Debug, Default)]
+pub struct SeriesStore {
+    series: BTreeMap<String, Series>,
+    latest: u64,
+}
+
+impl SeriesStore {
+    // Returns the buckets of the persisted tier that this sample closed
+    pub fn record(&mut self, t: u64, metrics: impl IntoIterator<Item = (String, f64)>) -> Vec<(String, Point)> {
+        self.latest = self.latest.max(t);
+        let mut closed = Vec::new();
+        for (name, value) in metrics {
+            if !value.is_finite() {
+                continue;
+            }
+            let series = self.series.entry(name.clone()).or_default();
+            for (index, (tier, spec)) in series.tiers.iter_mut().zip(TIERS).enumerate() {
+                if let Some(done) = tier.add(spec, Point::value(t, value)) {
+                    if index == PERSISTED_TIER {
+                        closed.push((name.clone(), done));
+                    }
+                }
+            }
+        }
+        closed
+    }
+
+    // A bucket of the persisted tier read back from disk; fills that tier and the coarser ones
+    pub fn restore(&mut self, name: &str, point: Point) {
+        self.latest = self.latest.max(point.t);
+        let series = self.series.entry(name.to_string()).or_default();
+        for (tier, spec) in series.tiers.iter_mut().zip(TIERS).skip(PERSISTED_TIER) {
+            tier.add(spec, point);
+        }
+    }
+
+    pub fn metrics(&self) -> Vec<String> {
+        self.series.keys().cloned().collect()
+    }
+
+    // `resolution_ms` must be one of the tier lengths; without it the finest tier still
+    // holding `from` answers
+    pub fn query(&self, metric: &str, from: u64, to: u64, resolution_ms: Option<u64>) -> Result<SeriesQuery, String> {
+        let series = self.series.get(metric).ok_or_else(|| format!("Unknown metric '{}'", metric))?;
+        let index = match resolution_ms {
+            Some(resolution) => TIERS.iter()
+                .position(|(r, _)| *r == resolution)
+                .ok_or_else(|| format!("Resolution must be one of {:?} ms", TIERS.map(|(r, _)| r)))?,
+            None => TIERS.iter()
+                .position(|(r, capacity)| self.latest.saturating_sub(r * *capacity as u64) <= from)
+                .unwrap_or(TIERS.len() - 1),
+        };
+        Ok(SeriesQuery {
+            metric: metric.to_string(),
+            resolution_ms: TIERS[index].0,
+            points: series.tiers[index].query(TIERS[index].0, from, to),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: (u64, usize) = TIERS[0];
+
+    #[test]
+    fn bucket_closes_on_its_boundary() {
+        let mut tier = Tier::default();
+        assert_eq!(tier.add(SECOND, Point::value(1_000, 1.0)), None);
+        assert_eq!(tier.add(SECOND, Point::value(1_999, 3.0)), None);
+        // First sample of the next second closes the previous bucket
+        let done = tier.add(SECOND, Point::value(2_000, 5.0)).unwrap();
+        assert_eq!(done, Point { t: 1_000, avg: 2.0, min: 1.0, max: 3.0 });
+        assert_eq!(tier.points.len(), 1);
+        assert_eq!(tier.open.unwrap().start, 2_000);
+    }
+
+    #[test]
+    fn full_buffer_drops_the_oldest_bucket() {
+        let mut tier = Tier::default();
+        let spec = (1_000, 3);
+        for i in 0..5 {
+            tier.add(spec, Point::value(i * 1_000, i as f64));
+        }
+        // Buckets 0..=3 are closed, only the last three are kept, 4 is still open
+        let kept: Vec<u64> = tier.points.iter().map(|p| p.t).collect();
+        assert_eq!(kept, vec![1_000, 2_000, 3_000]);
+        assert_eq!(tier.open.unwrap().start, 4_000);
+    }
+
+    #[test]
+    fn clock_going_backwards_fills_the_open_bucket() {
+        let mut tier = Tier::default();
+        tier.add(SECOND, Point::value(5_000, 2.0));
+        assert_eq!(tier.add(SECOND, Point::value(3_500, 4.0)), None);
+        assert!(tier.points.is_empty());
+        let open = tier.open.unwrap().point();
+        assert_eq!(open, Point { t: 5_000, avg: 3.0, min: 2.0, max: 4.0 });
+    }
+
+    fn store_until(end: u64) -> SeriesStore {
+        let mut store = SeriesStore::default();
+        let mut t = 0;
+        while t <= end {
+            store.record(t, [("cpu".to_string(), 10.0)]);
+            t += 30_000;
+        }
+        store
+    }
+
+    #[test]
+    fn query_picks_the_finest_tier_holding_the_range() {
+        let end = 2 * 24 * 3_600_000;
+        let store = store_until(end);
+        // Last 5 minutes fit in the 1 s tier
+        assert_eq!(store.query("cpu", end - 300_000, end, None).unwrap().resolution_ms, 1_000);
+        // Last hour is past 10 minutes, the 1 min tier holds it
+        assert_eq!(store.query("cpu", end - 3_600_000, end, None).unwrap().resolution_ms, 60_000);
+        // Two days back is past 24 hours
+        assert_eq!(store.query("cpu", 0, end, None).unwrap().resolution_ms, 900_000);
+    }
+
+    #[test]
+    fn query_with_explicit_resolution() {
+        let store = store_until(3_600_000);
+        let query = store.query("cpu", 0, 3_600_000, Some(60_000)).unwrap();
+        assert_eq!(query.resolution_ms, 60_000);
+        // 60 closed minutes plus the open one
+        assert_eq!(query.points.len(), 61);
+        assert!(store.query("cpu", 0, 3_600_000, Some(5_000)).is_err());
+        assert!(store.query("gpu", 0, 3_600_000, None).is_err());
+    }
+
+    #[test]
+    fn record_returns_closed_persisted_buckets() {
+        let mut store = SeriesStore::default();
+        assert!(store.record(0, [("cpu".to_string(), 1.0)]).is_empty());
+        assert!(store.record(59_000, [("cpu".to_string(), f64::NAN)]).is_empty());
+        let closed = store.record(60_000, [("cpu".to_string(), 3.0)]);
+        assert_eq!(closed, vec![("cpu".to_string(), Point { t: 0, avg: 1.0, min: 1.0, max: 1.0 })]);
+    }
+}
@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use super::series::{Point, SeriesStore};
+use crate::datetime::Timestamp;
+
+// On-disk history of the sampler: one JSON Lines file per UTC day holding the finished
+// one-minute buckets of every metric plus events (alerts, app starts). Lines are only ever
+// appended and synced one at a time, so a crash can at worst leave a torn last line, which
+// `repair` cuts off before the file is appended to again.
+
+pub const STORE_DIR: &str = "neko233-hardware-viewer.metrics";
+// Matches the coarsest in-memory tier
+pub const RETENTION_DAYS: i64 = 30;
+// Oldest days are dropped first past this
+const MAX_STORE_BYTES: u64 = 256 * 1024 * 1024;
+const DAY_MS: u64 = 86_400_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StoredEvent {
+    // Unix milliseconds
+    pub t: u64,
+    pub kind: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Record {
+    // Metric name -> [avg, min, max] of the minute starting at `t`
+    Metrics { t: u64, values: BTreeMap<String, [f64; 3]> },
+    Event(StoredEvent),
+}
+
+impl Record {
+    fn t(&self) -> u64 {
+        match self {
+            Record::Metrics { t, .. } => *t,
+            Record::Event(event) => event.t,
+        }
+    }
+}
+
+pub struct MetricStore {
+    dir: PathBuf,
+    // Minutes up to here were read back from disk; their buckets must not be written twice
+    restored_until: Option<u64>,
+    // Day file already checked for a torn tail in this session
+    repaired_day: Option<String>,
+}
+
+impl Default for MetricStore {
+    fn default() -> Self {
+        Self { dir: PathBuf::from(STORE_DIR), restored_until: None, repaired_day: None }
+    }
+}
+
+fn day_name(t: u64) -> String {
+    Timestamp::from_unix((t / 1000) as i64).to_date_string()
+}
+
+// Drops everything after the last complete line
+fn repair(path: &Path) -> std::io::Result<()> {
+    let mut file = match OpenOptions::new().read(true).write(true).open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(());
+    }
+    let mut last = [0u8];
+    file.seek(SeekFrom::End(-1))?;
+    file.read_exact(&mut last)?;
+    if last[0] == b'\n' {
+        return Ok(());
+    }
+    let mut content = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut content)?;
+    let keep = content.iter().rposition(|b| *b == b'\n').map(|i| i + 1).unwrap_or(0);
+    file.set_len(keep as u64)?;
+    file.sync_all()
+}
+
+impl MetricStore {
+    fn day_file(&self, day: &str) -> PathBuf {
+        self.dir.join(format!("{}.jsonl", day))
+    }
+
+    // Day files, oldest first
+    fn days(&self) -> Vec<(String, u64)> {
+        let mut days: Vec<(String, u64)> = fs::read_dir(&self.dir)
+            .map(|entries| entries.filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let name = e.file_name().to_string_lossy().strip_suffix(".jsonl")?.to_string();
+                    Some((name, e.metadata().ok()?.len()))
+                })
+                .collect())
+            .unwrap_or_default();
+        days.sort();
+        days
+    }
+
+    fn enforce_retention(&self, now: u64) {
+        let oldest = day_name(now.saturating_sub(RETENTION_DAYS as u64 * DAY_MS));
+        let days = self.days();
+        let mut total: u64 = days.iter().map(|(_, size)| size).sum();
+        for (day, size) in days {
+            if (day < oldest || total > MAX_STORE_BYTES) && fs::remove_file(self.day_file(&day)).is_ok() {
+                total -= size;
+            }
+        }
+    }
+
+    fn append(&mut self, record: &Record) -> Result<(), String> {
+        let t = record.t();
+        let day = day_name(t);
+        let path = self.day_file(&day);
+        if self.repaired_day.as_deref() != Some(day.as_str()) {
+            fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+            repair(&path).map_err(|e| e.to_string())?;
+            // A new day is a good moment to drop old ones
+            self.enforce_retention(t);
+            self.repaired_day = Some(day);
+        }
+        let mut line = serde_json::to_vec(record).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+        let mut file = OpenOptions::new().create(true).append(true).open(&path).map_err(|e| e.to_string())?;
+        // One write per line, synced before the next one starts
+        file.write_all(&line).map_err(|e| e.to_string())?;
+        file.sync_data().map_err(|e| e.to_string())
+    }
+
+    // Buckets closed by `SeriesStore::record`, grouped into one line per minute
+    pub fn append_metrics(&mut self, closed: Vec<(String, Point)>) -> Result<(), String> {
+        let mut minutes: BTreeMap<u64, BTreeMap<String, [f64; 3]>> = BTreeMap::new();
+        for (name, p) in closed {
+            if self.restored_until.is_some_and(|until| p.t <= until) {
+                continue;
+            }
+            minutes.entry(p.t).or_default().insert(name, [p.avg, p.min, p.max]);
+        }
+        for (t, values) in minutes {
+            self.append(&Record::Metrics { t, values })?;
+        }
+        Ok(())
+    }
+
+    pub fn append_event(&mut self, event: StoredEvent) -> Result<(), String> {
+        self.append(&Record::Event(event))
+    }
+
+    // Records of the day files overlapping [from, to], unreadable lines skipped
+    fn read(&self, from: u64, to: u64, mut visit: impl FnMut(Record)) {
+        let (first, last) = (day_name(from), day_name(to));
+        for (day, _) in self.days().into_iter().filter(|(day, _)| *day >= first && *day <= last) {
+            let Ok(file) = File::open(self.day_file(&day)) else { continue };
+            for line in BufReader::new(file).lines().map_while(Result::ok) {
+                if let Ok(record) = serde_json::from_str::<Record>(&line) {
+                    if record.t() >= from && record.t() <= to {
+                        visit(record);
+                    }
+                }
+            }
+        }
+    }
+
+    // Startup: drops expired days and refills the minute and 15-minute tiers
+    pub fn load(&mut self, series: &mut SeriesStore, now: u64) {
+        self.enforce_retention(now);
+        let mut until = None;
+        self.read(now.saturating_sub(RETENTION_DAYS as u64 * DAY_MS), now, |record| {
+            if let Record::Metrics { t, values } = record {
+                for (name, [avg, min, max]) in values {
+                    series.restore(&name, Point { t, avg, min, max });
+                }
+                until = until.max(Some(t));
+            }
+        });
+        self.restored_until = until;
+    }
+
+    pub fn events(&self, from: u64, to: u64) -> Vec<StoredEvent> {
+        let mut events = Vec::new();
+        self.read(from, to, |record| {
+            if let Record::Event(event) = record {
+                events.push(event);
+            }
+        });
+        events
+    }
+
+    // Long format, one row per metric and minute plus one per event, in time order; returns the
+    // rows written. Nothing is written when the range holds no rows.
+    pub fn export_csv(&self, from: u64, to: u64, metrics: Option<&[String]>, path: &Path) -> Result<usize, String> {
+        // Minutes are written when they end, so events can come before the minute they fall in
+        let mut rows: Vec<(u64, String)> = Vec::new();
+        self.read(from, to, |record| {
+            let time = Timestamp::from_unix((record.t() / 1000) as i64).to_iso8601();
+            match record {
+                Record::Metrics { t, values } => {
+                    for (name, [avg, min, max]) in values {
+                        if metrics.is_none_or(|m| m.contains(&name)) {
+                            rows.push((t, format!("{},{},{:.2},{:.2},{:.2},", time, csv_quote(&name), avg, min, max)));
+                        }
+                    }
+                }
+                Record::Event(event) => {
+                    rows.push((event.t, format!("{},event.{},,,,{}", time, event.kind, csv_quote(&event.message))));
+                }
+            }
+        });
+        if rows.is_empty() {
+            return Err("No history recorded in this range".to_string());
+        }
+        rows.sort_by_key(|(t, _)| *t);
+        let mut out = String::from("time,metric,avg,min,max,event\n");
+        for (_, row) in &rows {
+            out.push_str(row);
+            out.push('\n');
+        }
+        fs::write(path, out).map_err(|e| e.to_string())?;
+        Ok(rows.len())
+    }
+}
+
+// Metric names carry user-editable adapter aliases and event messages free text, both may hold
+// commas or quotes
+fn csv_quote(field: &str) -> String {
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(name: &str) -> MetricStore {
+        let dir = std::env::temp_dir().join(format!("neko233-hardware-viewer.metrics-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        MetricStore { dir, restored_until: None, repaired_day: None }
+    }
+
+    #[test]
+    fn csv_quotes_names_and_messages() {
+        let mut store = store("quotes");
+        let point = Point { t: 60_000, avg: 1.0, min: 0.5, max: 2.0 };
+        store.append_metrics(vec![("net_rx_bps.Office, 2.4 GHz".to_string(), point)]).unwrap();
+        store.append_event(StoredEvent { t: 90_000, kind: "alert".to_string(), message: "CPU \"hot\", 95 °C".to_string() }).unwrap();
+        let path = store.dir.join("export.csv");
+        assert_eq!(store.export_csv(0, 120_000, None, &path), Ok(2));
+        let csv = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "1970-01-01T00:01:00Z,\"net_rx_bps.Office, 2.4 GHz\",1.00,0.50,2.00,");
+        assert_eq!(lines[2], "1970-01-01T00:01:30Z,event.alert,,,,\"CPU \"\"hot\"\", 95 °C\"");
+        let _ = fs::remove_dir_all(&store.dir);
+    }
+
+    #[test]
+    fn empty_range_writes_nothing() {
+        let mut store = store("empty");
+        store.append_metrics(vec![("cpu".to_string(), Point { t: 60_000, avg: 1.0, min: 1.0, max: 1.0 })]).unwrap();
+        let path = store.dir.join("export.csv");
+        assert!(store.export_csv(DAY_MS, 2 * DAY_MS, None, &path).is_err());
+        assert!(!path.exists());
+        let _ = fs::remove_dir_all(&store.dir);
+    }
+}
@@ -0,0 +1,392 @@
+use serde::Serialize;
+use crate::hardware::{self, HardwareContext};
+use crate::hardware::cpu::CpuInfo;
+use crate::hardware::firmware::FirmwareInfo;
+use crate::model_name::ModelName;
+
+const GB: u64 = 1024 * 1024 * 1024;
+const MIN_RAM_BYTES: u64 = 4 * GB;
+// Storage is sold in decimal units, a "64 GB" drive reports about 59.6 GiB
+const MIN_STORAGE_BYTES: u64 = 64 * 1000 * 1000 * 1000;
+const MIN_CPU_CORES: u32 = 2;
+const MIN_CPU_CLOCK_MHZ: u32 = 1000;
+// WDDM 2.0 is the first DirectX 12 capable driver model
+const MIN_WDDM_MAJOR: u32 = 2;
+
+// Supported CPU rules, condensed from Microsoft's published processor lists
+const INTEL_CORE_MIN_GEN: u32 = 8;
+// AMD: first supported Ryzen series, with Zen 1 based parts of that series excluded below
+const AMD_RYZEN_MIN_SERIES: u32 = 2;
+const AMD_RYZEN_2000_UNSUPPORTED_SUFFIXES: [&str; 3] = ["G", "GE", "U"];
+const AMD_THREADRIPPER_MIN_SERIES: u32 = 2;
+// Brands where every model is on the list
+const ALWAYS_SUPPORTED_BRANDS: [&str; 3] = ["CORE(TM) ULTRA", "CORE ULTRA", "SNAPDRAGON"];
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct RequirementResult {
+    pub id: String,
+    pub name: String,
+    pub status: CheckStatus,
+    pub required: String,
+    pub found: String,
+    pub reason: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Win11Report {
+    pub status: CheckStatus,
+    pub requirements: Vec<RequirementResult>,
+}
+
+// Everything the rules look at. Collected from the live system by collect_snapshot(),
+// but can be filled in by hand to evaluate any other machine.
+#[derive(Debug, Clone, Default)]
+pub struct Win11Snapshot {
+    pub cpu: Option<CpuInfo>,
+    pub ram_total_bytes: Option<u64>,
+    pub system_disk_bytes: Option<u64>,
+    pub firmware: FirmwareInfo,
+    pub gpu_driver_versions: Vec<String>,
+}
+
+fn requirement(id: &str, name: &str, status: CheckStatus, required: &str, found: String, reason: String) -> RequirementResult {
+    RequirementResult {
+        id: id.to_string(),
+        name: name.to_string(),
+        status,
+        required: required.to_string(),
+        found,
+        reason,
+    }
+}
+
+fn format_gb(bytes: u64) -> String {
+    format!("{:.1} GB", bytes as f64 / GB as f64)
+}
+
+// Returns Some(true/false) when the model is recognized, None when it is not on any rule
+pub fn is_supported_cpu(name: &str) -> Option<bool> {
+    let upper = name.to_uppercase();
+
+    if ALWAYS_SUPPORTED_BRANDS.iter().any(|b| upper.contains(b)) {
+        return Some(true);
+    }
+
+    let parsed = ModelName::parse(name);
+    let series: Vec<&str> = parsed.series.split_whitespace().collect();
+    let model = parsed.model.as_deref().unwrap_or_default();
+    let numeric_model = !model.is_empty() && model.chars().all(|c| c.is_ascii_digit());
+    let suffix = parsed.suffixes.first().map(|s| s.as_str()).unwrap_or_default();
+
+    // Intel Core iX-NNNN / iX-NNNNN
+    if series.first() == Some(&"Core") && numeric_model {
+        if let Some(tier) = series.get(1) {
+            if tier.starts_with('i') {
+                let generation = match model.len() {
+                    5 => model[..2].parse::<u32>().ok(),
+                    4 => model[..1].parse::<u32>().ok(),
+                    // 3 digit models (e.g. i7-920) are first generation
+                    3 => Some(1),
+                    _ => None,
+                };
+                if let Some(gen) = generation {
+                    return Some(gen >= INTEL_CORE_MIN_GEN);
+                }
+            } else if tier.len() == 1 && tier.chars().all(|c| c.is_ascii_digit()) {
+                // New Intel naming without the "i": "Core(TM) 5 120U"
+                return Some(true);
+            }
+        }
+    }
+
+    // AMD Ryzen / Ryzen Threadripper: the first digit of the model is the series
+    if series.first() == Some(&"Ryzen") && numeric_model && model.len() == 4 {
+        let ryzen_series = model[..1].parse::<u32>().unwrap_or(0);
+        if series.contains(&"Threadripper") {
+            return Some(ryzen_series >= AMD_THREADRIPPER_MIN_SERIES);
+        }
+        if ryzen_series == 2 && AMD_RYZEN_2000_UNSUPPORTED_SUFFIXES.contains(&suffix) {
+            return Some(false);
+        }
+        return Some(ryzen_series >= AMD_RYZEN_MIN_SERIES);
+    }
+
+    // AMD Athlon Gold/Silver 3000 and newer
+    if series.first() == Some(&"Athlon") && numeric_model && model.len() == 4 {
+        return Some(model[..1].parse::<u32>().unwrap_or(0) >= 3);
+    }
+
+    None
+}
+
+fn check_cpu(cpu: Option<&CpuInfo>) -> RequirementResult {
+    let required = "1 GHz, 2+ cores, on the supported CPU list";
+    let cpu = match cpu {
+        Some(cpu) => cpu,
+        None => return requirement("cpu", "Processor", CheckStatus::Unknown, required, "Unknown".to_string(), "Processor information unavailable".to_string()),
+    };
+
+    let found = format!("{} ({} cores, {} MHz)", cpu.name.trim(), cpu.number_of_cores, cpu.max_clock_speed);
+
+    if cpu.number_of_cores > 0 && cpu.number_of_cores < MIN_CPU_CORES {
+        return requirement("cpu", "Processor", CheckStatus::Fail, required, found, format!("Only {} core(s), at least {} required", cpu.number_of_cores, MIN_CPU_CORES));
+    }
+    if cpu.max_clock_speed > 0 && cpu.max_clock_speed < MIN_CPU_CLOCK_MHZ {
+        return requirement("cpu", "Processor", CheckStatus::Fail, required, found, format!("Clock speed {} MHz is below 1 GHz", cpu.max_clock_speed));
+    }
+
+    match is_supported_cpu(&cpu.name) {
+        Some(true) => requirement("cpu", "Processor", CheckStatus::Pass, required, found, "Processor is on the supported list".to_string()),
+        Some(false) => requirement("cpu", "Processor", CheckStatus::Fail, required, found, "Processor generation is not on the supported list".to_string()),
+        None => requirement("cpu", "Processor", CheckStatus::Unknown, required, found, "Processor model not recognized, check Microsoft's list manually".to_string()),
+    }
+}
+
+fn check_ram(total: Option<u64>) -> RequirementResult {
+    let required = "4 GB";
+    match total {
+        Some(bytes) if bytes >= MIN_RAM_BYTES => requirement("ram", "Memory", CheckStatus::Pass, required, format_gb(bytes), "Enough memory installed".to_string()),
+        Some(bytes) => requirement("ram", "Memory", CheckStatus::Fail, required, format_gb(bytes), format!("{} installed, 4 GB required", format_gb(bytes))),
+        None => requirement("ram", "Memory", CheckStatus::Unknown, required, "Unknown".to_string(), "Installed memory could not be read".to_string()),
+    }
+}
+
+fn check_storage(size: Option<u64>) -> RequirementResult {
+    let required = "64 GB system drive";
+    match size {
+        Some(bytes) if bytes >= MIN_STORAGE_BYTES => requirement("storage", "Storage", CheckStatus::Pass, required, format_gb(bytes), "System drive is large enough".to_string()),
+        Some(bytes) => requirement("storage", "Storage", CheckStatus::Fail, required, format_gb(bytes), format!("System drive is {}, 64 GB required", format_gb(bytes))),
+        None => requirement("storage", "Storage", CheckStatus::Unknown, required, "Unknown".to_string(), "System drive size could not be read".to_string()),
+    }
+}
+
+fn check_tpm(fw: &FirmwareInfo) -> RequirementResult {
+    let required = "TPM 2.0";
+    if fw.tpm_present == Some(false) {
+        return requirement("tpm", "TPM", CheckStatus::Fail, required, "Not present".to_string(), "No TPM found, it may be disabled in the BIOS (PTT / fTPM)".to_string());
+    }
+    let version = match &fw.tpm_version {
+        Some(v) => v,
+        None => return requirement("tpm", "TPM", CheckStatus::Unknown, required, "Unknown".to_string(), "TPM version could not be read (requires administrator)".to_string()),
+    };
+    let major = version.split('.').next().and_then(|v| v.trim().parse::<u32>().ok()).unwrap_or(0);
+    let found = format!("TPM {}", version);
+    if major < 2 {
+        return requirement("tpm", "TPM", CheckStatus::Fail, required, found, format!("TPM {} is too old, 2.0 required", version));
+    }
+    if fw.tpm_enabled == Some(false) {
+        return requirement("tpm", "TPM", CheckStatus::Fail, required, found, "TPM 2.0 is present but disabled".to_string());
+    }
+    requirement("tpm", "TPM", CheckStatus::Pass, required, found, "TPM 2.0 available".to_string())
+}
+
+fn check_uefi(fw: &FirmwareInfo) -> RequirementResult {
+    let required = "UEFI";
+    match fw.uefi {
+        Some(true) => requirement("uefi", "Firmware", CheckStatus::Pass, required, "UEFI".to_string(), "System boots in UEFI mode".to_string()),
+        Some(false) => requirement("uefi", "Firmware", CheckStatus::Fail, required, "Legacy BIOS".to_string(), "System boots in Legacy/CSM mode, convert the disk to GPT and switch to UEFI".to_string()),
+        None => requirement("uefi", "Firmware", CheckStatus::Unknown, required, "Unknown".to_string(), "Firmware type could not be read".to_string()),
+    }
+}
+
+fn check_secure_boot(fw: &FirmwareInfo) -> RequirementResult {
+    let required = "Secure Boot capable";
+    match (fw.secure_boot, fw.uefi) {
+        (Some(true), _) => requirement("secure_boot", "Secure Boot", CheckStatus::Pass, required, "Enabled".to_string(), "Secure Boot is enabled".to_string()),
+        // Only "capable" is required, the setup does not demand it to be switched on
+        (Some(false), _) => requirement("secure_boot", "Secure Boot", CheckStatus::Pass, required, "Disabled".to_string(), "Secure Boot is supported but currently disabled".to_string()),
+        (None, Some(false)) => requirement("secure_boot", "Secure Boot", CheckStatus::Fail, required, "Not supported".to_string(), "Secure Boot requires UEFI mode".to_string()),
+        (None, _) => requirement("secure_boot", "Secure Boot", CheckStatus::Unknown, required, "Unknown".to_string(), "Secure Boot state could not be read".to_string()),
+    }
+}
+
+// Windows display driver versions encode the WDDM version in the first field:
+// 20.x = WDDM 2.0, 27.x = WDDM 2.7, 30.x = WDDM 3.0, 31.x = WDDM 3.1 ...
+pub fn wddm_version_from_driver(driver_version: &str) -> Option<(u32, u32)> {
+    let first = driver_version.split('.').next()?.trim().parse::<u32>().ok()?;
+    match first {
+        10..=19 => Some((1, first - 10)),
+        20..=29 => Some((2, first - 20)),
+        30..=39 => Some((3, first - 30)),
+        _ => None,
+    }
+}
+
+fn check_gpu(driver_versions: &[String]) -> RequirementResult {
+    let required = "DirectX 12, WDDM 2.0";
+    let best = driver_versions.iter().filter_map(|v| wddm_version_from_driver(v)).max();
+    match best {
+        Some((major, minor)) if major >= MIN_WDDM_MAJOR => requirement("gpu", "Graphics", CheckStatus::Pass, required, format!("WDDM {}.{}", major, minor), "Display driver supports DirectX 12".to_string()),
+        Some((major, minor)) => requirement("gpu", "Graphics", CheckStatus::Fail, required, format!("WDDM {}.{}", major, minor), "Display driver is older than WDDM 2.0, update the driver or GPU".to_string()),
+        None => requirement("gpu", "Graphics", CheckStatus::Unknown, required, "Unknown".to_string(), "Display driver model could not be determined".to_string()),
+    }
+}
+
+pub fn evaluate(snapshot: &Win11Snapshot) -> Win11Report {
+    let requirements = vec![
+        check_cpu(snapshot.cpu.as_ref()),
+        check_ram(snapshot.ram_total_bytes),
+        check_storage(snapshot.system_disk_bytes),
+        check_tpm(&snapshot.firmware),
+        check_uefi(&snapshot.firmware),
+        check_secure_boot(&snapshot.firmware),
+        check_gpu(&snapshot.gpu_driver_versions),
+    ];
+
+    let status = if requirements.iter().any(|r| r.status == CheckStatus::Fail) {
+        CheckStatus::Fail
+    } else if requirements.iter().any(|r| r.status == CheckStatus::Unknown) {
+        CheckStatus::Unknown
+    } else {
+        CheckStatus::Pass
+    };
+
+    Win11Report { status, requirements }
+}
+
+pub fn collect_snapshot() -> anyhow::Result<Win11Snapshot> {
+    let mut ctx = HardwareContext::new();
+    ctx.init_wmi()?;
+
+    let cpu = hardware::cpu::get_cpu_info(&mut ctx).ok().and_then(|c| c.into_iter().next());
+
+    let ram_total_bytes = hardware::memory::get_installed_memory_bytes(&mut ctx);
+
+    let system_disk_bytes = hardware::disk::get_system_volume_size(&ctx).unwrap_or(None);
+    let firmware = hardware::firmware::get_firmware_info(&ctx).unwrap_or_default();
+    let gpu_driver_versions = hardware::gpu::get_gpu_info(&ctx)
+        .map(|gpus| gpus.into_iter().map(|g| g.driver_version).collect())
+        .unwrap_or_default();
+
+    Ok(Win11Snapshot {
+        cpu,
+        ram_total_bytes,
+        system_disk_bytes,
+        firmware,
+        gpu_driver_versions,
+    })
+}
+
+#[tauri::command]
+pub fn check_win11_readiness() -> Result<Win11Report, String> {
+    let snapshot = collect_snapshot().map_err(|e| e.to_string())?;
+    Ok(evaluate(&snapshot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cpu(name: &str) -> CpuInfo {
+        CpuInfo {
+            name: name.to_string(),
+            max_clock_speed: 3000,
+            number_of_cores: 6,
+            number_of_logical_processors: 12,
+            manufacturer: String::new(),
+            l2_cache_size: None,
+            l3_cache_size: None,
+            socket_designation: None,
+            description: None,
+            virtualization_firmware_enabled: None,
+        }
+    }
+
+    // A machine that meets every requirement
+    fn supported() -> Win11Snapshot {
+        Win11Snapshot {
+            cpu: Some(cpu("Intel(R) Core(TM) i5-8400 CPU @ 2.80GHz")),
+            ram_total_bytes: Some(16 * GB),
+            system_disk_bytes: Some(512 * GB),
+            firmware: FirmwareInfo {
+                uefi: Some(true),
+                secure_boot: Some(true),
+                tpm_present: Some(true),
+                tpm_enabled: Some(true),
+                tpm_version: Some("2.0, 0, 1.38".to_string()),
+            },
+            gpu_driver_versions: vec!["31.0.101.4502".to_string()],
+        }
+    }
+
+    fn status_of(report: &Win11Report, id: &str) -> CheckStatus {
+        report.requirements.iter().find(|r| r.id == id).unwrap().status
+    }
+
+    #[test]
+    fn intel_core_generations() {
+        assert_eq!(is_supported_cpu("Intel(R) Core(TM) i7-7700 CPU @ 3.60GHz"), Some(false));
+        assert_eq!(is_supported_cpu("Intel(R) Core(TM) i5-8400 CPU @ 2.80GHz"), Some(true));
+        assert_eq!(is_supported_cpu("Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz"), Some(true));
+        assert_eq!(is_supported_cpu("Intel(R) Core(TM) Ultra 7 155H"), Some(true));
+    }
+
+    #[test]
+    fn amd_ryzen_series() {
+        assert_eq!(is_supported_cpu("AMD Ryzen 5 2600 Six-Core Processor"), Some(true));
+        // Zen 1 based mobile part sold as a 2000 series
+        assert_eq!(is_supported_cpu("AMD Ryzen 5 2500U with Radeon Vega Mobile Gfx"), Some(false));
+        assert_eq!(is_supported_cpu("AMD Ryzen 7 1700X Eight-Core Processor"), Some(false));
+        assert_eq!(is_supported_cpu("AMD Athlon Silver 3050U"), Some(true));
+        assert_eq!(is_supported_cpu("Some Unknown CPU"), None);
+    }
+
+    #[test]
+    fn supported_machine_passes() {
+        let report = evaluate(&supported());
+        assert_eq!(report.status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn unsupported_cpu_fails() {
+        let mut snapshot = supported();
+        snapshot.cpu = Some(cpu("Intel(R) Core(TM) i7-7700 CPU @ 3.60GHz"));
+        let report = evaluate(&snapshot);
+        assert_eq!(status_of(&report, "cpu"), CheckStatus::Fail);
+        assert_eq!(report.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn tpm_1_2_fails() {
+        let mut snapshot = supported();
+        snapshot.firmware.tpm_version = Some("1.2, 2, 3".to_string());
+        let report = evaluate(&snapshot);
+        assert_eq!(status_of(&report, "tpm"), CheckStatus::Fail);
+        assert_eq!(report.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn legacy_bios_fails_uefi_and_secure_boot() {
+        let mut snapshot = supported();
+        snapshot.firmware.uefi = Some(false);
+        snapshot.firmware.secure_boot = None;
+        let report = evaluate(&snapshot);
+        assert_eq!(status_of(&report, "uefi"), CheckStatus::Fail);
+        assert_eq!(status_of(&report, "secure_boot"), CheckStatus::Fail);
+        assert_eq!(report.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn missing_data_is_unknown() {
+        let mut snapshot = supported();
+        snapshot.firmware.tpm_version = None;
+        assert_eq!(evaluate(&snapshot).status, CheckStatus::Unknown);
+    }
+
+    #[test]
+    fn wddm_from_driver_version() {
+        assert_eq!(wddm_version_from_driver("9.18.13.4192"), None);
+        assert_eq!(wddm_version_from_driver("10.18.13.6881"), Some((1, 0)));
+        assert_eq!(wddm_version_from_driver("20.19.15.4531"), Some((2, 0)));
+        assert_eq!(wddm_version_from_driver("27.21.14.5671"), Some((2, 7)));
+        assert_eq!(wddm_version_from_driver("31.0.101.4502"), Some((3, 1)));
+        assert_eq!(wddm_version_from_driver("not a version"), None);
+    }
+}
@@ -30,3 +30,13 @@ export const getDriverLink = (manufacturer: string, productName: string): string
   
   return null;
 };
+
+// Prefer the vendor the backend derived from the model name (e.g. "GeForce RTX 3060" -> Nvidia),
+// WMI manufacturer strings are often generic ("Standard display types", "GenuineIntel")
+export const getDriverLinkForModel = (model: { Vendor?: string } | undefined, manufacturer: string, productName: string): string | null => {
+  if (model?.Vendor && model.Vendor !== 'Unknown') {
+    const link = getDriverLink(model.Vendor, productName);
+    if (link) return link;
+  }
+  return getDriverLink(manufacturer, productName);
+};
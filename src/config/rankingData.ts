@@ -1,201 +0,0 @@
-export const cpuRanking = [
-  // --- AMD Ryzen 9000 Series ---
-  { name: "AMD Ryzen 9 9950X", score: 67000, hasIGPU: true },
-  { name: "AMD Ryzen 9 9900X", score: 58000, hasIGPU: true },
-  { name: "AMD Ryzen 7 9700X", score: 48000, hasIGPU: true },
-  { name: "AMD Ryzen 5 9600X", score: 38000, hasIGPU: true },
-
-  // --- Intel 14th Gen ---
-  { name: "Intel Core i9-14900KS", score: 65000, hasIGPU: true },
-  { name: "Intel Core i9-14900K", score: 62000, hasIGPU: true },
-  { name: "Intel Core i9-14900KF", score: 62000, hasIGPU: false },
-  { name: "Intel Core i9-14900F", score: 59000, hasIGPU: false },
-  { name: "Intel Core i9-14900", score: 59000, hasIGPU: true },
-  { name: "Intel Core i7-14700K", score: 51000, hasIGPU: true },
-  { name: "Intel Core i7-14700KF", score: 51000, hasIGPU: false },
-  { name: "Intel Core i7-14700F", score: 48000, hasIGPU: false },
-  { name: "Intel Core i7-14700", score: 48000, hasIGPU: true },
-  { name: "Intel Core i5-14600K", score: 39000, hasIGPU: true },
-  { name: "Intel Core i5-14600KF", score: 39000, hasIGPU: false },
-  { name: "Intel Core i5-14500", score: 34000, hasIGPU: true },
-  { name: "Intel Core i5-14400F", score: 28000, hasIGPU: false },
-  { name: "Intel Core i5-14400", score: 28000, hasIGPU: true },
-  { name: "Intel Core i3-14100F", score: 16000, hasIGPU: false },
-  { name: "Intel Core i3-14100", score: 16000, hasIGPU: true },
-
-  // --- AMD Ryzen 7000 Series ---
-  { name: "AMD Ryzen 9 7950X3D", score: 63500, hasIGPU: true },
-  { name: "AMD Ryzen 9 7950X", score: 62500, hasIGPU: true },
-  { name: "AMD Ryzen 9 7900X3D", score: 54000, hasIGPU: true },
-  { name: "AMD Ryzen 9 7900X", score: 52000, hasIGPU: true },
-  { name: "AMD Ryzen 9 7900", score: 48000, hasIGPU: true },
-  { name: "AMD Ryzen 7 7800X3D", score: 36000, hasIGPU: true },
-  { name: "AMD Ryzen 7 7700X", score: 36000, hasIGPU: true },
-  { name: "AMD Ryzen 7 7700", score: 34000, hasIGPU: true },
-  { name: "AMD Ryzen 5 7600X", score: 29000, hasIGPU: true },
-  { name: "AMD Ryzen 5 7600", score: 28000, hasIGPU: true },
-  { name: "AMD Ryzen 5 7500F", score: 27000, hasIGPU: false },
-
-  // --- Intel 13th Gen ---
-  { name: "Intel Core i9-13900KS", score: 63000, hasIGPU: true },
-  { name: "Intel Core i9-13900K", score: 60000, hasIGPU: true },
-  { name: "Intel Core i9-13900KF", score: 60000, hasIGPU: false },
-  { name: "Intel Core i9-13900F", score: 56000, hasIGPU: false },
-  { name: "Intel Core i9-13900", score: 56000, hasIGPU: true },
-  { name: "Intel Core i7-13700K", score: 48000, hasIGPU: true },
-  { name: "Intel Core i7-13700KF", score: 48000, hasIGPU: false },
-  { name: "Intel Core i7-13700F", score: 45000, hasIGPU: false },
-  { name: "Intel Core i7-13700", score: 45000, hasIGPU: true },
-  { name: "Intel Core i5-13600K", score: 38000, hasIGPU: true },
-  { name: "Intel Core i5-13600KF", score: 38000, hasIGPU: false },
-  { name: "Intel Core i5-13500", score: 33000, hasIGPU: true },
-  { name: "Intel Core i5-13490F", score: 28500, hasIGPU: false },
-  { name: "Intel Core i5-13400F", score: 27000, hasIGPU: false },
-  { name: "Intel Core i5-13400", score: 27000, hasIGPU: true },
-  { name: "Intel Core i3-13100F", score: 15000, hasIGPU: false },
-  { name: "Intel Core i3-13100", score: 15000, hasIGPU: true },
-
-  // --- AMD Ryzen 5000 Series ---
-  { name: "AMD Ryzen 9 5950X", score: 46000, hasIGPU: false },
-  { name: "AMD Ryzen 9 5900X", score: 39000, hasIGPU: false },
-  { name: "AMD Ryzen 7 5800X3D", score: 29000, hasIGPU: false },
-  { name: "AMD Ryzen 7 5800X", score: 28000, hasIGPU: false },
-  { name: "AMD Ryzen 7 5700X", score: 27000, hasIGPU: false },
-  { name: "AMD Ryzen 7 5700G", score: 26000, hasIGPU: true },
-  { name: "AMD Ryzen 5 5600X", score: 22000, hasIGPU: false },
-  { name: "AMD Ryzen 5 5600", score: 21500, hasIGPU: false },
-  { name: "AMD Ryzen 5 5600G", score: 20000, hasIGPU: true },
-  { name: "AMD Ryzen 5 5500", score: 19000, hasIGPU: false },
-
-  // --- Intel 12th Gen ---
-  { name: "Intel Core i9-12900KS", score: 43000, hasIGPU: true },
-  { name: "Intel Core i9-12900K", score: 41000, hasIGPU: true },
-  { name: "Intel Core i9-12900KF", score: 41000, hasIGPU: false },
-  { name: "Intel Core i9-12900F", score: 39000, hasIGPU: false },
-  { name: "Intel Core i9-12900", score: 39000, hasIGPU: true },
-  { name: "Intel Core i7-12700K", score: 35000, hasIGPU: true },
-  { name: "Intel Core i7-12700KF", score: 35000, hasIGPU: false },
-  { name: "Intel Core i7-12700F", score: 32000, hasIGPU: false },
-  { name: "Intel Core i7-12700", score: 32000, hasIGPU: true },
-  { name: "Intel Core i5-12600K", score: 27000, hasIGPU: true },
-  { name: "Intel Core i5-12600KF", score: 27000, hasIGPU: false },
-  { name: "Intel Core i5-12490F", score: 20500, hasIGPU: false },
-  { name: "Intel Core i5-12400F", score: 19500, hasIGPU: false },
-  { name: "Intel Core i5-12400", score: 19500, hasIGPU: true },
-  { name: "Intel Core i3-12100F", score: 13000, hasIGPU: false },
-  { name: "Intel Core i3-12100", score: 13000, hasIGPU: true },
-
-  // --- Intel 11th Gen ---
-  { name: "Intel Core i9-11900K", score: 25000, hasIGPU: true },
-  { name: "Intel Core i9-11900KF", score: 25000, hasIGPU: false },
-  { name: "Intel Core i7-11700K", score: 23000, hasIGPU: true },
-  { name: "Intel Core i7-11700KF", score: 23000, hasIGPU: false },
-  { name: "Intel Core i5-11600K", score: 19000, hasIGPU: true },
-  { name: "Intel Core i5-11600KF", score: 19000, hasIGPU: false },
-  { name: "Intel Core i5-11400F", score: 16000, hasIGPU: false },
-  { name: "Intel Core i5-11400", score: 16000, hasIGPU: true },
-
-  // --- Intel 10th Gen ---
-  { name: "Intel Core i9-10900K", score: 24000, hasIGPU: true },
-  { name: "Intel Core i9-10900KF", score: 24000, hasIGPU: false },
-  { name: "Intel Core i7-10700K", score: 19500, hasIGPU: true },
-  { name: "Intel Core i7-10700KF", score: 19500, hasIGPU: false },
-  { name: "Intel Core i5-10600K", score: 14500, hasIGPU: true },
-  { name: "Intel Core i5-10600KF", score: 14500, hasIGPU: false },
-  { name: "Intel Core i5-10400F", score: 12500, hasIGPU: false },
-  { name: "Intel Core i5-10400", score: 12500, hasIGPU: true },
-  { name: "Intel Core i3-10105F", score: 9000, hasIGPU: false },
-  { name: "Intel Core i3-10100F", score: 8800, hasIGPU: false },
-
-  // --- AMD Ryzen 3000 Series ---
-  { name: "AMD Ryzen 9 3950X", score: 39000, hasIGPU: false },
-  { name: "AMD Ryzen 9 3900X", score: 32000, hasIGPU: false },
-  { name: "AMD Ryzen 7 3800X", score: 24000, hasIGPU: false },
-  { name: "AMD Ryzen 7 3700X", score: 22000, hasIGPU: false },
-  { name: "AMD Ryzen 5 3600X", score: 18500, hasIGPU: false },
-  { name: "AMD Ryzen 5 3600", score: 17800, hasIGPU: false },
-  { name: "AMD Ryzen 5 3500X", score: 15000, hasIGPU: false },
-  { name: "AMD Ryzen 3 3300X", score: 13000, hasIGPU: false },
-  { name: "AMD Ryzen 3 3100", score: 11000, hasIGPU: false },
-  { name: "AMD Ryzen 5 3400G", score: 9500, hasIGPU: true },
-  { name: "AMD Ryzen 3 3200G", score: 7500, hasIGPU: true },
-
-  // --- Older Intel ---
-  { name: "Intel Core i9-9900K", score: 18500, hasIGPU: true },
-  { name: "Intel Core i9-9900KF", score: 18500, hasIGPU: false },
-  { name: "Intel Core i7-9700K", score: 14500, hasIGPU: true },
-  { name: "Intel Core i7-9700KF", score: 14500, hasIGPU: false },
-  { name: "Intel Core i5-9600KF", score: 11000, hasIGPU: false },
-  { name: "Intel Core i5-9400F", score: 9500, hasIGPU: false },
-  { name: "Intel Core i3-9100F", score: 6500, hasIGPU: false },
-  { name: "Intel Core i7-8700K", score: 13800, hasIGPU: true },
-  { name: "Intel Core i5-8400", score: 9000, hasIGPU: true },
-  { name: "Intel Core i7-7700K", score: 9800, hasIGPU: true },
-  { name: "Intel Core i7-6700K", score: 8900, hasIGPU: true },
-  { name: "Intel Core i7-4790K", score: 8000, hasIGPU: true },
-  { name: "Intel Core i7-3770K", score: 6500, hasIGPU: true },
-  { name: "Intel Core i7-2600K", score: 5500, hasIGPU: true },
-  { name: "Intel Core i5-2500K", score: 4000, hasIGPU: true },
-  { name: "Intel Core 2 Quad Q6600", score: 1800, hasIGPU: false },
-
-  // --- Older AMD ---
-  { name: "AMD Ryzen 7 2700X", score: 17500, hasIGPU: false },
-  { name: "AMD Ryzen 5 2600", score: 13000, hasIGPU: false },
-  { name: "AMD Ryzen 7 1800X", score: 16000, hasIGPU: false },
-  { name: "AMD FX-8350", score: 5800, hasIGPU: false }
-];
-
-export const gpuRanking = [
-  { name: "NVIDIA GeForce RTX 4090", score: 39000 },
-  { name: "NVIDIA GeForce RTX 4080 Super", score: 31000 },
-  { name: "NVIDIA GeForce RTX 4080", score: 28000 },
-  { name: "AMD Radeon RX 7900 XTX", score: 29000 },
-  { name: "NVIDIA GeForce RTX 4070 Ti Super", score: 25000 },
-  { name: "NVIDIA GeForce RTX 3090 Ti", score: 26000 },
-  { name: "AMD Radeon RX 7900 XT", score: 26000 },
-  { name: "NVIDIA GeForce RTX 3090", score: 24000 },
-  { name: "NVIDIA GeForce RTX 4070 Ti", score: 23000 },
-  { name: "NVIDIA GeForce RTX 3080 Ti", score: 23500 },
-  { name: "AMD Radeon RX 6950 XT", score: 24000 },
-  { name: "NVIDIA GeForce RTX 3080 12GB", score: 22000 },
-  { name: "NVIDIA GeForce RTX 4070 Super", score: 21000 },
-  { name: "AMD Radeon RX 6900 XT", score: 21000 },
-  { name: "NVIDIA GeForce RTX 3080 10GB", score: 20500 },
-  { name: "NVIDIA GeForce RTX 4070", score: 18000 },
-  { name: "AMD Radeon RX 7800 XT", score: 19000 },
-  { name: "NVIDIA GeForce RTX 3070 Ti", score: 17000 },
-  { name: "AMD Radeon RX 6800 XT", score: 19000 },
-  { name: "NVIDIA GeForce RTX 3070", score: 16000 },
-  { name: "NVIDIA GeForce RTX 2080 Ti", score: 16500 },
-  { name: "AMD Radeon RX 6800", score: 16000 },
-  { name: "NVIDIA GeForce RTX 4060 Ti", score: 13500 },
-  { name: "NVIDIA GeForce RTX 3060 Ti", score: 14000 },
-  { name: "AMD Radeon RX 6700 XT", score: 13000 },
-  { name: "NVIDIA GeForce RTX 2080 Super", score: 13500 },
-  { name: "NVIDIA GeForce RTX 3060 12GB", score: 11000 },
-  { name: "NVIDIA GeForce RTX 2070 Super", score: 11500 },
-  { name: "AMD Radeon RX 5700 XT", score: 11000 },
-  { name: "NVIDIA GeForce RTX 2060 Super", score: 10000 },
-  { name: "NVIDIA GeForce GTX 1080 Ti", score: 11500 },
-  { name: "NVIDIA GeForce RTX 4060", score: 10500 },
-  { name: "AMD Radeon RX 6600 XT", score: 10000 },
-  { name: "NVIDIA GeForce RTX 3050", score: 7000 },
-  { name: "NVIDIA GeForce GTX 1080", score: 9000 },
-  { name: "NVIDIA GeForce RTX 2060", score: 8500 },
-  { name: "AMD Radeon RX 5600 XT", score: 8000 },
-  { name: "NVIDIA GeForce GTX 1070 Ti", score: 8200 },
-  { name: "NVIDIA GeForce GTX 1660 Super", score: 6800 },
-  { name: "NVIDIA GeForce GTX 1070", score: 7500 },
-  { name: "NVIDIA GeForce GTX 980 Ti", score: 7000 },
-  { name: "NVIDIA GeForce GTX 1660 Ti", score: 7000 },
-  { name: "AMD Radeon RX 580", score: 4500 },
-  { name: "NVIDIA GeForce GTX 1060 6GB", score: 4800 },
-  { name: "NVIDIA GeForce GTX 980", score: 5500 },
-  { name: "NVIDIA GeForce GTX 970", score: 4500 },
-  { name: "NVIDIA GeForce GTX 1650", score: 3500 },
-  { name: "NVIDIA GeForce GTX 1050 Ti", score: 2800 },
-  { name: "NVIDIA GeForce GTX 960", score: 2500 },
-  { name: "NVIDIA GeForce GTX 750 Ti", score: 1500 },
-  { name: "NVIDIA GeForce GTX 660", score: 1800 },
-  { name: "NVIDIA GeForce GT 1030", score: 1200 }
-];
@@ -42,6 +42,14 @@ export default {
     serialNumber: 'Serial Number',
     cpuUsage: 'CPU',
     ramUsage: 'RAM',
+    swapUsage: 'Page File',
+    diskIo: 'Disk I/O',
+    netIo: 'Network',
+    liveIo: 'Live I/O',
+    busy: 'Busy',
+    traffic: 'Traffic',
+    packetsPerSec: 'packets/s (in / out)',
+    errors: 'Errors',
     unknown: 'Unknown',
     dataWidth: 'Data Width',
     totalWidth: 'Total Width',
@@ -51,6 +59,7 @@ export default {
     memoryDiagnostic: 'Windows Memory Diagnostic',
     bootTime: 'Boot Time',
     upTime: 'Up Time',
+    systemModel: 'System Model',
     chipset: 'Chipset',
     ssdSlots: 'SSD Slots (M.2)',
     gpuSlots: 'GPU Slots (PCIe x16)',
@@ -59,7 +68,8 @@ export default {
     resolution: 'Resolution',
     macAddress: 'MAC Address',
     type: 'Type',
-    health: 'Health Status'
+    health: 'Health Status',
+    measuredSpeed: 'Measured seq. read / write'
   },
   cpuInfo: {
     title: 'CPU Naming Conventions',
@@ -96,9 +106,30 @@ export default {
     Poor: 'Poor',
     Unknown: 'Unknown'
   },
+  scoreSource: {
+    benchmark: 'Benchmark: {name}',
+    measured: 'Measured by the built-in benchmark on this PC',
+    heuristic: 'Estimated from specs (model not in benchmark database)'
+  },
+  explain: {
+    title: 'Why this score?',
+    inputs: 'Inputs',
+    rule: 'Rule',
+    nextTier: 'Next tier',
+    confidence: 'Confidence',
+    levels: {
+      High: 'High',
+      Medium: 'Medium',
+      Low: 'Low'
+    }
+  },
   menu: {
     hardware: 'Hardware Info',
     ranking: 'Hardware Ranking',
+    game_check: 'Can I Run It',
+    psu: 'PSU Calculator',
+    benchmark: 'Benchmark',
+    processes: 'Processes',
     optimization: 'Optimization',
     diagnostics: 'Diagnostics',
     driver_cleaner: 'Driver Cleaner',
@@ -129,6 +160,22 @@ export default {
     updateNow: 'Update Now',
     noUpdate: 'You are using the latest version',
     star: 'Star',
+    monitoring: 'Monitoring',
+    samplingInterval: 'Sampling Interval',
+    exportHistory: 'Export Usage History',
+    exportCsv: 'Export CSV',
+    exportedTo: 'Saved to',
+    historyError: 'Writing the usage history failed',
+    alerts: 'Alerts',
+    alertRules: 'Alert Rules',
+    ruleName: 'Name',
+    ruleMetric: 'Metric',
+    ruleDuration: 'For (seconds)',
+    ruleCooldown: 'Cooldown (seconds)',
+    addRule: 'Add Rule',
+    alertHistory: 'Fired Alerts',
+    noAlerts: 'No alerts fired yet',
+    clearHistory: 'Clear',
     advanced: 'Advanced',
     adminRights: 'Administrator Rights',
     adminEnabled: 'Enabled',
@@ -248,16 +295,21 @@ export default {
     networkTitle: 'Network',
     securityTitle: 'Security',
     powerActivation: 'Power & Activation',
-    quickCheck: 'Quick Memory Check',
-    quickCheckDesc: 'Allocates and tests 500MB of RAM for errors. Fast but less thorough.',
+    quickCheck: 'Memory Tester',
+    quickCheckDesc: 'Locks part of the free RAM and runs walking ones/zeros, moving inversions, March C-, random-address and bit-fade tests on all cores. Memory in use by Windows and other programs is not covered.',
     fullCheck: 'Windows Memory Diagnostic',
     fullCheckDesc: 'Launches the official Windows tool. Requires a restart to run a full hardware scan.',
-    runQuick: 'RUN QUICK TEST',
+    runQuick: 'RUN MEMORY TEST',
+    ofFreeRam: 'of free RAM',
+    cancel: 'CANCEL',
+    threads: 'threads',
+    bits: 'bits',
+    memErrors: '{count} memory errors',
     openTool: 'OPEN TOOL',
     noReboot: 'No Reboot Required',
     rebootRequired: 'Reboot Required',
     testing: 'TESTING...',
-    testPassed: 'Memory check passed (Quick Test)',
+    testPassed: 'Memory test passed, no errors found',
     testFailed: 'Test Failed: ',
     diskHealth: 'Disk Health',
     sfc: 'System File Check (SFC)',
@@ -279,6 +331,36 @@ export default {
     firewall: 'Firewall',
     cortana: 'Cortana'
   },
+  processes: {
+    title: 'PROCESSES',
+    subtitle: 'LIVE PER-PROCESS USAGE',
+    search: 'Filter by name, PID or user...',
+    list: 'List',
+    tree: 'Tree',
+    pid: 'PID',
+    name: 'Name',
+    user: 'User',
+    cpu: 'CPU',
+    memory: 'Memory',
+    diskRead: 'Disk Read',
+    diskWrite: 'Disk Write',
+    threads: 'Threads',
+    started: 'Started',
+    priority: 'Priority',
+    affinity: 'Affinity',
+    endProcess: 'End',
+    confirmEnd: 'End {name} ({pid})? Unsaved work in it will be lost.',
+    applyAffinity: 'Apply',
+    cancel: 'Cancel',
+    priorities: {
+      Idle: 'Idle',
+      BelowNormal: 'Below Normal',
+      Normal: 'Normal',
+      AboveNormal: 'Above Normal',
+      High: 'High',
+      Realtime: 'Realtime'
+    }
+  },
   uninstall: {
     title: 'SOFTWARE UNINSTALLER',
     subtitle: 'REMOVE BLOATWARE & APPS',
@@ -317,6 +399,96 @@ export default {
     desc: 'Open Windows Color Management Control Panel to load ICC profiles or calibrate display.',
     openColorCpl: 'Open Color Management'
   },
+  gameCheck: {
+    title: 'Can I Run It',
+    subtitle: 'Game & App System Requirements',
+    desc: 'Compare this PC with the minimum and recommended specs of games and apps. Add your own titles in neko233-hardware-viewer.requirements.json',
+    allGames: 'All titles',
+    runCheck: 'Check',
+    minimum: 'Minimum',
+    recommended: 'Recommended',
+    component: 'Component',
+    found: 'This PC'
+  },
+  psu: {
+    title: 'PSU Calculator',
+    subtitle: 'Power Supply Wattage Estimate',
+    desc: 'Adds up the worst-case draw of this PC and of a planned upgrade. Parts marked * are not in the power database and use a typical figure.',
+    installed: 'Installed PSU (W)',
+    newCpu: 'New CPU',
+    newGpu: 'New graphics card',
+    keep: 'Keep current',
+    extra_ram_modules: 'Extra memory modules',
+    extra_nvme: 'Extra NVMe SSDs',
+    extra_ssd: 'Extra SATA SSDs',
+    extra_hdd: 'Extra hard drives',
+    extra_fans: 'Extra fans',
+    estimate: 'Estimate',
+    current: 'Current configuration',
+    upgraded: 'After upgrade',
+    load: 'Peak load',
+    recommended: 'Recommended PSU',
+    verdict: {
+      Enough: 'Enough',
+      Tight: 'Covers the load without headroom',
+      TooSmall: 'Too small'
+    }
+  },
+  benchmark: {
+    title: 'Benchmark',
+    subtitle: 'Measured Performance',
+    run: 'Run',
+    cancel: 'Cancel',
+    duration: 'Time per test',
+    test: 'Test',
+    single: 'Single thread',
+    multi: 'All threads',
+    cpuTitle: 'CPU Benchmark',
+    cpuDesc: 'Integer, floating point, compression, hashing and sorting workloads, each on one thread and then on all threads. 1000 points is one thread of the reference machine.',
+    databasePoints: 'Database scale',
+    memoryTitle: 'Memory Benchmark',
+    memoryDesc: 'Read, write and copy bandwidth on all threads, then access latency from L1 cache out to DRAM. Confirms dual-channel and XMP/EXPO are actually in effect.',
+    read: 'Read',
+    write: 'Write',
+    copy: 'Copy',
+    latency: 'Latency by working set',
+    dramLatency: 'DRAM latency',
+    dualChannelPeak: 'Dual-channel peak',
+    diskTitle: 'Storage Benchmark',
+    diskDesc: 'Sequential 1 MiB and random 4 KiB reads and writes on a test file, bypassing the Windows cache. Shows whether a drive reaches the speed its interface allows. The result is kept with the disk.',
+    volume: 'Volume',
+    fileSize: 'Test file',
+    iops: 'IOPS',
+    latencyUs: 'Latency',
+    stressTitle: 'Stress Test',
+    stressDesc: 'Loads the CPU with self-checking workloads, tests part of the free RAM and optionally writes and verifies a file on a drive, all at once. Temperatures, clocks and errors are sampled every second; the test stops at the temperature limit.',
+    memoryLoad: 'Memory',
+    storageLoad: 'Storage',
+    tempLimit: 'Temperature limit',
+    perfLimit: 'Perf. limit',
+    errors: 'Errors',
+    stable: 'STABLE',
+    unstable: 'NOT STABLE',
+    maxTemp: 'Max temperature',
+    clocks: 'Clock start → sustained',
+    throttled: 'Throttled',
+    historyTitle: 'History',
+    historyDesc: 'Every finished run is stored with the hardware and Windows version it ran on. Compare two runs, or a run against reference results for similar hardware.',
+    kind: 'Benchmark',
+    baseRun: 'Before',
+    otherRun: 'After',
+    compare: 'Compare',
+    vsReference: 'vs reference',
+    delete: 'Delete',
+    noRuns: 'No stored runs yet',
+    noReference: 'No reference results for similar hardware',
+    hardwareChanged: 'The hardware changed between these runs:',
+    stopReason: {
+      Completed: 'completed',
+      TemperatureLimit: 'temperature limit reached',
+      Cancelled: 'cancelled'
+    }
+  },
   win11: {
     installTitle: 'Win11 Installer',
     installSubtitle: 'Bypass Restrictions & Download',
@@ -326,6 +498,15 @@ export default {
     officialDownload: 'Official Download',
     downloadDesc: 'Go to Microsoft official site to download Windows 11 ISO',
     openMicrosoft: 'Open Website',
+    readinessTitle: 'Compatibility Check',
+    readinessDesc: 'Check this PC against every Windows 11 hardware requirement',
+    runCheck: 'Run Check',
+    overall: 'Result',
+    status: {
+      Pass: 'PASS',
+      Fail: 'FAIL',
+      Unknown: 'UNKNOWN'
+    },
     tweaksTitle: 'Win11 Tweaks',
     tweaksSubtitle: 'UI & Experience Optimization',
     classicContext: 'Classic Context Menu',
@@ -348,7 +529,31 @@ export default {
     date: 'Date',
     oemId: 'OEM ID',
     uninstall: 'Uninstall',
-    noDrivers: 'No drivers found'
+    noDrivers: 'No drivers found',
+    daysOld: '{days} days old'
+  },
+  formFactors: {
+    Desktop: 'Desktop',
+    Laptop: 'Laptop',
+    Convertible: 'Convertible',
+    Tablet: 'Tablet',
+    Handheld: 'Handheld',
+    AllInOne: 'All-in-One',
+    MiniPc: 'Mini PC',
+    Server: 'Server',
+    Unknown: 'Unknown'
+  },
+  systemScore: {
+    profile: 'Scoring profile',
+    title: 'Overall System Score',
+    bottleneck: 'Bottleneck',
+    findings: 'Findings',
+    upgrades: 'Upgrade Advice',
+    noUpgrades: 'No upgrade on this platform raises the score'
+  },
+  virtualization: {
+    detected: 'Virtual Machine Detected',
+    scoreWarning: 'Scores below describe the virtual hardware assigned by the host, not the physical machine.'
   },
   infoBtn: {
     cpu: 'CPU Details',
@@ -42,6 +42,14 @@ export default {
     serialNumber: '序列号',
     cpuUsage: '处理器',
     ramUsage: '内存',
+    swapUsage: '页面文件',
+    diskIo: '磁盘读写',
+    netIo: '网络',
+    liveIo: '实时读写',
+    busy: '繁忙',
+    traffic: '流量',
+    packetsPerSec: '包/秒 (收 / 发)',
+    errors: '错误',
     unknown: '未知',
     dataWidth: '数据位宽',
     totalWidth: '总位宽',
@@ -51,6 +59,7 @@ export default {
     memoryDiagnostic: 'Windows 内存诊断',
     bootTime: '开机时间',
     upTime: '已运行时间',
+    systemModel: '整机型号',
     chipset: '芯片组',
     ssdSlots: 'SSD 插槽 (M.2)',
     gpuSlots: '显卡插槽 (PCIe x16)',
@@ -59,7 +68,8 @@ export default {
     resolution: '分辨率',
     macAddress: 'MAC 地址',
     type: '类型',
-    health: '健康状态'
+    health: '健康状态',
+    measuredSpeed: '实测顺序读 / 写'
   },
   cpuInfo: {
     title: 'CPU 命名规则说明',
@@ -103,9 +113,30 @@ export default {
     Poor: '较差',
     Unknown: '未知'
   },
+  scoreSource: {
+    benchmark: '跑分: {name}',
+    measured: '由本机内置跑分实测',
+    heuristic: '按规格估算（跑分库中无此型号）'
+  },
+  explain: {
+    title: '为什么是这个分数？',
+    inputs: '输入',
+    rule: '规则',
+    nextTier: '下一档',
+    confidence: '可信度',
+    levels: {
+      High: '高',
+      Medium: '中',
+      Low: '低'
+    }
+  },
   menu: {
     hardware: '硬件信息',
     ranking: '硬件天梯榜',
+    game_check: '能否运行',
+    psu: '电源计算',
+    benchmark: '性能测试',
+    processes: '进程管理',
     optimization: '系统优化',
     diagnostics: '系统诊断',
     driver_cleaner: '驱动清理',
@@ -136,6 +167,22 @@ export default {
     updateNow: '立即更新',
     noUpdate: '当前已是最新版本',
     star: '点赞',
+    monitoring: '监控',
+    samplingInterval: '采样间隔',
+    exportHistory: '导出使用历史',
+    exportCsv: '导出 CSV',
+    exportedTo: '已保存到',
+    historyError: '写入使用记录失败',
+    alerts: '告警',
+    alertRules: '告警规则',
+    ruleName: '名称',
+    ruleMetric: '指标',
+    ruleDuration: '持续时间（秒）',
+    ruleCooldown: '冷却时间（秒）',
+    addRule: '添加规则',
+    alertHistory: '已触发的告警',
+    noAlerts: '暂无告警',
+    clearHistory: '清空',
     advanced: '高级设置',
     adminRights: '管理员权限',
     adminEnabled: '已获取',
@@ -255,16 +302,21 @@ export default {
     networkTitle: '网络',
     securityTitle: '安全',
     powerActivation: '电源与激活',
-    quickCheck: '快速内存检查',
-    quickCheckDesc: '分配并测试 500MB 内存以查找错误。速度快但不如完整测试彻底。',
+    quickCheck: '内存测试',
+    quickCheckDesc: '锁定部分空闲内存，在所有核心上运行走步 1/0、移动反转、March C-、随机地址和位衰减测试。Windows 和其他程序正在使用的内存不在测试范围内。',
     fullCheck: 'Windows 内存诊断',
     fullCheckDesc: '启动官方 Windows 工具。需要重启以运行完整的硬件扫描。',
-    runQuick: '运行快速测试',
+    runQuick: '运行内存测试',
+    ofFreeRam: '空闲内存',
+    cancel: '取消',
+    threads: '线程',
+    bits: '位',
+    memErrors: '{count} 个内存错误',
     openTool: '打开工具',
     noReboot: '无需重启',
     rebootRequired: '需要重启',
     testing: '测试中...',
-    testPassed: '内存检查通过 (快速测试)',
+    testPassed: '内存测试通过，未发现错误',
     testFailed: '测试失败: ',
     diskHealth: '磁盘健康',
     sfc: '系统文件检查 (SFC)',
@@ -286,6 +338,36 @@ export default {
     firewall: '防火墙',
     cortana: 'Cortana'
   },
+  processes: {
+    title: '进程',
+    subtitle: '实时进程资源占用',
+    search: '按名称、PID 或用户筛选...',
+    list: '列表',
+    tree: '树状',
+    pid: 'PID',
+    name: '名称',
+    user: '用户',
+    cpu: 'CPU',
+    memory: '内存',
+    diskRead: '磁盘读取',
+    diskWrite: '磁盘写入',
+    threads: '线程',
+    started: '启动时间',
+    priority: '优先级',
+    affinity: '相关性',
+    endProcess: '结束',
+    confirmEnd: '结束 {name} ({pid})？其中未保存的内容将会丢失。',
+    applyAffinity: '应用',
+    cancel: '取消',
+    priorities: {
+      Idle: '低',
+      BelowNormal: '低于正常',
+      Normal: '正常',
+      AboveNormal: '高于正常',
+      High: '高',
+      Realtime: '实时'
+    }
+  },
   uninstall: {
     title: '软件卸载',
     subtitle: '移除臃肿软件与应用',
@@ -324,6 +406,96 @@ export default {
     desc: '打开 Windows 颜色管理控制面板以加载 ICC 配置文件或校准显示器。',
     openColorCpl: '打开颜色管理'
   },
+  gameCheck: {
+    title: '能否运行',
+    subtitle: '游戏与软件配置要求',
+    desc: '将本机与游戏/软件的最低配置和推荐配置进行对比。可在 neko233-hardware-viewer.requirements.json 中添加自定义条目',
+    allGames: '全部条目',
+    runCheck: '检测',
+    minimum: '最低配置',
+    recommended: '推荐配置',
+    component: '组件',
+    found: '本机'
+  },
+  psu: {
+    title: '电源计算',
+    subtitle: '电源功率估算',
+    desc: '累加本机以及计划升级后各部件的最大功耗。标 * 的部件不在功耗数据库中，按典型值估算。',
+    installed: '现有电源 (W)',
+    newCpu: '新 CPU',
+    newGpu: '新显卡',
+    keep: '保持不变',
+    extra_ram_modules: '增加内存条',
+    extra_nvme: '增加 NVMe 固态',
+    extra_ssd: '增加 SATA 固态',
+    extra_hdd: '增加机械硬盘',
+    extra_fans: '增加风扇',
+    estimate: '估算',
+    current: '当前配置',
+    upgraded: '升级后',
+    load: '峰值功耗',
+    recommended: '推荐电源',
+    verdict: {
+      Enough: '足够',
+      Tight: '能带动但没有余量',
+      TooSmall: '功率不足'
+    }
+  },
+  benchmark: {
+    title: '性能测试',
+    subtitle: '实测性能',
+    run: '开始',
+    cancel: '取消',
+    duration: '每项时长',
+    test: '测试项',
+    single: '单线程',
+    multi: '全部线程',
+    cpuTitle: 'CPU 性能测试',
+    cpuDesc: '整数、浮点、压缩、哈希和排序负载，先单线程再全部线程运行。1000 分等于参考机器的一个线程。',
+    databasePoints: '数据库分数',
+    memoryTitle: '内存性能测试',
+    memoryDesc: '全部线程的读取、写入、复制带宽，以及从 L1 缓存到内存的访问延迟。用于确认双通道和 XMP/EXPO 是否真正生效。',
+    read: '读取',
+    write: '写入',
+    copy: '复制',
+    latency: '不同数据量的延迟',
+    dramLatency: '内存延迟',
+    dualChannelPeak: '双通道理论峰值',
+    diskTitle: '硬盘性能测试',
+    diskDesc: '在测试文件上进行 1 MiB 顺序和 4 KiB 随机读写，绕过 Windows 缓存。用于判断硬盘是否达到接口应有的速度。结果会与该硬盘一起保存。',
+    volume: '分区',
+    fileSize: '测试文件',
+    iops: 'IOPS',
+    latencyUs: '延迟',
+    stressTitle: '压力测试',
+    stressDesc: '同时运行带结果校验的 CPU 负载、测试部分空闲内存，并可选在硬盘上写入并校验文件。每秒采样温度、频率和错误，达到温度上限时立即停止。',
+    memoryLoad: '内存',
+    storageLoad: '硬盘',
+    tempLimit: '温度上限',
+    perfLimit: '性能限制',
+    errors: '错误',
+    stable: '稳定',
+    unstable: '不稳定',
+    maxTemp: '最高温度',
+    clocks: '频率 起始 → 持续',
+    throttled: '降频时长',
+    historyTitle: '历史记录',
+    historyDesc: '每次完成的测试都会连同当时的硬件和 Windows 版本一起保存。可以对比两次测试，或与相近硬件的参考成绩对比。',
+    kind: '测试项目',
+    baseRun: '之前',
+    otherRun: '之后',
+    compare: '对比',
+    vsReference: '对比参考',
+    delete: '删除',
+    noRuns: '暂无保存的测试记录',
+    noReference: '没有相近硬件的参考成绩',
+    hardwareChanged: '两次测试之间硬件有变化：',
+    stopReason: {
+      Completed: '已完成',
+      TemperatureLimit: '达到温度上限',
+      Cancelled: '已取消'
+    }
+  },
   win11: {
     installTitle: 'Win11 安装助手',
     installSubtitle: '绕过限制与下载',
@@ -333,6 +505,15 @@ export default {
     officialDownload: '官方下载',
     downloadDesc: '前往 Microsoft 官网下载 Windows 11 镜像',
     openMicrosoft: '打开官网',
+    readinessTitle: '兼容性检测',
+    readinessDesc: '逐项检测本机是否满足 Windows 11 硬件要求',
+    runCheck: '开始检测',
+    overall: '结果',
+    status: {
+      Pass: '通过',
+      Fail: '不通过',
+      Unknown: '未知'
+    },
     tweaksTitle: 'Win11 调整',
     tweaksSubtitle: '界面与体验优化',
     classicContext: '经典右键菜单',
@@ -343,6 +524,29 @@ export default {
     restartExplorer: '重启资源管理器',
     restartExplorerBtn: '立即重启'
   },
+  formFactors: {
+    Desktop: '台式机',
+    Laptop: '笔记本',
+    Convertible: '二合一',
+    Tablet: '平板',
+    Handheld: '掌机',
+    AllInOne: '一体机',
+    MiniPc: '迷你主机',
+    Server: '服务器',
+    Unknown: '未知'
+  },
+  systemScore: {
+    profile: '评分方案',
+    title: '整机评分',
+    bottleneck: '瓶颈',
+    findings: '诊断结论',
+    upgrades: '升级建议',
+    noUpgrades: '当前平台上没有能提升评分的升级方案'
+  },
+  virtualization: {
+    detected: '检测到虚拟机',
+    scoreWarning: '以下评分反映的是宿主机分配的虚拟硬件，而非物理机器。'
+  },
   driver_cleaner: {
     title: '驱动清理',
     subtitle: '彻底移除显卡驱动',
@@ -355,6 +559,7 @@ export default {
     date: '日期',
     oemId: 'OEM ID',
     uninstall: '卸载',
-    noDrivers: '未找到相关驱动'
+    noDrivers: '未找到相关驱动',
+    daysOld: '{days} 天前'
   }
 }